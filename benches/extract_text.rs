@@ -0,0 +1,37 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use hwpers::{HwpDocument, HwpReader, HwpWriter};
+
+/// A document of many short paragraphs, where per-paragraph allocations dominate
+fn large_document() -> HwpDocument {
+    let mut writer = HwpWriter::new();
+    for i in 0..5000 {
+        writer
+            .add_paragraph(&format!("문단 {i}: 반복되는 본문 텍스트\t끝"))
+            .unwrap();
+    }
+    HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap()
+}
+
+fn bench_extract_text(c: &mut Criterion) {
+    let document = large_document();
+    let mut group = c.benchmark_group("extract_text");
+    group.bench_function("reserved", |b| b.iter(|| document.extract_text()));
+    // The loop extract_text used before: raw paragraph content pushed into
+    // one growing String, without cleanup or a reserved capacity
+    group.bench_function("baseline", |b| {
+        b.iter(|| {
+            let mut text = String::new();
+            for para in document.paragraphs() {
+                if let Some(ref para_text) = para.text {
+                    text.push_str(&para_text.content);
+                    text.push('\n');
+                }
+            }
+            text
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_extract_text);
+criterion_main!(benches);
@@ -11,7 +11,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Simple bullet list
     writer.add_heading("글머리 기호 목록", 2)?;
-    writer.add_list(
+    writer.add_list_of_type(
         &["첫 번째 항목", "두 번째 항목", "세 번째 항목"],
         ListType::Bullet,
     )?;
@@ -20,7 +20,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Numbered list
     writer.add_heading("번호 목록", 2)?;
-    writer.add_list(
+    writer.add_list_of_type(
         &["항목 하나", "항목 둘", "항목 셋", "항목 넷"],
         ListType::Numbered,
     )?;
@@ -29,13 +29,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Alphabetic list
     writer.add_heading("알파벳 목록", 2)?;
-    writer.add_list(&["Apple", "Banana", "Cherry", "Date"], ListType::Alphabetic)?;
+    writer.add_list_of_type(&["Apple", "Banana", "Cherry", "Date"], ListType::Alphabetic)?;
 
     writer.add_paragraph("")?;
 
     // Korean list
     writer.add_heading("한글 목록", 2)?;
-    writer.add_list(
+    writer.add_list_of_type(
         &["가을 하늘", "나비 날개", "다람쥐 집", "라일락 꽃"],
         ListType::Korean,
     )?;
@@ -44,7 +44,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Roman numeral list
     writer.add_heading("로마 숫자 목록", 2)?;
-    writer.add_list(
+    writer.add_list_of_type(
         &[
             "Introduction",
             "Background",
@@ -181,7 +181,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Add a list
     use hwpers::writer::style::ListType;
-    writer.add_list(
+    writer.add_list_of_type(
         &["첫 번째 항목", "두 번째 항목", "세 번째 항목"],
         ListType::Bullet,
     )?;
@@ -25,7 +25,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Example 1: Basic text box
     writer.add_heading("1. Basic Text Box", 2)?;
     writer.add_paragraph("Below is a basic text box with default styling:")?;
-    writer.add_text_box("This is a basic text box with default settings.")?;
+    writer.add_text_box(
+        "This is a basic text box with default settings.",
+        20.0,
+        40.0,
+        50.0,
+        20.0,
+    )?;
 
     // Example 2: Positioned text box
     writer.add_heading("2. Positioned Text Box", 2)?;
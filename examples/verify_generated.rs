@@ -23,7 +23,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     let truncated: String = text.chars().take(50).collect();
                     format!("{}...", truncated)
                 } else {
-                    text.clone()
+                    text.to_string()
                 };
                 println!("Text preview: {}", preview);
 
@@ -10,7 +10,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Original file size: {} bytes", original_bytes.len());
 
     let original_doc = HwpReader::from_file(original_path)?;
-    let original_text = original_doc.extract_text();
+    let original_text = original_doc.extract_text().into_owned();
     println!("Original text length: {} characters", original_text.len());
     println!("Original text hash: {:x}", md5::compute(&original_text));
     println!(
@@ -28,6 +28,42 @@ pub enum HwpError {
 
     #[error("Invalid input: {0}")]
     InvalidInput(String),
+
+    #[error("Missing required HWPX part: {0}")]
+    MissingPart(String),
+
+    #[error("Failed to parse stream '{stream}' at offset {offset}: {reason}")]
+    StreamParse {
+        stream: String,
+        offset: usize,
+        reason: String,
+    },
+}
+
+impl HwpError {
+    /// Attach the stream name and byte offset where this error occurred
+    ///
+    /// An error that already carries stream context only gets its stream name
+    /// replaced, so a parser-level name can be refined by the caller.
+    pub fn in_stream(self, stream: &str, offset: usize) -> Self {
+        match self {
+            HwpError::StreamParse { offset, reason, .. } => HwpError::StreamParse {
+                stream: stream.to_string(),
+                offset,
+                reason,
+            },
+            HwpError::ParseError(reason) => HwpError::StreamParse {
+                stream: stream.to_string(),
+                offset,
+                reason,
+            },
+            other => HwpError::StreamParse {
+                stream: stream.to_string(),
+                offset,
+                reason: other.to_string(),
+            },
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, HwpError>;
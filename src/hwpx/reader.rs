@@ -6,16 +6,56 @@ use zip::ZipArchive;
 use crate::error::{HwpError, Result};
 use crate::model::{
     para_char_shape::{CharPositionShape, ParaCharShape},
-    CharShape, DocumentProperties, FaceName, HwpDocument, ParaShape, ParaText, Paragraph, Section,
+    CharShape, ControlType, CtrlHeader, DocumentProperties, FaceName, Footnote, HwpDocument,
+    Hyperlink, ParaShape, ParaText, Paragraph, Section, Table, TableCell, VAlign,
 };
-use crate::parser::body_text::BodyText;
+use crate::parser::body_text::{BodyText, SectionSource};
 use crate::parser::doc_info::DocInfo;
 use crate::parser::header::FileHeader;
 
-use super::xml_types::{self, HcfVersion, Head, Run, Section as XmlSection, XmlParagraph};
+use super::xml_types::{
+    self, HcfVersion, Head, Run, Section as XmlSection, XmlParagraph, XmlTable,
+};
 
 pub struct HwpxReader;
 
+/// MIME type stored in the `mimetype` part of an HWPX package
+const HWPX_MIMETYPE: &str = "application/hwp+zip";
+
+/// Parts every HWPX package must contain
+const REQUIRED_PARTS: [&str; 3] = ["mimetype", "version.xml", "Contents/content.hpf"];
+
+/// Decode an XML part, honouring a byte order mark or the declared encoding
+///
+/// A leading BOM takes precedence over the declaration and is stripped.
+fn decode_xml(bytes: &[u8]) -> std::result::Result<String, String> {
+    let (encoding, bom_len) = encoding_rs::Encoding::for_bom(bytes)
+        .or_else(|| declared_encoding(bytes).map(|encoding| (encoding, 0)))
+        .unwrap_or((encoding_rs::UTF_8, 0));
+
+    let (text, had_errors) = encoding.decode_without_bom_handling(&bytes[bom_len..]);
+    if had_errors && encoding == encoding_rs::UTF_8 {
+        return Err("invalid UTF-8".to_string());
+    }
+    Ok(text.into_owned())
+}
+
+/// Encoding named in the `<?xml ... encoding="..."?>` declaration
+fn declared_encoding(bytes: &[u8]) -> Option<&'static encoding_rs::Encoding> {
+    let end = bytes.iter().position(|&b| b == b'>')?;
+    let declaration = std::str::from_utf8(&bytes[..end]).ok()?;
+    let rest = declaration
+        .strip_prefix("<?xml")?
+        .split_once("encoding")?
+        .1
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start();
+    let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let label = rest[1..].split(quote).next()?;
+    encoding_rs::Encoding::for_label(label.as_bytes())
+}
+
 impl HwpxReader {
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<HwpDocument> {
         let file = File::open(path).map_err(HwpError::Io)?;
@@ -28,9 +68,47 @@ impl HwpxReader {
         Self::from_reader(cursor)
     }
 
+    /// Check that `bytes` is an HWPX package with all required parts
+    ///
+    /// A missing `mimetype`, `version.xml` or `Contents/content.hpf` is
+    /// reported as [`HwpError::MissingPart`].
+    pub fn validate(bytes: &[u8]) -> Result<()> {
+        let mut archive = Self::open_archive(std::io::Cursor::new(bytes))?;
+        Self::validate_archive(&mut archive)
+    }
+
+    fn open_archive<R: Read + std::io::Seek>(reader: R) -> Result<ZipArchive<R>> {
+        ZipArchive::new(reader)
+            .map_err(|e| HwpError::InvalidFormat(format!("Invalid HWPX archive: {}", e)))
+    }
+
+    fn validate_archive<R: Read + std::io::Seek>(archive: &mut ZipArchive<R>) -> Result<()> {
+        if let Some(part) = REQUIRED_PARTS
+            .iter()
+            .find(|part| archive.index_for_name(part).is_none())
+        {
+            return Err(HwpError::MissingPart(part.to_string()));
+        }
+
+        let mut mimetype = String::new();
+        archive
+            .by_name("mimetype")
+            .map_err(|e| HwpError::InvalidFormat(format!("Unreadable mimetype: {}", e)))?
+            .read_to_string(&mut mimetype)
+            .map_err(HwpError::Io)?;
+        if mimetype.trim() != HWPX_MIMETYPE {
+            return Err(HwpError::InvalidFormat(format!(
+                "Unexpected HWPX mimetype: {}",
+                mimetype.trim()
+            )));
+        }
+
+        Ok(())
+    }
+
     fn from_reader<R: Read + std::io::Seek>(reader: R) -> Result<HwpDocument> {
-        let mut archive = ZipArchive::new(reader)
-            .map_err(|e| HwpError::InvalidFormat(format!("Invalid HWPX archive: {}", e)))?;
+        let mut archive = Self::open_archive(reader)?;
+        Self::validate_archive(&mut archive)?;
 
         let version = Self::read_version(&mut archive)?;
         let head = Self::read_header(&mut archive)?;
@@ -38,7 +116,7 @@ impl HwpxReader {
 
         let header = Self::create_file_header(&version);
         let doc_info = Self::convert_head_to_doc_info(&head);
-        let body_texts = Self::convert_sections_to_body_texts(&sections, &head);
+        let body_texts = Self::convert_sections_to_body_texts(sections, &head);
 
         Ok(HwpDocument {
             header,
@@ -47,6 +125,10 @@ impl HwpxReader {
             preview_text: None,
             preview_image: None,
             summary_info: None,
+            scripts: Vec::new(),
+            history: Vec::new(),
+            xml_template: None,
+            undecrypted_streams: Vec::new(),
         })
     }
 
@@ -64,7 +146,7 @@ impl HwpxReader {
 
     fn read_sections<R: Read + std::io::Seek>(
         archive: &mut ZipArchive<R>,
-    ) -> Result<Vec<XmlSection>> {
+    ) -> Result<Vec<(XmlSection, String)>> {
         let mut sections = Vec::new();
         let mut idx = 0;
 
@@ -75,7 +157,7 @@ impl HwpxReader {
                     let section = xml_types::parse_section(&xml).map_err(|e| {
                         HwpError::ParseError(format!("Failed to parse {}: {}", filename, e))
                     })?;
-                    sections.push(section);
+                    sections.push((section, xml));
                     idx += 1;
                 }
                 Err(_) => break,
@@ -99,20 +181,25 @@ impl HwpxReader {
             .by_name(filename)
             .map_err(|_| HwpError::NotFound(format!("File not found in archive: {}", filename)))?;
 
-        let mut contents = String::new();
-        file.read_to_string(&mut contents).map_err(HwpError::Io)?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes).map_err(HwpError::Io)?;
 
-        Ok(contents)
+        decode_xml(&bytes)
+            .map_err(|e| HwpError::EncodingError(format!("Failed to decode {}: {}", filename, e)))
     }
 
     fn create_file_header(version: &HcfVersion) -> FileHeader {
         let mut header = FileHeader::new_default();
 
-        let version_str = version
-            .version
-            .as_deref()
-            .or(version.xml_version.as_deref())
-            .unwrap_or("5.0");
+        // xmlVersion is the schema version, so the major/minor pair wins over it
+        let version_str = match (&version.version, &version.major) {
+            (Some(version_str), _) => version_str.clone(),
+            (None, Some(major)) => {
+                let minor = version.minor.as_deref().unwrap_or("0");
+                format!("{major}.{minor}")
+            }
+            (None, None) => version.xml_version.clone().unwrap_or_else(|| "5.0".into()),
+        };
 
         let version_parts: Vec<u8> = version_str
             .split('.')
@@ -126,14 +213,6 @@ impl HwpxReader {
                 version_parts.get(2).copied().unwrap_or(0),
                 version_parts.get(3).copied().unwrap_or(0),
             );
-        } else if let Some(ref major) = version.major {
-            let major_val: u8 = major.parse().unwrap_or(5);
-            let minor_val: u8 = version
-                .minor
-                .as_ref()
-                .and_then(|m| m.parse().ok())
-                .unwrap_or(0);
-            header.set_version(major_val, minor_val, 0, 0);
         }
 
         header
@@ -153,6 +232,7 @@ impl HwpxReader {
                             substitute_font_name: String::new(),
                             panose: None,
                             default_font_name: String::new(),
+                            embedded_bin_id: None,
                         });
                     }
                 }
@@ -172,12 +252,12 @@ impl HwpxReader {
                     }
                     if let Some(ref underline) = char_pr.underline {
                         if underline != "NONE" {
-                            char_shape.properties |= 0x04;
+                            char_shape.set_underline(Default::default());
                         }
                     }
                     if let Some(ref strikeout) = char_pr.strikeout {
                         if strikeout != "NONE" {
-                            char_shape.properties |= 0x08;
+                            char_shape.set_strikethrough();
                         }
                     }
                     if let Some(ref color_str) = char_pr.text_color {
@@ -222,28 +302,40 @@ impl HwpxReader {
         doc_info
     }
 
-    fn convert_sections_to_body_texts(sections: &[XmlSection], _head: &Head) -> Vec<BodyText> {
+    fn convert_sections_to_body_texts(
+        sections: Vec<(XmlSection, String)>,
+        _head: &Head,
+    ) -> Vec<BodyText> {
         sections
-            .iter()
-            .map(|xml_section| {
-                let paragraphs: Vec<Paragraph> = xml_section
-                    .paragraphs
-                    .iter()
-                    .map(Self::convert_paragraph)
-                    .collect();
+            .into_iter()
+            .map(|(xml_section, raw_xml)| {
+                let mut paragraphs = Vec::new();
+                for xml_para in &xml_section.paragraphs {
+                    Self::convert_paragraph(xml_para, &mut paragraphs);
+                }
 
                 BodyText {
                     sections: vec![Section {
                         paragraphs,
                         section_def: None,
                         page_def: None,
+                        column_def: None,
+                        page_border_fills: Vec::new(),
+                        unhandled_controls: 0,
                     }],
+                    source: SectionSource::BodyText,
+                    raw_xml: Some(raw_xml),
+                    raw_records: None,
                 }
             })
             .collect()
     }
 
-    fn convert_paragraph(xml_para: &XmlParagraph) -> Paragraph {
+    /// Convert a paragraph, followed by the paragraphs of any table cells it holds
+    ///
+    /// Cell paragraphs are laid out after the table paragraph, the same way
+    /// `HwpWriter` builds tables.
+    fn convert_paragraph(xml_para: &XmlParagraph, out: &mut Vec<Paragraph>) {
         let (text_content, char_positions) = Self::extract_text_and_char_shapes(&xml_para.runs);
 
         let char_shapes = if char_positions.is_empty() {
@@ -252,7 +344,7 @@ impl HwpxReader {
             Some(ParaCharShape { char_positions })
         };
 
-        Paragraph {
+        let mut paragraph = Paragraph {
             text: if text_content.is_empty() {
                 None
             } else {
@@ -264,10 +356,169 @@ impl HwpxReader {
             para_shape_id: xml_para.para_pr_id_ref.unwrap_or(0) as u16,
             style_id: xml_para.style_id_ref.unwrap_or(0) as u8,
             char_shapes,
+            hyperlinks: Self::extract_hyperlinks(&xml_para.runs),
+            footnotes: Self::extract_footnotes(&xml_para.runs),
             ..Default::default()
+        };
+
+        let mut tables = xml_para
+            .runs
+            .iter()
+            .filter_map(|run| run.table.as_ref())
+            .chain(&xml_para.tables);
+        let Some(first) = tables.next() else {
+            out.push(paragraph);
+            return;
+        };
+
+        let mut cell_paragraphs = Vec::new();
+        Self::attach_table(&mut paragraph, first, &mut cell_paragraphs);
+        out.push(paragraph);
+        out.append(&mut cell_paragraphs);
+
+        // Any further tables in the same paragraph get a paragraph of their own
+        for xml_table in tables {
+            let mut table_paragraph = Paragraph::default();
+            Self::attach_table(&mut table_paragraph, xml_table, &mut cell_paragraphs);
+            out.push(table_paragraph);
+            out.append(&mut cell_paragraphs);
         }
     }
 
+    fn attach_table(paragraph: &mut Paragraph, xml_table: &XmlTable, out: &mut Vec<Paragraph>) {
+        let row_count = xml_table.row_cnt.unwrap_or(xml_table.rows.len() as u32) as u16;
+        let col_count = xml_table.col_cnt.unwrap_or_else(|| {
+            xml_table
+                .rows
+                .iter()
+                .map(|row| row.cells.len() as u32)
+                .max()
+                .unwrap_or(0)
+        }) as u16;
+
+        let mut table = Table::new_default(row_count, col_count);
+        if let Some(spacing) = xml_table.cell_spacing {
+            table.cell_spacing = spacing as u16;
+        }
+        if xml_table.repeat_header.as_deref() == Some("1") {
+            table.properties |= crate::model::control::TABLE_REPEAT_HEADER;
+        }
+
+        let mut list_id = 0;
+        for (row_idx, xml_row) in xml_table.rows.iter().enumerate() {
+            for (col_idx, xml_cell) in xml_row.cells.iter().enumerate() {
+                let addr = xml_cell.cell_addr.as_ref();
+                let row = addr.and_then(|a| a.row_addr).unwrap_or(row_idx as u32) as u16;
+                let col = addr.and_then(|a| a.col_addr).unwrap_or(col_idx as u32) as u16;
+                let span = xml_cell.cell_span.as_ref();
+                let size = xml_cell.cell_sz.as_ref();
+                let width = size.and_then(|s| s.width).unwrap_or(0);
+
+                table.add_cell(
+                    row,
+                    col,
+                    TableCell {
+                        list_header_id: list_id,
+                        col_span: span
+                            .and_then(|s| s.col_span)
+                            .or(xml_cell.col_span)
+                            .unwrap_or(1) as u16,
+                        row_span: span
+                            .and_then(|s| s.row_span)
+                            .or(xml_cell.row_span)
+                            .unwrap_or(1) as u16,
+                        width,
+                        height: size.and_then(|s| s.height).unwrap_or(0),
+                        left_margin: 0,
+                        right_margin: 0,
+                        top_margin: 0,
+                        bottom_margin: 0,
+                        border_fill_id: 0,
+                        text_width: width,
+                        field_name: String::new(),
+                        paragraph_list_id: Some(list_id),
+                        paragraph_count: 0,
+                        cell_address: (row, col),
+                        vertical_align: match xml_cell
+                            .sub_list
+                            .as_ref()
+                            .and_then(|s| s.vert_align.as_deref())
+                        {
+                            Some("CENTER") => VAlign::Center,
+                            Some("BOTTOM") => VAlign::Bottom,
+                            _ => VAlign::Top,
+                        },
+                        is_header: xml_cell.header.as_deref() == Some("1"),
+                    },
+                );
+
+                let cell_start = out.len();
+                if let Some(sub_list) = &xml_cell.sub_list {
+                    for cell_para in &sub_list.paragraphs {
+                        let start = out.len();
+                        Self::convert_paragraph(cell_para, out);
+                        out[start].instance_id = list_id;
+                    }
+                }
+                if let Some(cell) = table.get_cell_mut(row, col) {
+                    cell.paragraph_count = (out.len() - cell_start) as u16;
+                }
+                list_id += 1;
+            }
+        }
+
+        paragraph.control_mask = 1;
+        paragraph.ctrl_header = Some(CtrlHeader {
+            ctrl_id: ControlType::Table as u32,
+            properties: 0,
+            instance_id: xml_table.id.unwrap_or(0),
+        });
+        paragraph.table_data = Some(table);
+    }
+
+    fn extract_hyperlinks(runs: &[Run]) -> Vec<Hyperlink> {
+        let mut hyperlinks = Vec::new();
+        let mut current_pos: u32 = 0;
+
+        for run in runs {
+            let text = run.text.as_deref().unwrap_or("");
+            let length = text.chars().count() as u32;
+
+            for link in run.ctrls.iter().filter_map(|ctrl| ctrl.hyperlink.as_ref()) {
+                let mut hyperlink =
+                    Hyperlink::new_url(text, link.url.as_deref().unwrap_or_default())
+                        .with_position(current_pos)
+                        .with_length(length)
+                        .with_new_window(link.new_window == Some(1));
+                hyperlink.visited = link.visited == Some(1);
+                hyperlinks.push(hyperlink);
+            }
+
+            current_pos += length;
+        }
+
+        hyperlinks
+    }
+
+    fn extract_footnotes(runs: &[Run]) -> Vec<Footnote> {
+        runs.iter()
+            .flat_map(|run| run.ctrls.iter())
+            .filter_map(|ctrl| ctrl.foot_note.as_ref())
+            .map(|note| {
+                let lines: Vec<String> = note
+                    .sub_list
+                    .iter()
+                    .flat_map(|sub_list| sub_list.paragraphs.iter())
+                    .map(|para| Self::extract_text_and_char_shapes(&para.runs).0)
+                    .collect();
+                Footnote {
+                    number: note.number,
+                    text: lines.join("\n"),
+                }
+            })
+            .collect()
+    }
+
     fn parse_color(color_str: &str) -> Option<u32> {
         let color_str = color_str.trim();
         if color_str.starts_with('#') && color_str.len() == 7 {
@@ -315,4 +566,26 @@ mod tests {
         let result = HwpxReader::from_file("nonexistent.hwpx");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_footnotes_and_interleaved_ctrls() {
+        let xml = r#"<sec><p><run charPrIDRef="0">
+            <ctrl><footNote number="1"><subList>
+                <p><run><t>First line</t></run></p>
+                <p><run><t>Second line</t></run></p>
+            </subList></footNote></ctrl>
+            <t>Body</t>
+            <ctrl><footNote number="2"><subList><p><run><t>Note</t></run></p></subList></footNote></ctrl>
+        </run></p></sec>"#;
+        let section = xml_types::parse_section(xml).unwrap();
+        let mut paragraphs = Vec::new();
+        HwpxReader::convert_paragraph(&section.paragraphs[0], &mut paragraphs);
+
+        let footnotes = &paragraphs[0].footnotes;
+        assert_eq!(footnotes.len(), 2);
+        assert_eq!(footnotes[0].number, Some(1));
+        assert_eq!(footnotes[0].text, "First line\nSecond line");
+        assert_eq!(footnotes[1].text, "Note");
+        assert_eq!(paragraphs[0].text(), "Body");
+    }
 }
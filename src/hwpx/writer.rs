@@ -9,7 +9,7 @@ use crate::model::char_shape::CharShape;
 use crate::model::para_char_shape::{CharPositionShape, ParaCharShape};
 use crate::model::paragraph::{ParaText, Paragraph, Section};
 use crate::model::HwpDocument;
-use crate::parser::body_text::BodyText;
+use crate::parser::body_text::{BodyText, SectionSource};
 use crate::parser::doc_info::DocInfo;
 use crate::parser::header::FileHeader;
 
@@ -95,16 +95,10 @@ impl HwpxTextStyle {
         if self.italic {
             properties |= 1 << 1; // Bit 1: Italic
         }
-        if self.underline {
-            properties |= 1 << 2; // Bit 2: Underline
-        }
-        if self.strikethrough {
-            properties |= 1 << 3; // Bit 3: Strikethrough
-        }
 
         let base_size = self.font_size.unwrap_or(10) as i32 * 100; // Convert pt to hwp units
 
-        CharShape {
+        let mut char_shape = CharShape {
             face_name_ids: [0; 7],
             ratios: [100; 7],
             char_spaces: [0; 7],
@@ -119,7 +113,14 @@ impl HwpxTextStyle {
             shade_color: 0xFFFFFF,
             shadow_color: 0x808080,
             border_fill_id: 0,
+        };
+        if self.underline {
+            char_shape.set_underline(Default::default());
+        }
+        if self.strikethrough {
+            char_shape.set_strikethrough();
         }
+        char_shape
     }
 }
 
@@ -374,8 +375,13 @@ pub struct HwpxWriter {
     footers: Vec<HwpxFooter>,
     next_table_id: u32,
     next_image_id: u32,
+    pretty: bool,
+    version: (u8, u8),
 }
 
+/// Format version written to version.xml unless overridden
+const DEFAULT_HWPX_VERSION: (u8, u8) = (5, 1);
+
 impl HwpxWriter {
     pub fn new() -> Self {
         Self {
@@ -386,6 +392,10 @@ impl HwpxWriter {
                 preview_text: None,
                 preview_image: None,
                 summary_info: None,
+                scripts: Vec::new(),
+                history: Vec::new(),
+                xml_template: None,
+                undecrypted_streams: Vec::new(),
             },
             tables: Vec::new(),
             images: Vec::new(),
@@ -394,6 +404,8 @@ impl HwpxWriter {
             footers: Vec::new(),
             next_table_id: 1,
             next_image_id: 1,
+            pretty: false,
+            version: DEFAULT_HWPX_VERSION,
         }
     }
 
@@ -407,6 +419,8 @@ impl HwpxWriter {
             footers: Vec::new(),
             next_table_id: 1,
             next_image_id: 1,
+            pretty: false,
+            version: DEFAULT_HWPX_VERSION,
         }
     }
 
@@ -568,7 +582,13 @@ impl HwpxWriter {
                     paragraphs: vec![paragraph],
                     section_def: None,
                     page_def: None,
+                    column_def: None,
+                    page_border_fills: Vec::new(),
+                    unhandled_controls: 0,
                 }],
+                source: SectionSource::BodyText,
+                raw_xml: None,
+                raw_records: None,
             });
         } else if let Some(body) = self.document.body_texts.first_mut() {
             if let Some(section) = body.sections.first_mut() {
@@ -583,6 +603,16 @@ impl HwpxWriter {
         id
     }
 
+    /// Write indented XML instead of the default minified form
+    pub fn set_pretty(&mut self, pretty: bool) {
+        self.pretty = pretty;
+    }
+
+    /// Set the format version recorded in version.xml (5.1 by default)
+    pub fn set_version(&mut self, major: u8, minor: u8) {
+        self.version = (major, minor);
+    }
+
     pub fn to_bytes(&self) -> Result<Vec<u8>> {
         let mut buffer = Cursor::new(Vec::new());
         self.write_to(&mut buffer)?;
@@ -610,7 +640,7 @@ impl HwpxWriter {
         // version.xml
         zip.start_file("version.xml", deflated)
             .map_err(|e| HwpError::Io(std::io::Error::other(e)))?;
-        zip.write_all(self.generate_version_xml().as_bytes())
+        zip.write_all(self.xml_part(self.generate_version_xml())?.as_bytes())
             .map_err(HwpError::Io)?;
 
         // Contents directory
@@ -620,15 +650,15 @@ impl HwpxWriter {
         // Contents/header.xml
         zip.start_file("Contents/header.xml", deflated)
             .map_err(|e| HwpError::Io(std::io::Error::other(e)))?;
-        zip.write_all(self.generate_header_xml().as_bytes())
+        zip.write_all(self.xml_part(self.generate_header_xml())?.as_bytes())
             .map_err(HwpError::Io)?;
 
         // Contents/section0.xml (and more if multiple sections)
-        for (idx, section_xml) in self.generate_section_xmls().iter().enumerate() {
+        for (idx, section_xml) in self.generate_section_xmls().into_iter().enumerate() {
             let filename = format!("Contents/section{}.xml", idx);
             zip.start_file(&filename, deflated)
                 .map_err(|e| HwpError::Io(std::io::Error::other(e)))?;
-            zip.write_all(section_xml.as_bytes())
+            zip.write_all(self.xml_part(section_xml)?.as_bytes())
                 .map_err(HwpError::Io)?;
         }
 
@@ -661,7 +691,7 @@ impl HwpxWriter {
         // settings.xml
         zip.start_file("settings.xml", deflated)
             .map_err(|e| HwpError::Io(std::io::Error::other(e)))?;
-        zip.write_all(self.generate_settings_xml().as_bytes())
+        zip.write_all(self.xml_part(self.generate_settings_xml())?.as_bytes())
             .map_err(HwpError::Io)?;
 
         // META-INF directory
@@ -671,25 +701,25 @@ impl HwpxWriter {
         // META-INF/container.xml
         zip.start_file("META-INF/container.xml", deflated)
             .map_err(|e| HwpError::Io(std::io::Error::other(e)))?;
-        zip.write_all(self.generate_container_xml().as_bytes())
+        zip.write_all(self.xml_part(self.generate_container_xml())?.as_bytes())
             .map_err(HwpError::Io)?;
 
         // META-INF/manifest.xml
         zip.start_file("META-INF/manifest.xml", deflated)
             .map_err(|e| HwpError::Io(std::io::Error::other(e)))?;
-        zip.write_all(self.generate_manifest_xml().as_bytes())
+        zip.write_all(self.xml_part(self.generate_manifest_xml())?.as_bytes())
             .map_err(HwpError::Io)?;
 
         // META-INF/container.rdf
         zip.start_file("META-INF/container.rdf", deflated)
             .map_err(|e| HwpError::Io(std::io::Error::other(e)))?;
-        zip.write_all(self.generate_container_rdf().as_bytes())
+        zip.write_all(self.xml_part(self.generate_container_rdf())?.as_bytes())
             .map_err(HwpError::Io)?;
 
         // Contents/content.hpf (must be after sections are known)
         zip.start_file("Contents/content.hpf", deflated)
             .map_err(|e| HwpError::Io(std::io::Error::other(e)))?;
-        zip.write_all(self.generate_content_hpf().as_bytes())
+        zip.write_all(self.xml_part(self.generate_content_hpf())?.as_bytes())
             .map_err(HwpError::Io)?;
 
         if !self.images.is_empty() {
@@ -710,15 +740,27 @@ impl HwpxWriter {
         Ok(())
     }
 
+    /// Apply the configured output form to a generated XML part
+    fn xml_part(&self, xml: String) -> Result<String> {
+        if self.pretty {
+            indent_xml(&xml)
+        } else {
+            Ok(xml)
+        }
+    }
+
     fn generate_version_xml(&self) -> String {
-        concat!(
-            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>"#,
-            r#"<hv:HCFVersion xmlns:hv="http://www.hancom.co.kr/hwpml/2011/version" "#,
-            r#"tagetApplication="WORDPROCESSOR" major="5" minor="1" micro="1" "#,
-            r#"buildNumber="0" os="1" xmlVersion="1.5" application="Hancom Office Hangul" "#,
-            r#"appVersion="12, 0, 0, 0"/>"#
+        let (major, minor) = self.version;
+        format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>"#,
+                r#"<hv:HCFVersion xmlns:hv="http://www.hancom.co.kr/hwpml/2011/version" "#,
+                r#"tagetApplication="WORDPROCESSOR" major="{}" minor="{}" micro="0" "#,
+                r#"buildNumber="0" os="1" xmlVersion="1.5" application="Hancom Office Hangul" "#,
+                r#"appVersion="12, 0, 0, 0"/>"#
+            ),
+            major, minor
         )
-        .to_string()
     }
 
     fn generate_settings_xml(&self) -> String {
@@ -1517,6 +1559,44 @@ fn escape_xml(s: &str) -> String {
         .replace('\'', "&apos;")
 }
 
+/// Re-indent minified XML, two spaces per level
+///
+/// Elements holding text stay on one line and empty start/end pairs become
+/// empty elements, so no whitespace is added to any text content.
+fn indent_xml(xml: &str) -> Result<String> {
+    use quick_xml::events::Event;
+
+    let format_error = |e: &dyn std::fmt::Display| {
+        HwpError::ParseError(format!("Failed to format generated XML: {e}"))
+    };
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut writer = quick_xml::Writer::new_with_indent(Vec::new(), b' ', 2);
+    let mut pending_start = None;
+
+    loop {
+        let event = reader.read_event().map_err(|e| format_error(&e))?;
+        let event = match (pending_start.take(), event) {
+            (_, Event::Eof) => break,
+            (Some(start), Event::End(_)) => Event::Empty(start),
+            (pending, event) => {
+                if let Some(start) = pending {
+                    writer
+                        .write_event(Event::Start(start))
+                        .map_err(|e| format_error(&e))?;
+                }
+                if let Event::Start(start) = event {
+                    pending_start = Some(start.into_owned());
+                    continue;
+                }
+                event
+            }
+        };
+        writer.write_event(event).map_err(|e| format_error(&e))?;
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(|e| format_error(&e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
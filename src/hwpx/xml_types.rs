@@ -135,6 +135,9 @@ pub struct XmlParagraph {
     pub style_id_ref: Option<u32>,
     #[serde(rename = "run", default)]
     pub runs: Vec<Run>,
+    /// Tables placed directly in the paragraph rather than inside a run
+    #[serde(rename = "tbl", default)]
+    pub tables: Vec<XmlTable>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -149,6 +152,34 @@ pub struct Run {
     pub table: Option<XmlTable>,
     #[serde(rename = "pic", default)]
     pub picture: Option<XmlPicture>,
+    #[serde(rename = "ctrl", default)]
+    pub ctrls: Vec<Ctrl>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Ctrl {
+    #[serde(rename = "hyperlink", default)]
+    pub hyperlink: Option<XmlHyperlink>,
+    #[serde(rename = "footNote", default)]
+    pub foot_note: Option<XmlFootNote>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct XmlHyperlink {
+    #[serde(rename = "@url", default)]
+    pub url: Option<String>,
+    #[serde(rename = "@visited", default)]
+    pub visited: Option<u32>,
+    #[serde(rename = "@new_window", default)]
+    pub new_window: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct XmlFootNote {
+    #[serde(rename = "@number", default)]
+    pub number: Option<u32>,
+    #[serde(rename = "subList", default)]
+    pub sub_list: Option<SubList>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -197,6 +228,14 @@ pub struct PagePr {
 pub struct XmlTable {
     #[serde(rename = "@id", default)]
     pub id: Option<u32>,
+    #[serde(rename = "@rowCnt", default)]
+    pub row_cnt: Option<u32>,
+    #[serde(rename = "@colCnt", default)]
+    pub col_cnt: Option<u32>,
+    #[serde(rename = "@cellSpacing", default)]
+    pub cell_spacing: Option<u32>,
+    #[serde(rename = "@repeatHeader", default)]
+    pub repeat_header: Option<String>,
     #[serde(rename = "tr", default)]
     pub rows: Vec<XmlTableRow>,
 }
@@ -209,6 +248,8 @@ pub struct XmlTableRow {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct XmlTableCell {
+    #[serde(rename = "@header", default)]
+    pub header: Option<String>,
     #[serde(rename = "@colSpan", default)]
     pub col_span: Option<u32>,
     #[serde(rename = "@rowSpan", default)]
@@ -217,6 +258,26 @@ pub struct XmlTableCell {
     pub cell_addr: Option<CellAddr>,
     #[serde(rename = "subList", default)]
     pub sub_list: Option<SubList>,
+    #[serde(rename = "cellSpan", default)]
+    pub cell_span: Option<CellSpan>,
+    #[serde(rename = "cellSz", default)]
+    pub cell_sz: Option<CellSz>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CellSpan {
+    #[serde(rename = "@colSpan", default)]
+    pub col_span: Option<u32>,
+    #[serde(rename = "@rowSpan", default)]
+    pub row_span: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CellSz {
+    #[serde(rename = "@width", default)]
+    pub width: Option<u32>,
+    #[serde(rename = "@height", default)]
+    pub height: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -229,6 +290,8 @@ pub struct CellAddr {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SubList {
+    #[serde(rename = "@vertAlign", default)]
+    pub vert_align: Option<String>,
     #[serde(rename = "p", default)]
     pub paragraphs: Vec<XmlParagraph>,
 }
@@ -12,33 +12,287 @@ pub mod writer;
 
 use std::io::{Read, Seek};
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 pub use crate::crypto::decrypt_distribution_stream;
 pub use crate::error::{HwpError, Result};
 pub use crate::hwpx::{HwpxReader, HwpxWriter};
 pub use crate::model::HwpDocument;
-use crate::parser::{body_text::BodyTextParser, doc_info::DocInfoParser, header::FileHeader};
-pub use crate::preview::{PreviewImage, PreviewText, SummaryInfo};
-pub use crate::rag::{extract_text_for_rag, normalize_text};
+use crate::parser::{
+    body_text::{BodyTextParser, SectionSource},
+    doc_info::DocInfoParser,
+    header::FileHeader,
+};
+pub use crate::preview::{DocStatistics, DocumentMetadata, PreviewImage, PreviewText, SummaryInfo};
+pub use crate::rag::{
+    chunk_text_with_headings, deduplicate_paragraphs, extract_text_for_rag,
+    extract_text_for_rag_with, extract_under_heading, looks_like_mojibake, normalize_text,
+    normalize_text_with, NormalizeOptions, RagOptions, ShortPolicy,
+};
 use crate::reader::CfbReader;
 pub use crate::writer::style;
 pub use crate::writer::HwpWriter;
 
+/// Section stream prefix of regular documents
+const BODY_TEXT_PREFIX: &str = "BodyText/Section";
+/// Section stream prefix of distribution documents
+const VIEW_TEXT_PREFIX: &str = "ViewText/Section";
+/// Record header of DISTRIBUTE_DOC_DATA: tag 0x1C, 256 bytes
+const DISTRIBUTE_DOC_DATA_HEADER: [u8; 4] = (0x1Cu32 | (256 << 20)).to_le_bytes();
+
+/// Options controlling which optional streams [`HwpReader`] loads
+#[derive(Debug, Clone)]
+pub struct ReaderOptions {
+    /// Read the PrvText and PrvImage preview streams
+    pub load_previews: bool,
+    /// Read the HwpSummaryInformation stream
+    pub load_summary: bool,
+    /// Largest record data size accepted, in bytes
+    pub max_record_size: usize,
+}
+
+impl Default for ReaderOptions {
+    fn default() -> Self {
+        Self {
+            load_previews: true,
+            load_summary: true,
+            max_record_size: parser::DEFAULT_MAX_RECORD_SIZE,
+        }
+    }
+}
+
+impl ReaderOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether preview streams are read
+    pub fn load_previews(mut self, load: bool) -> Self {
+        self.load_previews = load;
+        self
+    }
+
+    /// Set whether the summary information stream is read
+    pub fn load_summary(mut self, load: bool) -> Self {
+        self.load_summary = load;
+        self
+    }
+
+    /// Set the largest record data size accepted, in bytes
+    ///
+    /// Records declaring more data fail to parse instead of being allocated.
+    pub fn max_record_size(mut self, max_record_size: usize) -> Self {
+        self.max_record_size = max_record_size;
+        self
+    }
+}
+
+/// Time spent on one stream by [`HwpReader::from_file_timed`]
+#[derive(Debug, Clone, Default)]
+pub struct StreamTiming {
+    pub stream: String,
+    pub decryption: Duration,
+    pub decompression: Duration,
+    pub parsing: Duration,
+}
+
+impl StreamTiming {
+    fn new(stream: &str) -> Self {
+        Self {
+            stream: stream.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Where [`HwpReader::from_file_timed`] spent its time
+#[derive(Debug, Clone, Default)]
+pub struct ParseMetrics {
+    /// DocInfo and section streams, in reading order
+    pub streams: Vec<StreamTiming>,
+    /// Wall time of the whole read, including the streams not listed
+    pub total: Duration,
+}
+
+impl ParseMetrics {
+    pub fn decryption(&self) -> Duration {
+        self.streams.iter().map(|s| s.decryption).sum()
+    }
+
+    pub fn decompression(&self) -> Duration {
+        self.streams.iter().map(|s| s.decompression).sum()
+    }
+
+    pub fn parsing(&self) -> Duration {
+        self.streams.iter().map(|s| s.parsing).sum()
+    }
+}
+
+/// Storage details of one stream, from [`HwpReader::stream_info`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamInfo {
+    pub name: String,
+    /// Bytes stored in the compound file
+    pub compressed_size: usize,
+    /// Bytes after decryption and decompression
+    pub decompressed_size: usize,
+    /// Stored as deflate data
+    pub is_compressed: bool,
+    /// Encrypted with the key of a distribution document
+    pub is_encrypted: bool,
+}
+
+/// Streams stored uncompressed even when the document is compressed
+const UNCOMPRESSED_STREAMS: [&str; 2] = ["FileHeader", "\u{5}HwpSummaryInformation"];
+
+/// Run `f`, adding its duration to `slot` when timing is enabled
+fn timed<T>(slot: Option<&mut Duration>, f: impl FnOnce() -> T) -> T {
+    match slot {
+        Some(slot) => {
+            let start = Instant::now();
+            let value = f();
+            *slot += start.elapsed();
+            value
+        }
+        None => f(),
+    }
+}
+
 pub struct HwpReader;
 
 impl HwpReader {
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<HwpDocument> {
-        let reader = CfbReader::from_file(path)?;
-        Self::parse_document(reader)
+        Self::from_file_with_options(path, &ReaderOptions::default())
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Result<HwpDocument> {
+        Self::from_bytes_with_options(bytes, &ReaderOptions::default())
+    }
+
+    pub fn from_file_with_options<P: AsRef<Path>>(
+        path: P,
+        options: &ReaderOptions,
+    ) -> Result<HwpDocument> {
+        let reader = CfbReader::from_file(path)?;
+        Self::parse_document(reader, options, None)
+    }
+
+    /// Read a file, also reporting the time spent on each stream
+    pub fn from_file_timed<P: AsRef<Path>>(path: P) -> Result<(HwpDocument, ParseMetrics)> {
+        let start = Instant::now();
+        let mut metrics = ParseMetrics::default();
+        let reader = CfbReader::from_file(path)?;
+        let document = Self::parse_document(reader, &ReaderOptions::default(), Some(&mut metrics))?;
+        metrics.total = start.elapsed();
+        Ok((document, metrics))
+    }
+
+    /// Check whether a file holds at least `min_chars` non-whitespace characters
+    ///
+    /// Only the section streams are parsed, and reading stops as soon as
+    /// enough text has been seen.
+    pub fn has_text<P: AsRef<Path>>(path: P, min_chars: usize) -> Result<bool> {
+        let mut reader = CfbReader::from_file(path)?;
+        let header = FileHeader::parse(reader.read_stream("FileHeader")?)?;
+
+        if header.is_encrypted() {
+            return Err(HwpError::UnsupportedVersion(
+                "Password-encrypted documents are not supported".to_string(),
+            ));
+        }
+
+        let distribution_record = if header.is_distribute() {
+            Some(Self::read_distribution_record(
+                &mut reader,
+                header.is_compressed(),
+            )?)
+        } else {
+            None
+        };
+
+        let stream_prefix = Self::section_stream_prefix(&reader, header.is_distribute());
+        let mut seen = 0;
+
+        for section_idx in 0.. {
+            let section_name = format!("{stream_prefix}{section_idx}");
+            if !reader.stream_exists(&section_name) {
+                break;
+            }
+
+            let section_data = reader.read_stream(&section_name)?;
+            let section_decrypted =
+                Self::decrypt_stream(section_data, &header, distribution_record.as_deref())?;
+            if header.is_distribute()
+                && !Self::decrypted_stream_ok(&section_decrypted, header.is_compressed())
+            {
+                continue;
+            }
+            let section_records =
+                Self::decompress_records(section_decrypted, header.is_compressed())
+                    .map_err(|e| e.in_stream(&section_name, 0))?;
+            let body_text = BodyTextParser::parse(section_records, false)?;
+
+            let texts = body_text
+                .sections
+                .iter()
+                .flat_map(|section| section.paragraphs.iter())
+                .filter_map(|paragraph| paragraph.text.as_ref());
+            for text in texts {
+                seen += text
+                    .content
+                    .chars()
+                    .filter(|c| !c.is_whitespace() && !c.is_control())
+                    .count();
+                if seen >= min_chars {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(seen >= min_chars)
+    }
+
+    /// Report the stored and decoded size of the stream `name`, e.g. `BodyText/Section0`
+    pub fn stream_info<P: AsRef<Path>>(path: P, name: &str) -> Result<StreamInfo> {
+        let mut reader = CfbReader::from_file(path)?;
+        let header = FileHeader::parse(reader.read_stream("FileHeader")?)?;
+        let name = name.trim_start_matches('/');
+        let data = reader.read_stream(name)?;
+
+        // Encrypted streams start with the distribution record holding their key
+        let is_encrypted = header.is_distribute()
+            && data.len() >= 260
+            && data.get(..4) == Some(&DISTRIBUTE_DOC_DATA_HEADER[..]);
+        let decrypted = if is_encrypted {
+            decrypt_distribution_stream(&data[260..], &data[..260])?
+        } else {
+            data.clone()
+        };
+        let decompressed = (header.is_compressed() && !UNCOMPRESSED_STREAMS.contains(&name))
+            .then(|| crate::utils::compression::decompress_stream(&decrypted).ok())
+            .flatten()
+            .filter(|decompressed| *decompressed != decrypted);
+
+        Ok(StreamInfo {
+            name: name.to_string(),
+            compressed_size: data.len(),
+            decompressed_size: decompressed.as_ref().unwrap_or(&decrypted).len(),
+            is_compressed: decompressed.is_some(),
+            is_encrypted,
+        })
+    }
+
+    pub fn from_bytes_with_options(bytes: &[u8], options: &ReaderOptions) -> Result<HwpDocument> {
         let cursor = std::io::Cursor::new(bytes.to_vec());
         let reader = CfbReader::new(cursor)?;
-        Self::parse_document(reader)
+        Self::parse_document(reader, options, None)
     }
 
-    fn parse_document<F: Read + Seek>(mut reader: CfbReader<F>) -> Result<HwpDocument> {
+    fn parse_document<F: Read + Seek>(
+        mut reader: CfbReader<F>,
+        options: &ReaderOptions,
+        mut metrics: Option<&mut ParseMetrics>,
+    ) -> Result<HwpDocument> {
         let header_data = reader.read_stream("FileHeader")?;
         let header = FileHeader::parse(header_data)?;
 
@@ -57,19 +311,28 @@ impl HwpReader {
             None
         };
 
+        let mut timing = metrics.is_some().then(|| StreamTiming::new("DocInfo"));
         let doc_info_data = reader.read_stream("DocInfo")?;
-        let doc_info_decrypted =
-            Self::decrypt_stream(doc_info_data, &header, distribution_record.as_deref())?;
-        let doc_info = DocInfoParser::parse(doc_info_decrypted, header.is_compressed())?;
+        let doc_info_decrypted = timed(timing.as_mut().map(|t| &mut t.decryption), || {
+            Self::decrypt_stream(doc_info_data, &header, distribution_record.as_deref())
+        })?;
+        let doc_info_records = timed(timing.as_mut().map(|t| &mut t.decompression), || {
+            Self::decompress_records(doc_info_decrypted, header.is_compressed())
+                .map_err(|e| e.in_stream("DocInfo", 0))
+        })?;
+        let mut doc_info = timed(timing.as_mut().map(|t| &mut t.parsing), || {
+            DocInfoParser::parse_with_limit(doc_info_records, false, options.max_record_size)
+        })?;
+        if let (Some(metrics), Some(timing)) = (metrics.as_deref_mut(), timing) {
+            metrics.streams.push(timing);
+        }
+        Self::read_bin_data(&mut reader, &mut doc_info.bin_data, header.is_compressed());
 
         let mut body_texts = Vec::new();
+        let mut undecrypted_streams = Vec::new();
         let mut section_idx = 0;
 
-        let stream_prefix = if header.is_distribute() {
-            "ViewText/Section"
-        } else {
-            "BodyText/Section"
-        };
+        let stream_prefix = Self::section_stream_prefix(&reader, header.is_distribute());
 
         loop {
             let section_name = format!("{stream_prefix}{section_idx}");
@@ -77,24 +340,71 @@ impl HwpReader {
                 break;
             }
 
+            let mut timing = metrics.is_some().then(|| StreamTiming::new(&section_name));
             let section_data = reader.read_stream(&section_name)?;
-            let section_decrypted =
-                Self::decrypt_stream(section_data, &header, distribution_record.as_deref())?;
-            let body_text = BodyTextParser::parse(section_decrypted, header.is_compressed())?;
+            let section_decrypted = timed(timing.as_mut().map(|t| &mut t.decryption), || {
+                Self::decrypt_stream(section_data, &header, distribution_record.as_deref())
+            })?;
+            if header.is_distribute()
+                && !Self::decrypted_stream_ok(&section_decrypted, header.is_compressed())
+            {
+                undecrypted_streams.push(section_name);
+                section_idx += 1;
+                continue;
+            }
+            let section_records = timed(timing.as_mut().map(|t| &mut t.decompression), || {
+                Self::decompress_records(section_decrypted, header.is_compressed())
+                    .map_err(|e| e.in_stream(&section_name, 0))
+            })?;
+            let mut body_text = timed(timing.as_mut().map(|t| &mut t.parsing), || {
+                BodyTextParser::parse_with_limit(section_records, false, options.max_record_size)
+            })
+            .map_err(|e| match e {
+                HwpError::StreamParse { offset, .. } => e.in_stream(&section_name, offset),
+                other => other,
+            })?;
+            body_text.source = if stream_prefix == VIEW_TEXT_PREFIX {
+                SectionSource::ViewText
+            } else {
+                SectionSource::BodyText
+            };
             body_texts.push(body_text);
+            if let (Some(metrics), Some(timing)) = (metrics.as_deref_mut(), timing) {
+                metrics.streams.push(timing);
+            }
 
             section_idx += 1;
         }
 
         if body_texts.is_empty() {
+            if let Some(stream) = undecrypted_streams.first() {
+                return Err(HwpError::InvalidFormat(format!(
+                    "Could not decrypt distribution stream {stream}"
+                )));
+            }
             return Err(HwpError::InvalidFormat(
                 "No BodyText sections found".to_string(),
             ));
         }
 
-        let preview_text = Self::read_preview_text(&mut reader).ok();
-        let preview_image = Self::read_preview_image(&mut reader).ok();
-        let summary_info = Self::read_summary_info(&mut reader).ok();
+        let (preview_text, preview_image) = if options.load_previews {
+            (
+                Self::read_preview_text(&mut reader).ok(),
+                Self::read_preview_image(&mut reader).ok(),
+            )
+        } else {
+            (None, None)
+        };
+        let summary_info = options
+            .load_summary
+            .then(|| Self::read_summary_info(&mut reader).ok())
+            .flatten();
+        let scripts = Self::read_scripts(&mut reader);
+        let history = Self::read_history(&mut reader, header.is_compressed());
+        let xml_template = header
+            .is_xml_template()
+            .then(|| Self::read_xml_template(&mut reader))
+            .flatten();
 
         Ok(HwpDocument {
             header,
@@ -103,9 +413,68 @@ impl HwpReader {
             preview_text,
             preview_image,
             summary_info,
+            scripts,
+            history,
+            xml_template,
+            undecrypted_streams,
         })
     }
 
+    /// Decompress a record stream if the document is compressed
+    fn decompress_records(data: Vec<u8>, is_compressed: bool) -> Result<Vec<u8>> {
+        if is_compressed {
+            crate::utils::compression::decompress_stream(&data)
+        } else {
+            Ok(data)
+        }
+    }
+
+    /// Find the storage holding the section streams
+    ///
+    /// Distributed documents normally use `ViewText` and others `BodyText`, but some
+    /// producers use the other storage, so every known prefix is tried in order.
+    fn section_stream_prefix<F: Read + Seek>(
+        reader: &CfbReader<F>,
+        is_distribute: bool,
+    ) -> &'static str {
+        let expected = if is_distribute {
+            VIEW_TEXT_PREFIX
+        } else {
+            BODY_TEXT_PREFIX
+        };
+
+        let found = [expected, BODY_TEXT_PREFIX, VIEW_TEXT_PREFIX]
+            .into_iter()
+            .find(|prefix| reader.stream_exists(&format!("{prefix}0")));
+
+        match found {
+            Some(prefix) if prefix != expected => {
+                log::warn!("Section streams not found under {expected}, using {prefix}");
+                prefix
+            }
+            _ => expected,
+        }
+    }
+
+    /// Load the content of each BinData entry from its stream, decompressing as needed
+    fn read_bin_data<F: Read + Seek>(
+        reader: &mut CfbReader<F>,
+        bin_data: &mut [crate::model::bin_data::BinData],
+        document_compressed: bool,
+    ) {
+        for entry in bin_data.iter_mut().filter(|entry| entry.is_stored()) {
+            let Ok(data) = reader.read_stream(&format!("BinData/{}", entry.stream_name())) else {
+                continue;
+            };
+            entry.data = if entry.is_stream_compressed(document_compressed) {
+                // Some writers store raw data despite the flag
+                crate::utils::compression::decompress_stream(&data).unwrap_or(data)
+            } else {
+                data
+            };
+        }
+    }
+
     fn read_preview_text<F: Read + Seek>(reader: &mut CfbReader<F>) -> Result<PreviewText> {
         let data = reader.read_stream("PrvText")?;
         PreviewText::from_bytes(&data)
@@ -121,6 +490,79 @@ impl HwpReader {
         SummaryInfo::from_bytes(&data)
     }
 
+    /// Read the schema of the XMLTemplate storage
+    ///
+    /// The stream holds a length-prefixed UTF-16LE string, deflated by some writers.
+    fn read_xml_template<F: Read + Seek>(reader: &mut CfbReader<F>) -> Option<String> {
+        fn decode(data: &[u8]) -> Option<String> {
+            let len = u32::from_le_bytes(data.get(..4)?.try_into().ok()?) as usize;
+            let bytes = data.get(4..4 + len.checked_mul(2)?)?;
+            let units: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+                .collect();
+            Some(String::from_utf16_lossy(&units))
+        }
+
+        let data = reader.read_stream("XMLTemplate/Schema").ok()?;
+        decode(&data).or_else(|| {
+            let inflated = crate::utils::compression::decompress_stream(&data).ok()?;
+            decode(&inflated)
+        })
+    }
+
+    fn read_scripts<F: Read + Seek>(reader: &mut CfbReader<F>) -> Vec<crate::model::Script> {
+        let script_streams: Vec<String> = reader
+            .list_streams()
+            .into_iter()
+            .filter(|name| name.trim_start_matches('/').starts_with("Scripts/"))
+            .filter(|name| !name.ends_with("JScriptVersion"))
+            .collect();
+
+        script_streams
+            .iter()
+            .filter_map(|name| {
+                let data = reader.read_stream(name).ok()?;
+                crate::model::Script::from_bytes(name.trim_start_matches('/'), &data).ok()
+            })
+            .collect()
+    }
+
+    /// Read the revisions of every `DocHistory/VersionLog<n>` stream, in log order
+    ///
+    /// Streams that fail to parse are skipped.
+    fn read_history<F: Read + Seek>(
+        reader: &mut CfbReader<F>,
+        is_compressed: bool,
+    ) -> Vec<crate::model::HistoryEntry> {
+        let mut logs: Vec<(u32, String)> = reader
+            .list_streams()
+            .into_iter()
+            .filter_map(|name| {
+                let number = name
+                    .trim_start_matches('/')
+                    .strip_prefix("DocHistory/VersionLog")?
+                    .parse()
+                    .ok()?;
+                Some((number, name))
+            })
+            .collect();
+        logs.sort();
+
+        logs.iter()
+            .filter_map(|(_, name)| {
+                let data = reader.read_stream(name).ok()?;
+                let data = if is_compressed {
+                    crate::utils::compression::decompress_stream(&data).ok()?
+                } else {
+                    data
+                };
+                crate::model::HistoryEntry::parse_stream(data).ok()
+            })
+            .flatten()
+            .collect()
+    }
+
     fn read_distribution_record<F: Read + Seek>(
         reader: &mut CfbReader<F>,
         is_compressed: bool,
@@ -128,7 +570,7 @@ impl HwpReader {
         let doc_info_data = reader.read_stream("DocInfo")?;
 
         let decompressed = if is_compressed {
-            crate::utils::decompress(&doc_info_data)?
+            crate::utils::decompress(&doc_info_data).map_err(|e| e.in_stream("DocInfo", 0))?
         } else {
             doc_info_data
         };
@@ -142,6 +584,22 @@ impl HwpReader {
         Ok(decompressed[..260].to_vec())
     }
 
+    /// Self-check of a decrypted stream: with the wrong key it does not
+    /// decompress, or does not start with a top-level record
+    fn decrypted_stream_ok(data: &[u8], is_compressed: bool) -> bool {
+        let records = if is_compressed {
+            match crate::utils::compression::decompress_stream(data) {
+                Ok(records) => records,
+                Err(_) => return false,
+            }
+        } else {
+            data.to_vec()
+        };
+        crate::parser::RecordReader::new(records)
+            .next()
+            .is_some_and(|record| record.tag_id() >= 0x10 && record.level() == 0)
+    }
+
     fn decrypt_stream(
         data: Vec<u8>,
         _header: &FileHeader,
@@ -1,5 +1,6 @@
 use crate::error::Result;
 use crate::parser::record::Record;
+use crate::reader::StreamReader;
 
 #[derive(Debug, Clone)]
 pub struct BinData {
@@ -11,6 +12,23 @@ pub struct BinData {
     pub data: Vec<u8>,
 }
 
+/// An image stored in the document, with its size on the page and in pixels
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddedImage {
+    pub bin_id: u16,
+    pub extension: String,
+    /// Display width from the picture control, if the image is placed
+    pub display_width_mm: Option<f32>,
+    /// Display height from the picture control, if the image is placed
+    pub display_height_mm: Option<f32>,
+    /// Intrinsic width decoded from the image header
+    pub pixel_width: Option<u32>,
+    /// Intrinsic height decoded from the image header
+    pub pixel_height: Option<u32>,
+    /// Caption of the picture control showing the image
+    pub caption: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum BinDataType {
     Link = 0,
@@ -19,10 +37,15 @@ pub enum BinDataType {
 }
 
 impl BinData {
+    /// Parse a BinData record
+    ///
+    /// LINK entries carry the absolute and relative file names; EMBEDDING
+    /// and STORAGE entries carry the id of their stream, and EMBEDDING
+    /// entries also its extension.
     pub fn from_record(record: &Record) -> Result<Self> {
         let mut reader = record.data_reader();
 
-        if reader.remaining() < 6 {
+        if reader.remaining() < 4 {
             return Err(crate::error::HwpError::ParseError(format!(
                 "BinData record too small: {} bytes",
                 reader.remaining()
@@ -30,37 +53,31 @@ impl BinData {
         }
 
         let properties = reader.read_u16()?;
-
-        // Read absolute file name
-        let abs_name_len = reader.read_u16()? as usize;
-        let abs_name = if reader.remaining() >= abs_name_len * 2 {
-            reader.read_string(abs_name_len * 2)?
-        } else {
-            "bindata.dat".to_string()
-        };
-
-        // Read relative file name
-        let rel_name_len = reader.read_u16()? as usize;
-        let rel_name = if reader.remaining() >= rel_name_len * 2 {
-            reader.read_string(rel_name_len * 2)?
-        } else {
-            "bindata.dat".to_string()
-        };
-
-        if reader.remaining() < 2 {
-            return Err(crate::error::HwpError::ParseError(
-                "Insufficient data for BinData ID".to_string(),
-            ));
+        fn read_name(reader: &mut StreamReader, fallback: &str) -> Result<String> {
+            let len = reader.read_u16()? as usize * 2;
+            if reader.remaining() >= len {
+                reader.read_string(len)
+            } else {
+                Ok(fallback.to_string())
+            }
         }
 
-        let bin_id = reader.read_u16()?;
-
-        // Read extension
-        let extension_len = reader.read_u16()? as usize;
-        let extension = if reader.remaining() >= extension_len * 2 {
-            reader.read_string(extension_len * 2)?
-        } else {
-            "dat".to_string()
+        let (abs_name, rel_name, bin_id, extension) = match properties & 0x03 {
+            0 => (
+                read_name(&mut reader, "bindata.dat")?,
+                read_name(&mut reader, "bindata.dat")?,
+                0,
+                String::new(),
+            ),
+            kind => {
+                let bin_id = reader.read_u16()?;
+                let extension = if kind == BinDataType::Embedding as u16 {
+                    read_name(&mut reader, "dat")?
+                } else {
+                    String::new()
+                };
+                (String::new(), String::new(), bin_id, extension)
+            }
         };
 
         // Read remaining data as binary content
@@ -88,6 +105,11 @@ impl BinData {
         }
     }
 
+    /// Whether the content is kept in a `BinData` stream rather than linked
+    pub fn is_stored(&self) -> bool {
+        !matches!(self.get_type(), BinDataType::Link)
+    }
+
     pub fn is_compressed(&self) -> bool {
         (self.properties & 0x04) != 0
     }
@@ -103,10 +125,39 @@ impl BinData {
         )
     }
 
+    /// Whether this entry is an embedded TrueType/OpenType font
+    pub fn is_font(&self) -> bool {
+        matches!(
+            self.extension.to_lowercase().as_str(),
+            "ttf" | "ttc" | "otf"
+        )
+    }
+
     pub fn is_ole_object(&self) -> bool {
         self.extension.to_lowercase() == "ole"
     }
 
+    /// Name of the stream holding this entry inside the `BinData` storage
+    pub fn stream_name(&self) -> String {
+        format!("BIN{:04X}.{}", self.bin_id, self.extension)
+    }
+
+    /// Whether the stream is deflated, from bits 4-5 of the properties
+    /// (0 follows the document setting, 1 compressed, 2 stored raw)
+    pub(crate) fn is_stream_compressed(&self, document_compressed: bool) -> bool {
+        match (self.properties >> 4) & 0x03 {
+            1 => true,
+            2 => false,
+            _ => document_compressed,
+        }
+    }
+
+    /// Intrinsic size in pixels, decoded from a PNG, GIF, BMP or JPEG header
+    pub fn pixel_dimensions(&self) -> Option<(u32, u32)> {
+        let data = self.get_data().ok()?;
+        image_dimensions(&data)
+    }
+
     pub fn get_data(&self) -> Result<Vec<u8>> {
         if self.is_compressed() {
             // Decompress the data if needed
@@ -116,3 +167,51 @@ impl BinData {
         }
     }
 }
+
+/// Read the pixel size from the header of common image formats
+fn image_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let be_u32 = |i: usize| Some(u32::from_be_bytes(data.get(i..i + 4)?.try_into().ok()?));
+    let le_u16 = |i: usize| Some(u16::from_le_bytes(data.get(i..i + 2)?.try_into().ok()?) as u32);
+    let le_i32 = |i: usize| Some(i32::from_le_bytes(data.get(i..i + 4)?.try_into().ok()?));
+
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        // IHDR is the first chunk: width and height follow its type
+        return Some((be_u32(16)?, be_u32(20)?));
+    }
+    if data.starts_with(b"GIF8") {
+        return Some((le_u16(6)?, le_u16(8)?));
+    }
+    if data.starts_with(b"BM") {
+        // BITMAPINFOHEADER; a negative height marks a top-down bitmap
+        return Some((le_i32(18)?.unsigned_abs(), le_i32(22)?.unsigned_abs()));
+    }
+    if data.starts_with(&[0xFF, 0xD8]) {
+        return jpeg_dimensions(data);
+    }
+    None
+}
+
+/// Walk the JPEG markers up to the first start-of-frame segment
+fn jpeg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let mut pos = 2;
+    while pos + 4 <= data.len() {
+        if data[pos] != 0xFF {
+            return None;
+        }
+        let marker = data[pos + 1];
+        if marker == 0xFF {
+            pos += 1; // fill byte
+            continue;
+        }
+        let length = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        // SOF0-SOF15, except DHT (C4), JPG (C8) and DAC (CC)
+        if (0xC0..=0xCF).contains(&marker) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+            let frame = data.get(pos + 5..pos + 9)?;
+            let height = u16::from_be_bytes([frame[0], frame[1]]) as u32;
+            let width = u16::from_be_bytes([frame[2], frame[3]]) as u32;
+            return Some((width, height));
+        }
+        pos += 2 + length;
+    }
+    None
+}
@@ -187,9 +187,35 @@ impl BorderFill {
             },
         }
     }
+
+    /// Resolve a BorderFill reference from a shape or page definition
+    ///
+    /// References count from 1 in DocInfo order; 0 means no border fill.
+    pub(crate) fn from_reference(border_fills: &[Self], id: u16) -> Option<&Self> {
+        border_fills.get((id as usize).checked_sub(1)?)
+    }
 }
 
+/// Line widths in millimeters, indexed by the `thickness` field of a border line
+pub const LINE_WIDTHS_MM: [f32; 16] = [
+    0.1, 0.12, 0.15, 0.2, 0.25, 0.3, 0.4, 0.5, 0.6, 0.7, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0,
+];
+
 impl BorderLine {
+    /// The `thickness` index of the supported line width closest to `mm`
+    pub fn thickness_for_mm(mm: f32) -> u8 {
+        LINE_WIDTHS_MM
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - mm).abs().total_cmp(&(*b - mm).abs()))
+            .map_or(0, |(index, _)| index as u8)
+    }
+
+    /// Line width in millimeters, if `thickness` is a known index
+    pub fn width_mm(&self) -> Option<f32> {
+        LINE_WIDTHS_MM.get(self.thickness as usize).copied()
+    }
+
     fn read(reader: &mut StreamReader) -> Result<Self> {
         if reader.remaining() < 6 {
             return Err(crate::error::HwpError::ParseError(
@@ -1,7 +1,42 @@
 use crate::error::Result;
 use crate::parser::record::Record;
 
-#[derive(Debug, Clone)]
+/// Line shape of an underline, as stored in bits 4-7 of CharShape properties
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnderlineStyle {
+    #[default]
+    Solid = 0,
+    Dash = 1,
+    Dot = 2,
+    DashDot = 3,
+    DashDotDot = 4,
+    LongDash = 5,
+    Circle = 6,
+    Double = 7,
+    Wave = 11,
+}
+
+impl UnderlineStyle {
+    /// Decode a line shape value, treating unknown shapes as solid
+    pub fn from_shape(value: u8) -> Self {
+        match value {
+            1 => Self::Dash,
+            2 => Self::Dot,
+            3 => Self::DashDot,
+            4 => Self::DashDotDot,
+            5 => Self::LongDash,
+            6 => Self::Circle,
+            7 => Self::Double,
+            11 => Self::Wave,
+            _ => Self::Solid,
+        }
+    }
+}
+
+/// Underline kind (bits 2-3) placing the line below the text
+const UNDERLINE_BELOW: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CharShape {
     pub face_name_ids: [u16; 7],
     pub ratios: [u8; 7],
@@ -31,13 +66,29 @@ impl CharShape {
     }
 
     pub fn is_underline(&self) -> bool {
-        // Underline type is bits 2-4, non-zero means underlined
-        (self.properties >> 2) & 0x7 != 0
+        // Underline kind is bits 2-3, non-zero means underlined
+        (self.properties >> 2) & 0x3 != 0
+    }
+
+    /// Line shape of the underline, or `None` without one
+    pub fn underline_style(&self) -> Option<UnderlineStyle> {
+        self.is_underline()
+            .then(|| UnderlineStyle::from_shape(((self.properties >> 4) & 0xF) as u8))
+    }
+
+    /// Underline the text with a line of the given shape
+    pub fn set_underline(&mut self, style: UnderlineStyle) {
+        self.properties &= !(0x3F << 2);
+        self.properties |= UNDERLINE_BELOW << 2 | (style as u32) << 4;
     }
 
     pub fn is_strikethrough(&self) -> bool {
-        // Strikethrough type is bits 5-7, non-zero means strikethrough
-        (self.properties >> 5) & 0x7 != 0
+        // Strikethrough kind is bits 18-20, non-zero means strikethrough
+        (self.properties >> 18) & 0x7 != 0
+    }
+
+    pub fn set_strikethrough(&mut self) {
+        self.properties |= 1 << 18;
     }
 
     pub fn get_outline_type(&self) -> u8 {
@@ -131,8 +182,18 @@ pub struct FaceName {
     pub substitute_font_name: String,
     pub panose: Option<[u8; 10]>,
     pub default_font_name: String,
+    /// BinData entry holding the embedded font file, if the font is embedded
+    pub embedded_bin_id: Option<u16>,
 }
 
+/// FaceName property bits flagging the optional fields that follow the name
+const FACE_HAS_SUBSTITUTE: u8 = 0x80;
+const FACE_HAS_TYPE_INFO: u8 = 0x40;
+const FACE_HAS_DEFAULT: u8 = 0x20;
+/// Unused by the documented bits: the font is embedded, and the id of its
+/// BinData entry ends the record (HWPX `isEmbedded` / `binaryItemIDRef`)
+const FACE_EMBEDDED: u8 = 0x01;
+
 impl FaceName {
     pub fn from_record(record: &Record) -> Result<Self> {
         let mut reader = record.data_reader();
@@ -158,20 +219,18 @@ impl FaceName {
         let mut substitute_font_name = String::new();
         let mut panose = None;
         let mut default_font_name = String::new();
+        let mut embedded_bin_id = None;
 
-        // Read optional fields if available
-        if reader.remaining() >= 3 {
+        // Each optional field is present only when its property bit is set
+        if properties & FACE_HAS_SUBSTITUTE != 0 && reader.remaining() >= 3 {
             substitute_font_type = reader.read_u8()?;
-            if reader.remaining() >= 2 {
-                let substitute_font_name_len = reader.read_u16()? as usize;
-                if reader.remaining() >= substitute_font_name_len * 2 {
-                    substitute_font_name = reader.read_string(substitute_font_name_len * 2)?;
-                }
+            let substitute_font_name_len = reader.read_u16()? as usize;
+            if reader.remaining() >= substitute_font_name_len * 2 {
+                substitute_font_name = reader.read_string(substitute_font_name_len * 2)?;
             }
         }
 
-        // Read panose if flag is set and data available
-        if properties & 0x80 != 0 && reader.remaining() >= 10 {
+        if properties & FACE_HAS_TYPE_INFO != 0 && reader.remaining() >= 10 {
             let mut p = [0u8; 10];
             for item in &mut p {
                 *item = reader.read_u8()?;
@@ -179,14 +238,17 @@ impl FaceName {
             panose = Some(p);
         }
 
-        // Read default font name if available
-        if reader.remaining() >= 2 {
+        if properties & FACE_HAS_DEFAULT != 0 && reader.remaining() >= 2 {
             let default_font_name_len = reader.read_u16()? as usize;
             if reader.remaining() >= default_font_name_len * 2 {
                 default_font_name = reader.read_string(default_font_name_len * 2)?;
             }
         }
 
+        if properties & FACE_EMBEDDED != 0 && reader.remaining() >= 2 {
+            embedded_bin_id = Some(reader.read_u16()?);
+        }
+
         Ok(Self {
             properties,
             font_name,
@@ -194,8 +256,50 @@ impl FaceName {
             substitute_font_name,
             panose,
             default_font_name,
+            embedded_bin_id,
         })
     }
+
+    /// Link the face to the BinData entry embedding its font file
+    pub fn set_embedded(&mut self, bin_id: u16) {
+        self.properties |= FACE_EMBEDDED;
+        self.embedded_bin_id = Some(bin_id);
+    }
+
+    /// Serialize the FACE_NAME record, writing the optional fields its
+    /// property bits announce
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut properties = self.properties & !FACE_EMBEDDED;
+        if self.embedded_bin_id.is_some() {
+            properties |= FACE_EMBEDDED;
+        }
+        if self.panose.is_none() {
+            properties &= !FACE_HAS_TYPE_INFO;
+        }
+
+        fn push_string(data: &mut Vec<u8>, text: &str) {
+            let units: Vec<u16> = text.encode_utf16().collect();
+            data.extend((units.len() as u16).to_le_bytes());
+            data.extend(units.iter().flat_map(|unit| unit.to_le_bytes()));
+        }
+
+        let mut data = vec![properties];
+        push_string(&mut data, &self.font_name);
+        if properties & FACE_HAS_SUBSTITUTE != 0 {
+            data.push(self.substitute_font_type);
+            push_string(&mut data, &self.substitute_font_name);
+        }
+        if let Some(panose) = self.panose.filter(|_| properties & FACE_HAS_TYPE_INFO != 0) {
+            data.extend(panose);
+        }
+        if properties & FACE_HAS_DEFAULT != 0 {
+            push_string(&mut data, &self.default_font_name);
+        }
+        if let Some(bin_id) = self.embedded_bin_id {
+            data.extend(bin_id.to_le_bytes());
+        }
+        data
+    }
 }
 impl FaceName {
     /// Create a new default FaceName for writing
@@ -207,6 +311,7 @@ impl FaceName {
             substitute_font_name: font_name.clone(),
             panose: None,
             default_font_name: font_name,
+            embedded_bin_id: None,
         }
     }
 }
@@ -0,0 +1,41 @@
+/// A color as stored in the document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Automatic color, left to the renderer (black for text)
+    Auto,
+    /// Explicit RGB color
+    Rgb(u8, u8, u8),
+}
+
+/// Color value meaning "no color set"
+pub const COLOR_NONE: u32 = 0xFFFF_FFFF;
+
+impl Color {
+    /// Create a color from a `0xRRGGBB` value
+    pub fn from_rgb(rgb: u32) -> Self {
+        Color::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+
+    /// Create a color from a character shape's text color
+    ///
+    /// Only unset colors, stored as [`COLOR_NONE`], are reported as
+    /// [`Color::Auto`]; explicit black stays `Rgb(0, 0, 0)`.
+    pub fn from_text_color(value: u32) -> Self {
+        match value {
+            COLOR_NONE => Color::Auto,
+            rgb => Color::from_rgb(rgb),
+        }
+    }
+
+    /// The color as a `0xRRGGBB` value, black for [`Color::Auto`]
+    pub fn to_rgb(&self) -> u32 {
+        match *self {
+            Color::Auto => 0x000000,
+            Color::Rgb(r, g, b) => (r as u32) << 16 | (g as u32) << 8 | b as u32,
+        }
+    }
+
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Color::Auto)
+    }
+}
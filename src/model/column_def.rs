@@ -0,0 +1,89 @@
+use crate::parser::record::Record;
+
+/// Control id of column definitions (`cold`)
+pub const CTRL_COLUMN_DEF: u32 = u32::from_be_bytes(*b"cold");
+
+/// Column properties bit marking columns of equal width
+const SAME_WIDTH: u16 = 1 << 12;
+
+/// Column layout of a section (단 정의)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnDef {
+    /// Bits 0-1 kind, bits 2-9 column count, bits 10-11 direction, bit 12 same width
+    pub properties: u16,
+    /// Gap between columns (HWP units)
+    pub gap: u16,
+    /// Width of each column, only stored when the columns differ in width
+    pub widths: Vec<u16>,
+    pub properties2: u16,
+    pub divider_type: u8,
+    pub divider_thickness: u8,
+    pub divider_color: u32,
+}
+
+impl ColumnDef {
+    /// Create `count` columns of equal width separated by `gap` HWP units
+    pub fn new(count: u8, gap: u16) -> Self {
+        Self {
+            properties: SAME_WIDTH | ((count.max(1) as u16) << 2),
+            gap,
+            widths: Vec::new(),
+            properties2: 0,
+            divider_type: 0,
+            divider_thickness: 0,
+            divider_color: 0,
+        }
+    }
+
+    /// Parse a column definition from a `cold` CTRL_HEADER record
+    pub fn from_ctrl_header(record: &Record) -> Option<Self> {
+        let mut reader = record.data_reader();
+        if reader.read_u32().ok()? != CTRL_COLUMN_DEF {
+            return None;
+        }
+
+        let properties = reader.read_u16().ok()?;
+        let gap = reader.read_u16().ok()?;
+        let count = (properties >> 2) & 0xFF;
+        let widths = if properties & SAME_WIDTH == 0 {
+            (0..count)
+                .map(|_| reader.read_u16())
+                .collect::<Result<Vec<_>, _>>()
+                .ok()?
+        } else {
+            Vec::new()
+        };
+
+        Some(Self {
+            properties,
+            gap,
+            widths,
+            properties2: reader.read_u16().unwrap_or(0),
+            divider_type: reader.read_u8().unwrap_or(0),
+            divider_thickness: reader.read_u8().unwrap_or(0),
+            divider_color: reader.read_u32().unwrap_or(0),
+        })
+    }
+
+    /// Number of columns
+    pub fn column_count(&self) -> u16 {
+        ((self.properties >> 2) & 0xFF).max(1)
+    }
+
+    /// Serialize as the data of a `cold` CTRL_HEADER record
+    pub fn to_ctrl_header(&self) -> Vec<u8> {
+        let mut data = CTRL_COLUMN_DEF.to_le_bytes().to_vec();
+        data.extend_from_slice(&self.properties.to_le_bytes());
+        data.extend_from_slice(&self.gap.to_le_bytes());
+        if self.properties & SAME_WIDTH == 0 {
+            for width in &self.widths {
+                data.extend_from_slice(&width.to_le_bytes());
+            }
+        }
+        data.extend_from_slice(&self.properties2.to_le_bytes());
+        data.push(self.divider_type);
+        data.push(self.divider_thickness);
+        data.extend_from_slice(&self.divider_color.to_le_bytes());
+        data
+    }
+}
@@ -39,6 +39,8 @@ pub struct Picture {
     pub border_fill_id: u16,
     pub image_width: u32,
     pub image_height: u32,
+    /// Caption written below the picture, inside the picture control
+    pub caption: Option<String>,
 }
 
 impl Picture {
@@ -59,6 +61,7 @@ impl Picture {
             border_fill_id: 0,
             image_width: width,
             image_height: height,
+            caption: None,
         }
     }
 
@@ -116,10 +119,64 @@ pub struct TableCell {
     pub field_name: String,
     /// Reference to the paragraphs that form this cell's content
     pub paragraph_list_id: Option<u32>,
+    /// Number of paragraphs following the table paragraph that hold this cell's content
+    pub paragraph_count: u16,
     /// Cell address for easier reference (row, col)
     pub cell_address: (u16, u16),
+    /// Vertical alignment of the cell content
+    pub vertical_align: VAlign,
+    /// Whether the cell belongs to a header row
+    pub is_header: bool,
 }
 
+/// Vertical alignment of a paragraph list, such as a table cell's content
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VAlign {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+}
+
+impl VAlign {
+    /// Decode bits 5-6 of LIST_HEADER properties
+    pub fn from_list_properties(properties: u32) -> Self {
+        match (properties >> 5) & 0x3 {
+            1 => Self::Center,
+            2 => Self::Bottom,
+            _ => Self::Top,
+        }
+    }
+
+    /// LIST_HEADER property bits for this alignment
+    pub fn to_list_properties(self) -> u32 {
+        (self as u32) << 5
+    }
+}
+
+/// Location of a table cell: the table's index in [`HwpDocument::tables`] order
+/// and the cell address
+///
+/// [`HwpDocument::tables`]: crate::HwpDocument::tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableCellRef {
+    pub table_index: usize,
+    pub row: u16,
+    pub col: u16,
+}
+
+/// Control id of tables (`tbl `)
+pub const CTRL_TABLE: u32 = u32::from_be_bytes(*b"tbl ");
+
+/// Spec tag of HWPTAG_TABLE, following the table's control header
+pub const TAG_TABLE: u16 = 0x4D;
+
+/// Spec tag of LIST_HEADER, which starts each table cell
+pub const TAG_LIST_HEADER: u16 = 0x48;
+
+/// Table property bit repeating the header rows on every page
+pub const TABLE_REPEAT_HEADER: u32 = 1 << 2;
+
 impl Table {
     pub fn new_default(rows: u16, cols: u16) -> Self {
         Self {
@@ -172,7 +229,10 @@ impl Table {
             text_width: width.saturating_sub(200), // width - margins
             field_name: format!("cell_{}_{}", row, col),
             paragraph_list_id: None,
+            paragraph_count: 1,
             cell_address: (row, col),
+            vertical_align: VAlign::Top,
+            is_header: false,
         };
 
         self.add_cell(row, col, cell);
@@ -189,6 +249,31 @@ impl Table {
         }
     }
 
+    /// Whether the header rows are repeated on every page the table spans
+    pub fn repeats_header(&self) -> bool {
+        self.properties & TABLE_REPEAT_HEADER != 0
+    }
+
+    /// Number of leading rows made of header cells
+    pub fn header_rows(&self) -> u16 {
+        (0..self.rows)
+            .take_while(|&row| {
+                let mut cells = self.cells.iter().filter(|c| c.cell_address.0 == row);
+                cells.next().is_some_and(|first| first.is_header)
+                    && cells.all(|cell| cell.is_header)
+            })
+            .count() as u16
+    }
+
+    /// Total table width in HWP units, measured across the first row
+    pub fn total_width(&self) -> u32 {
+        self.cells
+            .iter()
+            .filter(|cell| cell.cell_address.0 == 0)
+            .map(|cell| cell.width)
+            .sum()
+    }
+
     /// Get all cells in row order
     pub fn cells_by_row(&self) -> Vec<&TableCell> {
         let mut cells = self.cells.iter().collect::<Vec<_>>();
@@ -196,47 +281,44 @@ impl Table {
         cells
     }
 
-    /// Serialize table to bytes for HWP format
+    /// Serialize the HWPTAG_TABLE record
+    ///
+    /// Layout: properties, row and column counts, cell spacing, inner margins,
+    /// the number of cells in each row, border fill id and an empty zone list.
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut data = Vec::new();
 
-        // Basic table properties (24 bytes)
         data.extend_from_slice(&self.properties.to_le_bytes());
         data.extend_from_slice(&self.rows.to_le_bytes());
         data.extend_from_slice(&self.cols.to_le_bytes());
         data.extend_from_slice(&self.cell_spacing.to_le_bytes());
-        data.extend_from_slice(&self.left_margin.to_le_bytes());
-        data.extend_from_slice(&self.right_margin.to_le_bytes());
-        data.extend_from_slice(&self.top_margin.to_le_bytes());
-        data.extend_from_slice(&self.bottom_margin.to_le_bytes());
+        for margin in [
+            self.left_margin,
+            self.right_margin,
+            self.top_margin,
+            self.bottom_margin,
+        ] {
+            data.extend_from_slice(&(margin.clamp(0, u16::MAX as i32) as u16).to_le_bytes());
+        }
 
-        // Cells data (in row order)
-        let sorted_cells = self.cells_by_row();
-        for cell in sorted_cells {
-            data.extend_from_slice(&cell.list_header_id.to_le_bytes());
-            data.extend_from_slice(&cell.col_span.to_le_bytes());
-            data.extend_from_slice(&cell.row_span.to_le_bytes());
-            data.extend_from_slice(&cell.width.to_le_bytes());
-            data.extend_from_slice(&cell.height.to_le_bytes());
-            data.extend_from_slice(&cell.left_margin.to_le_bytes());
-            data.extend_from_slice(&cell.right_margin.to_le_bytes());
-            data.extend_from_slice(&cell.top_margin.to_le_bytes());
-            data.extend_from_slice(&cell.bottom_margin.to_le_bytes());
-            data.extend_from_slice(&cell.border_fill_id.to_le_bytes());
-            data.extend_from_slice(&cell.text_width.to_le_bytes());
-
-            // Field name (length + UTF-16LE string)
-            let name_utf16: Vec<u16> = cell.field_name.encode_utf16().collect();
-            data.extend_from_slice(&(name_utf16.len() as u16).to_le_bytes());
-            for ch in name_utf16 {
-                data.extend_from_slice(&ch.to_le_bytes());
-            }
+        for row in 0..self.rows {
+            let count = self
+                .cells
+                .iter()
+                .filter(|c| c.cell_address.0 == row)
+                .count();
+            data.extend_from_slice(&(count as u16).to_le_bytes());
         }
+        data.extend_from_slice(&0u16.to_le_bytes()); // border fill id: default
+        data.extend_from_slice(&0u16.to_le_bytes()); // valid zone count
 
         data
     }
 }
 
+/// LIST_HEADER property bit marking a header (title) cell
+pub const CELL_HEADER: u32 = 1 << 18;
+
 impl TableCell {
     pub fn new_default(width: u32, height: u32) -> Self {
         Self {
@@ -253,16 +335,100 @@ impl TableCell {
             text_width: width.saturating_sub(200),
             field_name: format!("Cell{}x{}", width / 100, height / 100),
             paragraph_list_id: None,
+            paragraph_count: 1,
             cell_address: (0, 0),
+            vertical_align: VAlign::Top,
+            is_header: false,
+        }
+    }
+
+    /// Serialize the cell's LIST_HEADER record holding `paragraph_count` paragraphs
+    ///
+    /// Layout: paragraph count, list properties, then the cell's address, spans,
+    /// size, inner margins and border fill id.
+    pub fn to_list_header(&self, paragraph_count: usize) -> Vec<u8> {
+        let mut properties = self.vertical_align.to_list_properties();
+        if self.is_header {
+            properties |= CELL_HEADER;
         }
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&(paragraph_count as i32).to_le_bytes());
+        data.extend_from_slice(&properties.to_le_bytes());
+        data.extend_from_slice(&self.cell_address.1.to_le_bytes());
+        data.extend_from_slice(&self.cell_address.0.to_le_bytes());
+        data.extend_from_slice(&self.col_span.to_le_bytes());
+        data.extend_from_slice(&self.row_span.to_le_bytes());
+        data.extend_from_slice(&self.width.to_le_bytes());
+        data.extend_from_slice(&self.height.to_le_bytes());
+        data.extend_from_slice(&self.left_margin.to_le_bytes());
+        data.extend_from_slice(&self.right_margin.to_le_bytes());
+        data.extend_from_slice(&self.top_margin.to_le_bytes());
+        data.extend_from_slice(&self.bottom_margin.to_le_bytes());
+        data.extend_from_slice(&self.border_fill_id.to_le_bytes());
+        data
+    }
+
+    /// Parse a cell from its LIST_HEADER record
+    ///
+    /// The paragraph count is left at 0 for the caller to count the cell's
+    /// paragraphs as they are read.
+    pub fn from_list_header(
+        record: &crate::parser::record::Record,
+        list_id: u32,
+    ) -> crate::error::Result<Self> {
+        let mut reader = record.data_reader();
+
+        if reader.remaining() < 34 {
+            return Err(crate::error::HwpError::ParseError(format!(
+                "Table cell record too small: {} bytes",
+                reader.remaining()
+            )));
+        }
+
+        let _paragraph_count = reader.read_i32()?;
+        let properties = reader.read_u32()?;
+        let col = reader.read_u16()?;
+        let row = reader.read_u16()?;
+        let col_span = reader.read_u16()?;
+        let row_span = reader.read_u16()?;
+        let width = reader.read_u32()?;
+        let height = reader.read_u32()?;
+        let left_margin = reader.read_u16()?;
+        let right_margin = reader.read_u16()?;
+        let top_margin = reader.read_u16()?;
+        let bottom_margin = reader.read_u16()?;
+        let border_fill_id = reader.read_u16()?;
+
+        Ok(Self {
+            list_header_id: list_id,
+            col_span,
+            row_span,
+            width,
+            height,
+            left_margin,
+            right_margin,
+            top_margin,
+            bottom_margin,
+            border_fill_id,
+            text_width: width.saturating_sub(u32::from(left_margin) + u32::from(right_margin)),
+            field_name: format!("cell_{}_{}", row, col),
+            paragraph_list_id: Some(list_id),
+            paragraph_count: 0,
+            cell_address: (row, col),
+            vertical_align: VAlign::from_list_properties(properties),
+            is_header: properties & CELL_HEADER != 0,
+        })
     }
 }
 
 impl Table {
+    /// Parse an HWPTAG_TABLE record; cells are added from the LIST_HEADER
+    /// records that follow it
     pub fn from_record(record: &crate::parser::record::Record) -> crate::error::Result<Self> {
         let mut reader = record.data_reader();
 
-        if reader.remaining() < 20 {
+        if reader.remaining() < 18 {
             return Err(crate::error::HwpError::ParseError(format!(
                 "Table record too small: {} bytes",
                 reader.remaining()
@@ -273,52 +439,10 @@ impl Table {
         let rows = reader.read_u16()?;
         let cols = reader.read_u16()?;
         let cell_spacing = reader.read_u16()?;
-        let left_margin = reader.read_i32()?;
-        let right_margin = reader.read_i32()?;
-        let top_margin = reader.read_i32()?;
-        let bottom_margin = reader.read_i32()?;
-
-        // Read cells if available
-        let mut cells = Vec::new();
-        let total_cells = (rows * cols) as usize;
-
-        for i in 0..total_cells {
-            if reader.remaining() < 34 {
-                break; // Not enough data for a complete cell
-            }
-
-            let row = (i / cols as usize) as u16;
-            let col = (i % cols as usize) as u16;
-
-            let cell = TableCell {
-                list_header_id: reader.read_u32()?,
-                col_span: reader.read_u16()?,
-                row_span: reader.read_u16()?,
-                width: reader.read_u32()?,
-                height: reader.read_u32()?,
-                left_margin: reader.read_u16()?,
-                right_margin: reader.read_u16()?,
-                top_margin: reader.read_u16()?,
-                bottom_margin: reader.read_u16()?,
-                border_fill_id: reader.read_u16()?,
-                text_width: reader.read_u32()?,
-                field_name: {
-                    if reader.remaining() >= 2 {
-                        let name_len = reader.read_u16()? as usize;
-                        if reader.remaining() >= name_len * 2 {
-                            reader.read_string(name_len * 2)?
-                        } else {
-                            format!("Cell_{}", i)
-                        }
-                    } else {
-                        format!("Cell_{}", i)
-                    }
-                },
-                paragraph_list_id: None,
-                cell_address: (row, col),
-            };
-            cells.push(cell);
-        }
+        let left_margin = reader.read_u16()? as i32;
+        let right_margin = reader.read_u16()? as i32;
+        let top_margin = reader.read_u16()? as i32;
+        let bottom_margin = reader.read_u16()? as i32;
 
         Ok(Self {
             properties,
@@ -329,7 +453,7 @@ impl Table {
             right_margin,
             top_margin,
             bottom_margin,
-            cells,
+            cells: Vec::new(),
         })
     }
 }
@@ -0,0 +1,44 @@
+/// One step of a paragraph-level text diff, turning the old document into the new one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffOp {
+    /// Paragraph present in both documents
+    Equal(String),
+    /// Paragraph only in the new document
+    Insert(String),
+    /// Paragraph only in the old document
+    Delete(String),
+}
+
+/// Diff two paragraph lists with a longest common subsequence table
+pub(crate) fn diff_paragraphs(old: &[String], new: &[String]) -> Vec<DiffOp> {
+    // lcs[i][j]: length of the LCS of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            ops.push(DiffOp::Equal(old[i].clone()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push(DiffOp::Delete(old[i].clone()));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(new[j].clone()));
+            j += 1;
+        }
+    }
+    ops.extend(old[i..].iter().cloned().map(DiffOp::Delete));
+    ops.extend(new[j..].iter().cloned().map(DiffOp::Insert));
+    ops
+}
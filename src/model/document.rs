@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use crate::error::Result;
 use crate::parser::body_text::BodyText;
 use crate::parser::doc_info::DocInfo;
@@ -13,6 +15,12 @@ pub struct HwpDocument {
     pub preview_text: Option<PreviewText>,
     pub preview_image: Option<PreviewImage>,
     pub summary_info: Option<SummaryInfo>,
+    pub scripts: Vec<crate::model::Script>,
+    pub history: Vec<crate::model::HistoryEntry>,
+    /// Schema of the XMLTemplate storage, when the header flags one
+    pub xml_template: Option<String>,
+    /// Streams skipped because they could not be decrypted
+    pub undecrypted_streams: Vec<String>,
 }
 
 impl HwpDocument {
@@ -20,16 +28,440 @@ impl HwpDocument {
         self.body_texts.iter().flat_map(|bt| bt.sections.iter())
     }
 
-    pub fn extract_text(&self) -> String {
-        let mut result = String::new();
+    /// Iterate over all paragraphs of all sections, in document order
+    pub fn paragraphs(&self) -> impl Iterator<Item = &crate::model::Paragraph> {
+        self.sections()
+            .flat_map(|section| section.paragraphs.iter())
+    }
+
+    /// Extract plain text using the default extraction options
+    ///
+    /// The text is borrowed only for an empty document, or one whose single
+    /// text paragraph already ends in the `\n` separator and holds no controls
+    /// or inline objects. Parsed paragraphs normally end in `\r`, so in practice
+    /// almost every document is collected into a new string.
+    pub fn extract_text(&self) -> Cow<'_, str> {
+        let options = crate::model::ExtractionOptions::default();
+        match self.single_plain_text(&options) {
+            Some(text) => Cow::Borrowed(text),
+            None => Cow::Owned(self.extract_text_with(&options)),
+        }
+    }
+
+    /// The text of a document holding at most one paragraph of text, when
+    /// extraction would reproduce it unchanged
+    fn single_plain_text(&self, options: &crate::model::ExtractionOptions) -> Option<&str> {
+        let mut paragraphs = self
+            .paragraphs()
+            .filter(|para| para.text.is_some() || !para.shapes.is_empty());
+        let text = match (paragraphs.next(), paragraphs.next()) {
+            (None, _) => return Some(""),
+            (Some(para), None) if para.shapes.is_empty() => para.text.as_ref()?,
+            _ => return None,
+        };
+        options.unchanged_paragraph_text(&text.content)
+    }
+
+    /// Extract plain text using the given extraction options
+    pub fn extract_text_with(&self, options: &crate::model::ExtractionOptions) -> String {
+        // The placeholder may be longer than the object marker it replaces, so
+        // this is a starting size rather than a bound
+        let capacity = self.body_texts.iter().map(BodyText::text_capacity).sum();
+        let mut result = String::with_capacity(capacity);
+
+        for body_text in &self.body_texts {
+            body_text.push_text_with(options, &mut result);
+        }
+
+        result
+    }
+
+    /// Write the text of [`extract_text`] to `writer` instead of collecting it
+    ///
+    /// [`extract_text`]: Self::extract_text
+    pub fn write_text_to<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        self.write_text_to_with(&crate::model::ExtractionOptions::default(), writer)
+    }
 
+    /// Write plain text extracted with the given options to `writer`
+    ///
+    /// Text is written one section stream at a time, so only the text of a
+    /// single section is buffered.
+    pub fn write_text_to_with<W: std::io::Write>(
+        &self,
+        options: &crate::model::ExtractionOptions,
+        mut writer: W,
+    ) -> std::io::Result<()> {
+        let mut buffer = String::new();
         for body_text in &self.body_texts {
-            result.push_str(&body_text.extract_text());
+            buffer.clear();
+            body_text.push_text_with(options, &mut buffer);
+            writer.write_all(buffer.as_bytes())?;
+        }
+        writer.flush()
+    }
+
+    /// Find the paragraph and run holding the char at `char_offset` of [`extract_text`]
+    ///
+    /// Offsets on the line breaks between paragraphs, or in drawing object text,
+    /// have no location. Each call extracts the text again; use
+    /// [`text_locator`](Self::text_locator) to look up many offsets.
+    ///
+    /// [`extract_text`]: Self::extract_text
+    pub fn locate(&self, char_offset: usize) -> Option<crate::model::TextLocation> {
+        self.text_locator().locate(char_offset)
+    }
+
+    /// Index the text of [`extract_text`] once for repeated [`locate`] lookups
+    ///
+    /// [`extract_text`]: Self::extract_text
+    /// [`locate`]: crate::model::TextLocator::locate
+    pub fn text_locator(&self) -> crate::model::TextLocator<'_> {
+        crate::model::TextLocator::new(self)
+    }
+
+    /// Number of paragraphs in each section, in document order
+    pub fn section_paragraph_counts(&self) -> Vec<usize> {
+        self.sections()
+            .map(|section| section.paragraphs.len())
+            .collect()
+    }
+
+    /// Number of paragraphs in the document, including table cell paragraphs
+    pub fn paragraph_count(&self) -> usize {
+        self.sections()
+            .map(|section| section.paragraphs.len())
+            .sum()
+    }
+
+    /// Get the raw XML of the `idx`-th section, for documents read from HWPX
+    pub fn section_xml(&self, idx: usize) -> Option<&str> {
+        self.body_texts.get(idx)?.raw_xml.as_deref()
+    }
+
+    /// Get the page settings of each section, in document order
+    pub fn page_settings(&self) -> Vec<crate::model::PageSetup> {
+        self.sections()
+            .map(|section| {
+                let mut setup = crate::model::PageSetup::from_defs(
+                    section.page_def.as_ref(),
+                    section.section_def.as_ref(),
+                );
+                // The first page border fill applies to both even and odd pages
+                setup.background = section.page_border_fills.first().and_then(|fill| {
+                    crate::model::PageBackground::from_page_border_fill(
+                        fill,
+                        &self.doc_info.border_fills,
+                    )
+                });
+                setup
+            })
+            .collect()
+    }
+
+    /// Get the page settings shared by sections, each listed once in order of first use
+    pub fn distinct_page_setups(&self) -> Vec<crate::model::PageSetup> {
+        let mut setups = Vec::new();
+        for setup in self.page_settings() {
+            if !setups.contains(&setup) {
+                setups.push(setup);
+            }
+        }
+        setups
+    }
+
+    /// Get the paragraph-level formatting of each paragraph, in document order
+    pub fn paragraph_styles(&self) -> Vec<crate::writer::style::ParagraphStyle> {
+        self.paragraphs()
+            .map(|para| {
+                self.get_para_shape(para.para_shape_id as usize)
+                    .map(|para_shape| {
+                        crate::writer::style::ParagraphStyle::from_para_shape(
+                            para_shape,
+                            &self.doc_info.border_fills,
+                        )
+                    })
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Get the text runs of every paragraph with their character formatting,
+    /// in document order
+    pub fn styled_runs(&self) -> Vec<crate::model::StyledRun> {
+        self.paragraphs()
+            .flat_map(|para| {
+                crate::model::StyledRun::from_paragraph(para, &self.doc_info.char_shapes)
+            })
+            .collect()
+    }
+
+    /// Iterate over the text runs of every paragraph with their start offset
+    ///
+    /// Offsets count chars across the text of all runs in document order, so
+    /// each run starts where the previous one ended.
+    pub fn runs(&self) -> impl Iterator<Item = (usize, crate::model::StyledRun)> + '_ {
+        self.paragraphs()
+            .flat_map(|para| {
+                crate::model::StyledRun::from_paragraph(para, &self.doc_info.char_shapes)
+            })
+            .scan(0, |offset, run| {
+                let start = *offset;
+                *offset += run.text.chars().count();
+                Some((start, run))
+            })
+    }
+
+    /// Whether a paragraph is a horizontal rule: no text, and a bottom border
+    /// without a top border
+    pub fn is_horizontal_line(&self, paragraph: &crate::model::Paragraph) -> bool {
+        if !paragraph.text().trim().is_empty() || paragraph.table_data.is_some() {
+            return false;
+        }
+
+        self.get_para_shape(paragraph.para_shape_id as usize)
+            .and_then(|para_shape| {
+                crate::model::border_fill::BorderFill::from_reference(
+                    &self.doc_info.border_fills,
+                    para_shape.border_fill_id,
+                )
+            })
+            .is_some_and(|border_fill| {
+                border_fill.bottom.line_type != 0 && border_fill.top.line_type == 0
+            })
+    }
+
+    /// Get all tables in the document, in document order
+    pub fn tables(&self) -> Vec<&crate::model::Table> {
+        self.paragraphs()
+            .filter_map(|para| para.table_data.as_ref())
+            .collect()
+    }
+
+    /// Get the (rows, cols) of each table as declared by its table record, in document order
+    pub fn table_dimensions(&self) -> Vec<(usize, usize)> {
+        self.paragraphs()
+            .filter_map(|para| para.table_data.as_ref())
+            .map(|table| (table.rows as usize, table.cols as usize))
+            .collect()
+    }
+
+    /// Get the text of each paragraph, tagged with the table cell it belongs to
+    ///
+    /// Cell paragraphs follow their table's paragraph; text of tables nested in a
+    /// cell is attributed to the outer cell.
+    pub fn text_with_table_context(&self) -> Vec<crate::model::TextSegment> {
+        self.paragraphs_with_cells()
+            .into_iter()
+            .filter(|(para, _)| para.text.is_some())
+            .map(|(para, cell)| crate::model::TextSegment {
+                text: para.text(),
+                cell,
+            })
+            .collect()
+    }
+
+    /// Get the text runs of a table cell with their character formatting
+    ///
+    /// `table_index` counts tables in [`tables`](Self::tables) order.
+    pub fn table_cell_runs(
+        &self,
+        table_index: usize,
+        row: u16,
+        col: u16,
+    ) -> Vec<crate::model::StyledRun> {
+        let target = crate::model::TableCellRef {
+            table_index,
+            row,
+            col,
+        };
+        self.paragraphs_with_cells()
+            .into_iter()
+            .filter(|(_, cell)| *cell == Some(target))
+            .flat_map(|(para, _)| {
+                crate::model::StyledRun::from_paragraph(para, &self.doc_info.char_shapes)
+            })
+            .collect()
+    }
+
+    /// Every paragraph with the table cell it belongs to, in document order
+    fn paragraphs_with_cells(
+        &self,
+    ) -> Vec<(&crate::model::Paragraph, Option<crate::model::TableCellRef>)> {
+        use crate::model::TableCellRef;
+
+        let mut result = Vec::new();
+        let mut push = |para, cell| result.push((para, cell));
+
+        let mut next_table_index = 0;
+        for section in self.body_texts.iter().flat_map(|body| body.sections.iter()) {
+            let mut paragraphs = section.paragraphs.iter();
+            while let Some(para) = paragraphs.next() {
+                push(para, None);
+                let Some(table) = &para.table_data else {
+                    continue;
+                };
+                let table_index = next_table_index;
+                next_table_index += 1;
+
+                let mut cells: Vec<_> = table.cells.iter().collect();
+                cells.sort_by_key(|cell| cell.paragraph_list_id.unwrap_or(cell.list_header_id));
+                for cell in cells {
+                    let cell_ref = TableCellRef {
+                        table_index,
+                        row: cell.cell_address.0,
+                        col: cell.cell_address.1,
+                    };
+                    for cell_para in paragraphs.by_ref().take(cell.paragraph_count as usize) {
+                        if cell_para.table_data.is_some() {
+                            next_table_index += 1;
+                        }
+                        push(cell_para, Some(cell_ref));
+                    }
+                }
+            }
         }
 
         result
     }
 
+    /// Get all hyperlinks in the document, in document order
+    pub fn hyperlinks(&self) -> Vec<&crate::model::Hyperlink> {
+        self.paragraphs()
+            .flat_map(|para| para.hyperlinks.iter())
+            .collect()
+    }
+
+    /// Get all drawing objects (shapes, text boxes) in the document, in document order
+    pub fn shapes(&self) -> Vec<&crate::model::Shape> {
+        self.paragraphs()
+            .flat_map(|para| para.shapes.iter())
+            .collect()
+    }
+
+    /// Get all fields (page numbers, dates, ...) in the document, in document order
+    pub fn fields(&self) -> Vec<&crate::model::Field> {
+        self.paragraphs()
+            .flat_map(|para| para.fields.iter())
+            .collect()
+    }
+
+    /// Get all form objects (checkboxes, input boxes) in the document, in document order
+    pub fn form_fields(&self) -> Vec<&crate::model::FormField> {
+        self.paragraphs()
+            .flat_map(|para| para.form_fields.iter())
+            .collect()
+    }
+
+    /// Find the form object named `name`
+    pub fn form_field(&self, name: &str) -> Option<&crate::model::FormField> {
+        self.form_fields()
+            .into_iter()
+            .find(|field| field.name == name)
+    }
+
+    /// Get the names of all bookmarks in the document, in document order
+    pub fn bookmarks(&self) -> Vec<&str> {
+        self.fields()
+            .into_iter()
+            .filter(|field| field.kind == crate::model::FieldKind::Bookmark)
+            .map(|field| field.command.as_str())
+            .collect()
+    }
+
+    /// Get all footnotes in the document, in document order
+    pub fn footnotes(&self) -> Vec<&crate::model::Footnote> {
+        self.paragraphs()
+            .flat_map(|para| para.footnotes.iter())
+            .collect()
+    }
+
+    /// Get the paragraphs that belong to numbered or bulleted lists, in document order
+    pub fn list_items(&self) -> Vec<crate::model::ListItem> {
+        use crate::model::ParaHeadType;
+
+        self.paragraphs()
+            .filter_map(|para| {
+                let para_shape = self.get_para_shape(para.para_shape_id as usize)?;
+                let ordered = match para_shape.head_type() {
+                    ParaHeadType::Numbering => true,
+                    ParaHeadType::Bullet => false,
+                    _ => return None,
+                };
+                Some(crate::model::ListItem {
+                    text: para.text(),
+                    ordered,
+                    level: para_shape.paragraph_level(),
+                })
+            })
+            .collect()
+    }
+
+    /// Heading level (1 for the top level) of a paragraph with an outline head
+    pub fn heading_level(&self, paragraph: &crate::model::Paragraph) -> Option<u8> {
+        let para_shape = self.get_para_shape(paragraph.para_shape_id as usize)?;
+        match para_shape.head_type() {
+            crate::model::ParaHeadType::Outline => Some(para_shape.paragraph_level() + 1),
+            _ => None,
+        }
+    }
+
+    /// Get the headings of the document, in document order
+    pub fn outline(&self) -> Vec<crate::model::OutlineEntry> {
+        self.paragraphs()
+            .filter_map(|para| {
+                Some(crate::model::OutlineEntry {
+                    level: self.heading_level(para)?,
+                    text: para.text(),
+                })
+            })
+            .collect()
+    }
+
+    /// Paragraph-level diff of this document's text against `other`
+    ///
+    /// Operations turn this document's paragraphs into `other`'s, in order.
+    pub fn text_diff(&self, other: &HwpDocument) -> Vec<crate::model::DiffOp> {
+        let old: Vec<String> = self.paragraphs().map(|para| para.text()).collect();
+        let new: Vec<String> = other.paragraphs().map(|para| para.text()).collect();
+        crate::model::diff::diff_paragraphs(&old, &new)
+    }
+
+    /// Count what extraction captured and how many controls it skipped
+    pub fn extraction_report(&self) -> crate::model::ExtractionReport {
+        crate::model::ExtractionReport {
+            paragraphs: self.paragraphs().count(),
+            tables: self.tables().len(),
+            images: self.get_images().len(),
+            encrypted_content: !self.undecrypted_streams.is_empty(),
+            unhandled_controls: self
+                .body_texts
+                .iter()
+                .map(BodyText::unhandled_controls)
+                .sum(),
+        }
+    }
+
+    /// Base formatting of a section: the char and para shape of its first paragraph
+    ///
+    /// A paragraph without explicit char shape positions uses char shape 0.
+    pub fn section_default_style(
+        &self,
+        section_idx: usize,
+    ) -> Option<(&crate::model::CharShape, &crate::model::ParaShape)> {
+        let first = self.sections().nth(section_idx)?.paragraphs.first();
+        let char_shape_id = first
+            .and_then(|para| para.char_shapes.as_ref())
+            .and_then(|shapes| shapes.char_positions.first())
+            .map_or(0, |position| position.char_shape_id);
+        let para_shape_id = first.map_or(0, |para| para.para_shape_id);
+
+        Some((
+            self.get_char_shape(char_shape_id as usize)?,
+            self.get_para_shape(para_shape_id as usize)?,
+        ))
+    }
+
     /// Get a character shape by ID
     pub fn get_char_shape(&self, id: usize) -> Option<&crate::model::CharShape> {
         self.doc_info.char_shapes.get(id)
@@ -40,11 +472,42 @@ impl HwpDocument {
         self.doc_info.para_shapes.get(id)
     }
 
+    /// Get the record data a character shape was read from
+    ///
+    /// Only documents read from HWP files keep the original bytes.
+    pub fn raw_char_shape(&self, id: usize) -> Option<&[u8]> {
+        self.doc_info.raw_char_shapes.get(id).map(Vec::as_slice)
+    }
+
+    /// Get the record data a paragraph shape was read from
+    pub fn raw_para_shape(&self, id: usize) -> Option<&[u8]> {
+        self.doc_info.raw_para_shapes.get(id).map(Vec::as_slice)
+    }
+
     /// Get a style by ID
     pub fn get_style(&self, id: usize) -> Option<&crate::model::style::Style> {
         self.doc_info.styles.get(id)
     }
 
+    /// Get the named styles defined in DocInfo, in style id order
+    pub fn named_styles(&self) -> Vec<crate::model::NamedStyle> {
+        self.doc_info
+            .styles
+            .iter()
+            .enumerate()
+            .map(|(id, style)| style.to_named(id))
+            .collect()
+    }
+
+    /// Get the named style a paragraph uses
+    pub fn paragraph_named_style(
+        &self,
+        paragraph: &crate::model::Paragraph,
+    ) -> Option<crate::model::NamedStyle> {
+        let id = paragraph.style_id as usize;
+        self.get_style(id).map(|style| style.to_named(id))
+    }
+
     /// Get a border fill by ID
     pub fn get_border_fill(&self, id: usize) -> Option<&crate::model::border_fill::BorderFill> {
         self.doc_info.border_fills.get(id)
@@ -70,11 +533,36 @@ impl HwpDocument {
         self.doc_info.bin_data.iter().find(|bd| bd.bin_id == id)
     }
 
+    /// Get the stored content of a BinData entry by ID, already decompressed
+    pub fn bin_data(&self, id: u16) -> Option<&[u8]> {
+        self.get_bin_data(id)
+            .map(|bin_data| bin_data.data.as_slice())
+    }
+
     /// Get a font face by ID
     pub fn get_face_name(&self, id: usize) -> Option<&crate::model::FaceName> {
         self.doc_info.face_names.get(id)
     }
 
+    /// Font of the default character shape, used for text without explicit styling
+    ///
+    /// Falls back to the first font face, and to an empty name without any fonts.
+    pub fn base_font(&self) -> String {
+        self.get_char_shape(0)
+            .and_then(|shape| self.get_face_name(shape.face_name_ids[0] as usize))
+            .or_else(|| self.get_face_name(0))
+            .map(|face| face.font_name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Text color of the default character shape
+    pub fn base_color(&self) -> crate::model::Color {
+        self.get_char_shape(0)
+            .map_or(crate::model::Color::Auto, |shape| {
+                crate::model::Color::from_text_color(shape.text_color)
+            })
+    }
+
     /// Get document properties
     pub fn get_properties(&self) -> Option<&crate::model::DocumentProperties> {
         self.doc_info.properties.as_ref()
@@ -121,6 +609,51 @@ impl HwpDocument {
             .collect()
     }
 
+    /// Get the data of the font embedded for the face named `name`
+    ///
+    /// The face's FaceName record references the BinData entry holding the font.
+    pub fn embedded_font(&self, name: &str) -> Option<&[u8]> {
+        let bin_id = self
+            .doc_info
+            .face_names
+            .iter()
+            .filter(|face| face.font_name == name)
+            .find_map(|face| face.embedded_bin_id)?;
+        self.bin_data(bin_id)
+    }
+
+    /// Get the embedded images with their display and pixel dimensions
+    ///
+    /// Display size comes from the picture shape showing the image; either size
+    /// is `None` when it cannot be determined.
+    pub fn embedded_images(&self) -> Vec<crate::model::EmbeddedImage> {
+        let shapes = self.shapes();
+        self.doc_info
+            .bin_data
+            .iter()
+            .filter(|bd| bd.is_image())
+            .map(|bd| {
+                let shape = shapes
+                    .iter()
+                    .find(|shape| shape.bin_data_id == Some(bd.bin_id));
+                let pixels = bd.pixel_dimensions();
+                crate::model::EmbeddedImage {
+                    bin_id: bd.bin_id,
+                    extension: bd.extension.clone(),
+                    display_width_mm: shape
+                        .map(|s| crate::model::page_layout::hwp_units_to_mm(s.width)),
+                    display_height_mm: shape
+                        .map(|s| crate::model::page_layout::hwp_units_to_mm(s.height)),
+                    pixel_width: pixels.map(|(w, _)| w),
+                    pixel_height: pixels.map(|(_, h)| h),
+                    caption: shape
+                        .map(|s| s.text.clone())
+                        .filter(|text| !text.is_empty()),
+                }
+            })
+            .collect()
+    }
+
     /// Get all OLE objects in the document
     pub fn get_ole_objects(&self) -> Vec<&crate::model::bin_data::BinData> {
         self.doc_info
@@ -130,6 +663,16 @@ impl HwpDocument {
             .collect()
     }
 
+    /// Get the saved revisions from the `DocHistory` storage, oldest first
+    pub fn history(&self) -> Vec<&crate::model::HistoryEntry> {
+        self.history.iter().collect()
+    }
+
+    /// Get the XML template schema, if the document has one
+    pub fn xml_template(&self) -> Option<String> {
+        self.xml_template.clone()
+    }
+
     pub fn preview_text(&self) -> Option<&PreviewText> {
         self.preview_text.as_ref()
     }
@@ -142,6 +685,11 @@ impl HwpDocument {
         self.summary_info.as_ref()
     }
 
+    /// Title, author, dates and the other summary properties, if the stream was read
+    pub fn metadata(&self) -> Option<crate::preview::DocumentMetadata> {
+        self.summary_info.as_ref().map(Into::into)
+    }
+
     pub fn title(&self) -> Option<&str> {
         self.summary_info.as_ref().and_then(|s| s.title.as_deref())
     }
@@ -162,15 +710,92 @@ impl HwpDocument {
             .and_then(|s| s.keywords.as_deref())
     }
 
+    /// Page, word and character counts cached in the summary information
+    ///
+    /// Cheaper than recomputing them, but only as fresh as the last save.
+    pub fn statistics(&self) -> Option<crate::preview::DocStatistics> {
+        self.summary_info.as_ref()?.statistics()
+    }
+
+    /// Creation time recorded in the summary information
+    pub fn creation_date(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.summary_info.as_ref()?.creation_datetime()
+    }
+
+    /// Last save time recorded in the summary information
+    pub fn last_saved_date(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.summary_info.as_ref()?.last_saved_datetime()
+    }
+
     pub fn is_distribution_document(&self) -> bool {
         self.header.is_distribute()
     }
 
+    /// Whether the document properties mark the document read-only
+    pub fn is_read_only(&self) -> bool {
+        self.doc_info
+            .properties
+            .as_ref()
+            .is_some_and(|props| props.read_only)
+    }
+
     pub fn is_encrypted(&self) -> bool {
         self.header.is_encrypted()
     }
+
+    /// Check if the document carries user scripts (macros)
+    ///
+    /// The blank template Hangul stores in every document is not counted.
+    pub fn has_scripts(&self) -> bool {
+        self.scripts
+            .iter()
+            .any(|script| !script.is_blank_template())
+    }
+
+    /// Get the source of each user script in the document
+    pub fn script_sources(&self) -> Vec<String> {
+        self.scripts
+            .iter()
+            .filter(|script| !script.is_blank_template())
+            .map(|script| script.source.clone())
+            .collect()
+    }
+
+    /// Get the stream path of each user script, e.g. `Scripts/DefaultJScript`
+    pub fn script_stream_names(&self) -> Vec<&str> {
+        self.scripts
+            .iter()
+            .filter(|script| !script.is_blank_template())
+            .map(|script| script.stream_name.as_str())
+            .collect()
+    }
+
+    /// Get the distinct languages of the user scripts, in stream order
+    ///
+    /// Streams whose language is not known from their name are reported as `"Unknown"`.
+    pub fn script_languages(&self) -> Vec<String> {
+        let mut languages: Vec<String> = Vec::new();
+        for script in self
+            .scripts
+            .iter()
+            .filter(|script| !script.is_blank_template())
+        {
+            let language = script.language().unwrap_or("Unknown");
+            if !languages.iter().any(|known| known == language) {
+                languages.push(language.to_string());
+            }
+        }
+        languages
+    }
 }
 
+/// Size of the documented DOCUMENT_PROPERTIES fields
+const DOCUMENT_PROPERTIES_SIZE: usize = 26;
+
+const FLAG_PASSWORD_PROTECTED: u32 = 0x01;
+const FLAG_READ_ONLY: u32 = 0x02;
+const FLAG_COMPRESSED: u32 = 0x04;
+
 #[derive(Debug, Default, Clone)]
 pub struct DocumentProperties {
     pub section_count: u16,
@@ -443,15 +1068,9 @@ impl DocumentProperties {
             .unwrap();
 
         // Write flags
-        let mut flags = 0u32;
-        if self.password_protected {
-            flags |= 0x01;
-        }
-        if self.read_only {
-            flags |= 0x02;
-        }
+        let mut flags = self.protection_flags();
         if self.compressed {
-            flags |= 0x04;
+            flags |= FLAG_COMPRESSED;
         }
         writer.write_u32::<LittleEndian>(flags).unwrap();
 
@@ -529,6 +1148,18 @@ impl FormattedText {
 }
 
 impl DocumentProperties {
+    /// Password and read-only bits of the flags word
+    pub fn protection_flags(&self) -> u32 {
+        let mut flags = 0;
+        if self.password_protected {
+            flags |= FLAG_PASSWORD_PROTECTED;
+        }
+        if self.read_only {
+            flags |= FLAG_READ_ONLY;
+        }
+        flags
+    }
+
     pub fn from_record(record: &Record) -> Result<Self> {
         let mut reader = record.data_reader();
         let size = reader.remaining();
@@ -536,6 +1167,22 @@ impl DocumentProperties {
         // The record size varies. Read what's available
         let mut props = Self::default();
 
+        // The writer appends the protection flags to the documented fields
+        if size == DOCUMENT_PROPERTIES_SIZE + 4 {
+            props.section_count = reader.read_u16()?;
+            props.page_start_number = reader.read_u16()?;
+            props.footnote_start_number = reader.read_u16()?;
+            props.endnote_start_number = reader.read_u16()?;
+            props.picture_start_number = reader.read_u16()?;
+            props.table_start_number = reader.read_u16()?;
+            props.equation_start_number = reader.read_u16()?;
+            reader.set_position(DOCUMENT_PROPERTIES_SIZE as u64);
+            let flags = reader.read_u32()?;
+            props.password_protected = flags & FLAG_PASSWORD_PROTECTED != 0;
+            props.read_only = flags & FLAG_READ_ONLY != 0;
+            return Ok(props);
+        }
+
         if size >= 2 {
             props.section_count = reader.read_u16()?;
         }
@@ -0,0 +1,314 @@
+use crate::model::paragraph::OBJECT_REPLACEMENT_CHAR;
+use crate::model::ruby::{RUBY_ANCHOR, RUBY_SEPARATOR, RUBY_TERMINATOR};
+
+/// Which text of a ruby annotation (e.g. Hanja with its Hangul reading) is extracted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RubyHandling {
+    /// The annotated base text
+    #[default]
+    Base,
+    /// The ruby reading only
+    Ruby,
+    /// The base text followed by the reading in parentheses, e.g. `漢字(한자)`
+    Both,
+}
+
+/// Options controlling plain-text extraction
+#[derive(Debug, Clone)]
+pub struct ExtractionOptions {
+    /// Text inserted where an inline object (picture, drawing, table anchor) sits
+    /// inside a paragraph. `None` drops the object without a separator.
+    pub object_placeholder: Option<String>,
+    /// Text put before the text of each drawing object (shape labels, text boxes).
+    /// `None` adds the text without a marker.
+    pub shape_marker: Option<String>,
+    /// Text emitted for ruby annotations
+    pub ruby: RubyHandling,
+    /// Include the text of table cells
+    pub include_tables: bool,
+    /// Include the text of hidden comments (숨은 설명), which Hancom does not display
+    pub include_hidden: bool,
+    /// Include footnote text after the paragraph holding the footnote
+    pub include_footnotes: bool,
+    /// Text put after each paragraph, drawing object text and footnote
+    pub paragraph_separator: String,
+}
+
+impl Default for ExtractionOptions {
+    fn default() -> Self {
+        Self {
+            object_placeholder: Some(" ".to_string()),
+            shape_marker: None,
+            ruby: RubyHandling::Base,
+            include_tables: true,
+            include_hidden: false,
+            include_footnotes: false,
+            paragraph_separator: "\n".to_string(),
+        }
+    }
+}
+
+impl ExtractionOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the text inserted for inline objects
+    pub fn object_placeholder(mut self, placeholder: Option<&str>) -> Self {
+        self.object_placeholder = placeholder.map(str::to_string);
+        self
+    }
+
+    /// Set the marker put before drawing object text
+    pub fn shape_marker(mut self, marker: Option<&str>) -> Self {
+        self.shape_marker = marker.map(str::to_string);
+        self
+    }
+
+    /// Set which text of ruby annotations is emitted
+    pub fn ruby(mut self, handling: RubyHandling) -> Self {
+        self.ruby = handling;
+        self
+    }
+
+    /// Set whether table cell text is extracted
+    pub fn include_tables(mut self, include: bool) -> Self {
+        self.include_tables = include;
+        self
+    }
+
+    /// Set whether the text of hidden comments is extracted
+    pub fn include_hidden(mut self, include: bool) -> Self {
+        self.include_hidden = include;
+        self
+    }
+
+    /// Set whether footnote text is extracted
+    pub fn include_footnotes(mut self, include: bool) -> Self {
+        self.include_footnotes = include;
+        self
+    }
+
+    /// Set the text put after each paragraph, `"\n"` by default
+    pub fn paragraph_separator(mut self, separator: &str) -> Self {
+        self.paragraph_separator = separator.to_string();
+        self
+    }
+
+    /// `content` itself when cleaning it and adding the paragraph separator
+    /// would give the same text
+    pub(crate) fn unchanged_paragraph_text<'a>(&self, content: &'a str) -> Option<&'a str> {
+        let body = content.strip_suffix(self.paragraph_separator.as_str())?;
+        let is_clean = !body.ends_with('\n')
+            && !body
+                .chars()
+                .any(|ch| ch == OBJECT_REPLACEMENT_CHAR || is_stripped(ch));
+        is_clean.then_some(content)
+    }
+
+    /// Clean raw paragraph text for output
+    ///
+    /// Inline object markers are replaced according to `object_placeholder`,
+    /// control characters other than tab and line break are dropped, and
+    /// trailing paragraph markers are trimmed.
+    pub(crate) fn clean_paragraph_text(&self, content: &str) -> String {
+        let mut result = String::with_capacity(content.len());
+        self.push_paragraph_text(content, &mut result);
+        result
+    }
+
+    /// Append the cleaned paragraph text to `out` without intermediate allocations
+    pub(crate) fn push_paragraph_text(&self, content: &str, out: &mut String) {
+        self.push_paragraph_text_tracked(content, out, |_, _| {});
+    }
+
+    /// Like [`push_paragraph_text`](Self::push_paragraph_text), calling `track` with
+    /// the char index in `content` and the byte offset in `out` of every pushed piece
+    pub(crate) fn push_paragraph_text_tracked(
+        &self,
+        content: &str,
+        out: &mut String,
+        mut track: impl FnMut(usize, usize),
+    ) {
+        let placeholder = self.object_placeholder.as_deref().unwrap_or("");
+        let is_separator = placeholder.chars().all(char::is_whitespace);
+        let start = out.len();
+        // Whether the current char is inside ruby base text or its reading
+        let (mut in_base, mut in_ruby) = (false, false);
+
+        for (char_index, (i, ch)) in content.char_indices().enumerate() {
+            match ch {
+                RUBY_ANCHOR => {
+                    in_base = true;
+                    continue;
+                }
+                RUBY_SEPARATOR if in_base => {
+                    (in_base, in_ruby) = (false, true);
+                    if self.ruby == RubyHandling::Both {
+                        out.push('(');
+                    }
+                    continue;
+                }
+                RUBY_TERMINATOR => {
+                    if in_ruby && self.ruby == RubyHandling::Both {
+                        out.push(')');
+                    }
+                    (in_base, in_ruby) = (false, false);
+                    continue;
+                }
+                _ if in_base && self.ruby == RubyHandling::Ruby => continue,
+                _ if in_ruby && self.ruby == RubyHandling::Base => continue,
+                _ => {}
+            }
+
+            if ch == OBJECT_REPLACEMENT_CHAR {
+                // A whitespace separator is only needed between two non-space characters
+                if is_separator {
+                    let before = out[start..].chars().next_back();
+                    let after = content[i + ch.len_utf8()..]
+                        .chars()
+                        .find(|&c| c != OBJECT_REPLACEMENT_CHAR && !is_stripped(c));
+                    let needs_separator = matches!(before, Some(c) if !c.is_whitespace())
+                        && matches!(after, Some(c) if !c.is_whitespace());
+                    if !needs_separator {
+                        continue;
+                    }
+                }
+                track(char_index, out.len());
+                out.push_str(placeholder);
+            } else if !is_stripped(ch) {
+                track(char_index, out.len());
+                out.push(ch);
+            }
+        }
+
+        let trimmed_len = start + out[start..].trim_end_matches('\n').len();
+        out.truncate(trimmed_len);
+    }
+}
+
+/// Summary of what text extraction found, as a fidelity signal
+///
+/// A high `unhandled_controls` count means parts of the document (fields,
+/// drawing objects, notes) were skipped and the extracted text may be incomplete.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExtractionReport {
+    pub paragraphs: usize,
+    pub tables: usize,
+    pub images: usize,
+    pub unhandled_controls: usize,
+    /// Some streams could not be decrypted, so their text is missing
+    pub encrypted_content: bool,
+}
+
+/// Text of one paragraph, tagged with the table cell it came from
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextSegment {
+    pub text: String,
+    /// `None` for paragraphs outside tables
+    pub cell: Option<crate::model::TableCellRef>,
+}
+
+/// Position of a character of the extracted text in the document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextLocation {
+    /// Section index in [`HwpDocument::sections`] order
+    ///
+    /// [`HwpDocument::sections`]: crate::HwpDocument::sections
+    pub section: usize,
+    /// Paragraph index within the section
+    pub paragraph_index: usize,
+    /// Run index within the paragraph, in [`HwpDocument::styled_runs`] order
+    ///
+    /// [`HwpDocument::styled_runs`]: crate::HwpDocument::styled_runs
+    pub run_index: usize,
+}
+
+/// Maps char offsets of [`HwpDocument::extract_text`] to document positions
+///
+/// Built once by [`HwpDocument::text_locator`], so each lookup is a binary
+/// search instead of a fresh extraction.
+///
+/// [`HwpDocument::extract_text`]: crate::HwpDocument::extract_text
+/// [`HwpDocument::text_locator`]: crate::HwpDocument::text_locator
+pub struct TextLocator<'a> {
+    document: &'a crate::HwpDocument,
+    /// Paragraph spans, with offsets counted in chars of the extracted text
+    spans: Vec<TextSpan>,
+}
+
+impl<'a> TextLocator<'a> {
+    pub(crate) fn new(document: &'a crate::HwpDocument) -> Self {
+        let options = ExtractionOptions::default();
+        let mut text = String::new();
+        let mut spans = Vec::new();
+        let mut first_section = 0;
+        for body_text in &document.body_texts {
+            body_text.push_text_tracked(&options, &mut text, first_section, Some(&mut spans));
+            first_section += body_text.sections.len();
+        }
+
+        // Span and piece offsets only grow, so one pass converts them all
+        let (mut byte, mut chars) = (0, 0);
+        let mut to_chars = |offset: usize| {
+            chars += text[byte..offset].chars().count();
+            byte = offset;
+            chars
+        };
+        for span in &mut spans {
+            span.start = to_chars(span.start);
+            for piece in &mut span.pieces {
+                piece.0 = to_chars(piece.0);
+            }
+            span.end = to_chars(span.end);
+        }
+
+        Self { document, spans }
+    }
+
+    /// Find the paragraph and run holding the char at `char_offset`
+    ///
+    /// Offsets on the line breaks between paragraphs, or in drawing object text,
+    /// have no location.
+    pub fn locate(&self, char_offset: usize) -> Option<TextLocation> {
+        let span = self
+            .spans
+            .get(self.spans.partition_point(|span| span.end <= char_offset))
+            .filter(|span| span.start <= char_offset)?;
+        // The last piece starting at or before the offset holds it
+        let last_piece = span
+            .pieces
+            .partition_point(|&(start, _)| start <= char_offset)
+            .checked_sub(1)?;
+        let (_, char_index) = span.pieces[last_piece];
+        let paragraph = self
+            .document
+            .sections()
+            .nth(span.section)?
+            .paragraphs
+            .get(span.paragraph_index)?;
+
+        Some(TextLocation {
+            section: span.section,
+            paragraph_index: span.paragraph_index,
+            run_index: crate::model::StyledRun::run_index_at(paragraph, char_index),
+        })
+    }
+}
+
+/// Where one paragraph's text landed in the extracted output
+pub(crate) struct TextSpan {
+    pub section: usize,
+    pub paragraph_index: usize,
+    /// Byte range in the output
+    pub start: usize,
+    pub end: usize,
+    /// (output byte offset, char index in the paragraph text) of each pushed piece
+    pub pieces: Vec<(usize, usize)>,
+}
+
+/// Control characters that never belong in extracted text
+pub(crate) fn is_stripped(ch: char) -> bool {
+    (ch < '\u{20}' && ch != '\t' && ch != '\n')
+        || matches!(ch, RUBY_ANCHOR | RUBY_SEPARATOR | RUBY_TERMINATOR)
+}
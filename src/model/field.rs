@@ -0,0 +1,217 @@
+use crate::parser::record::Record;
+
+/// Control id of automatic numbers (`atno`), used for page and caption numbers
+pub const CTRL_AUTO_NUMBER: u32 = u32::from_be_bytes(*b"atno");
+
+/// Control id of date fields (`%dte`)
+pub const CTRL_FIELD_DATE: u32 = u32::from_be_bytes(*b"%dte");
+
+/// Control id of bookmark fields (`%bmk`)
+pub const CTRL_FIELD_BOOKMARK: u32 = u32::from_be_bytes(*b"%bmk");
+
+/// Control character opening a field; the cached value follows it in the text
+pub const FIELD_BEGIN_CTRL: u16 = 0x0003;
+
+/// Control character closing a field
+pub const FIELD_END_CTRL: u16 = 0x0004;
+
+/// Control character anchoring an automatic number
+pub const AUTO_NUMBER_CTRL: u16 = 0x0012;
+
+/// Automatic number type of page numbers, in bits 0-3 of the `atno` properties
+const NUMBER_TYPE_PAGE: u32 = 0;
+
+/// Object a caption numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptionKind {
+    /// Picture caption (그림)
+    Figure,
+    /// Table caption (표)
+    Table,
+}
+
+impl CaptionKind {
+    /// Label written ahead of the number
+    pub fn label(&self) -> &'static str {
+        match self {
+            CaptionKind::Figure => "그림",
+            CaptionKind::Table => "표",
+        }
+    }
+
+    /// Automatic number type counting these objects
+    fn number_type(&self) -> u32 {
+        match self {
+            CaptionKind::Figure => 3,
+            CaptionKind::Table => 4,
+        }
+    }
+
+    fn from_number_type(number_type: u32) -> Option<Self> {
+        [CaptionKind::Figure, CaptionKind::Table]
+            .into_iter()
+            .find(|kind| kind.number_type() == number_type)
+    }
+}
+
+/// Kind of a field control
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// Current page number (`atno` automatic number of page type)
+    PageNumber,
+    /// Figure or table number (`atno` automatic number of that type)
+    CaptionNumber(CaptionKind),
+    /// Current date (`%dte`)
+    Date,
+    /// Named bookmark (`%bmk`), with the name as its command
+    Bookmark,
+    /// Any other `%`-prefixed field, with its control id
+    Other(u32),
+}
+
+/// A field whose value is computed by the viewer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub kind: FieldKind,
+    /// Field command, such as the date format
+    pub command: String,
+    /// Value shown until the viewer recomputes the field
+    pub cached_value: String,
+}
+
+impl Field {
+    /// A page number field starting at page 1
+    pub fn page_number() -> Self {
+        Self {
+            kind: FieldKind::PageNumber,
+            command: String::new(),
+            cached_value: "1".to_string(),
+        }
+    }
+
+    /// The `number`th figure or table number, labelled with the caption kind
+    pub fn caption_number(kind: CaptionKind, number: u16) -> Self {
+        Self {
+            kind: FieldKind::CaptionNumber(kind),
+            command: kind.label().to_string(),
+            cached_value: number.to_string(),
+        }
+    }
+
+    /// A date field rendered with `format`, showing `cached_value` until updated
+    pub fn date(format: &str, cached_value: &str) -> Self {
+        Self {
+            kind: FieldKind::Date,
+            command: format.to_string(),
+            cached_value: cached_value.to_string(),
+        }
+    }
+
+    /// A bookmark field named `name`
+    pub fn bookmark(name: &str) -> Self {
+        Self {
+            kind: FieldKind::Bookmark,
+            command: name.to_string(),
+            cached_value: String::new(),
+        }
+    }
+
+    /// Control id written for this field
+    pub fn ctrl_id(&self) -> u32 {
+        match self.kind {
+            FieldKind::PageNumber | FieldKind::CaptionNumber(_) => CTRL_AUTO_NUMBER,
+            FieldKind::Date => CTRL_FIELD_DATE,
+            FieldKind::Bookmark => CTRL_FIELD_BOOKMARK,
+            FieldKind::Other(ctrl_id) => ctrl_id,
+        }
+    }
+
+    /// Whether this is an `atno` automatic number rather than a `%` field
+    pub fn is_auto_number(&self) -> bool {
+        self.ctrl_id() == CTRL_AUTO_NUMBER
+    }
+
+    /// Automatic number type written in bits 0-3 of the `atno` properties
+    pub(crate) fn number_type(&self) -> u32 {
+        match self.kind {
+            FieldKind::CaptionNumber(kind) => kind.number_type(),
+            _ => NUMBER_TYPE_PAGE,
+        }
+    }
+
+    /// Parse a field from a CTRL_HEADER record, if it holds one
+    ///
+    /// `%`-prefixed fields keep their value in the paragraph text, so their
+    /// `cached_value` is left empty for the caller to fill in. Caption numbers
+    /// leave their label in the text as well, so their `command` stays empty.
+    pub fn from_ctrl_header(record: &Record) -> Option<Self> {
+        let mut reader = record.data_reader();
+        let ctrl_id = reader.read_u32().ok()?;
+        let properties = reader.read_u32().ok()?;
+
+        if ctrl_id == CTRL_AUTO_NUMBER {
+            // Number type in bits 0-3; 0 is the page number
+            let kind = match properties & 0x0F {
+                NUMBER_TYPE_PAGE => FieldKind::PageNumber,
+                number_type => {
+                    FieldKind::CaptionNumber(CaptionKind::from_number_type(number_type)?)
+                }
+            };
+            let number = reader.read_u16().ok()?;
+            return Some(Self {
+                kind,
+                command: String::new(),
+                cached_value: number.to_string(),
+            });
+        }
+
+        if ctrl_id.to_be_bytes()[0] != b'%' {
+            return None;
+        }
+        reader.read_u8().ok()?; // extra properties
+        let command_len = reader.read_u16().ok()? as usize;
+        let command = reader.read_string(command_len * 2).ok()?;
+        let kind = match ctrl_id {
+            CTRL_FIELD_DATE => FieldKind::Date,
+            CTRL_FIELD_BOOKMARK => FieldKind::Bookmark,
+            _ => FieldKind::Other(ctrl_id),
+        };
+
+        Some(Self {
+            kind,
+            command,
+            cached_value: String::new(),
+        })
+    }
+}
+
+/// Text between each field begin and end control of a PARA_TEXT record, in order
+pub(crate) fn field_values(record: &Record) -> Vec<String> {
+    let chars: Vec<u16> = record
+        .data
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+
+    let mut values = Vec::new();
+    let mut current: Option<Vec<u16>> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+        let is_control =
+            matches!(ch, 0x01..=0x08 | 0x0B | 0x0C | 0x0E..=0x17) && chars.get(i + 7) == Some(&ch);
+        if is_control && ch == FIELD_BEGIN_CTRL {
+            current = Some(Vec::new());
+        } else if is_control && ch == FIELD_END_CTRL {
+            if let Some(units) = current.take() {
+                values.push(String::from_utf16_lossy(&units));
+            }
+        } else if ch >= 0x20 {
+            if let Some(units) = current.as_mut() {
+                units.push(ch);
+            }
+        }
+        i += if is_control { 8 } else { 1 };
+    }
+    values
+}
@@ -0,0 +1,8 @@
+/// A footnote anchored in a paragraph
+#[derive(Debug, Clone, Default)]
+pub struct Footnote {
+    /// Footnote number, when the source records one
+    pub number: Option<u32>,
+    /// Footnote body, one line per paragraph
+    pub text: String,
+}
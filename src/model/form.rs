@@ -0,0 +1,180 @@
+use crate::parser::record::Record;
+
+/// Control id of form objects (`form`, 양식 개체)
+pub const CTRL_FORM: u32 = u32::from_be_bytes(*b"form");
+
+/// Spec tag of FORM_OBJECT, the child of a form control holding its properties
+pub const TAG_FORM_OBJECT: u16 = 0x5B;
+
+/// Kind of a form object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormFieldKind {
+    CheckBox,
+    RadioButton,
+    Edit,
+    ComboBox,
+    PushButton,
+}
+
+impl FormFieldKind {
+    /// Type name leading the property string of the form object
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            FormFieldKind::CheckBox => "CheckBox",
+            FormFieldKind::RadioButton => "RadioButton",
+            FormFieldKind::Edit => "Edit",
+            FormFieldKind::ComboBox => "ComboBox",
+            FormFieldKind::PushButton => "PushButton",
+        }
+    }
+
+    /// Form object type id leading the FORM_OBJECT record
+    pub fn type_id(&self) -> u32 {
+        u32::from_be_bytes(match self {
+            FormFieldKind::CheckBox => *b"+cbt",
+            FormFieldKind::RadioButton => *b"+rbt",
+            FormFieldKind::Edit => *b"+edt",
+            FormFieldKind::ComboBox => *b"+cob",
+            FormFieldKind::PushButton => *b"+pbt",
+        })
+    }
+
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        [
+            FormFieldKind::CheckBox,
+            FormFieldKind::RadioButton,
+            FormFieldKind::Edit,
+            FormFieldKind::ComboBox,
+            FormFieldKind::PushButton,
+        ]
+        .into_iter()
+        .find(|kind| kind.type_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Whether the value is an on/off state rather than text
+    fn has_state(&self) -> bool {
+        matches!(self, FormFieldKind::CheckBox | FormFieldKind::RadioButton)
+    }
+}
+
+/// A form object such as a checkbox or an input box
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormField {
+    pub kind: FormFieldKind,
+    pub name: String,
+    /// Text shown next to the control
+    pub caption: String,
+    /// Entered text, or `"1"`/`"0"` for checked and unchecked boxes
+    pub value: String,
+}
+
+impl FormField {
+    /// A checkbox named `name` with `caption` next to it
+    pub fn checkbox(name: &str, caption: &str, checked: bool) -> Self {
+        Self {
+            kind: FormFieldKind::CheckBox,
+            name: name.to_string(),
+            caption: caption.to_string(),
+            value: if checked { "1" } else { "0" }.to_string(),
+        }
+    }
+
+    /// Whether a checkbox or radio button is checked
+    pub fn is_checked(&self) -> bool {
+        self.kind.has_state() && !matches!(self.value.as_str(), "" | "0")
+    }
+
+    /// Parse a FORM_OBJECT record
+    ///
+    /// The record holds the form object type id twice, then the property
+    /// string as a WORD length and WCHAR array.
+    pub fn from_form_object(record: &Record) -> Option<Self> {
+        if record.tag_id() != TAG_FORM_OBJECT {
+            return None;
+        }
+        let mut reader = record.data_reader();
+        reader.read_u32().ok()?;
+        reader.read_u32().ok()?;
+        let len = reader.read_u16().ok()? as usize;
+        Self::parse_properties(&reader.read_string(len * 2).ok()?)
+    }
+
+    /// Parse the property string of a form object
+    ///
+    /// The string is the type name followed by space-separated properties:
+    /// `Key:int:value`, `Key:wstring:length:text` or `Key:set:length:properties`.
+    /// Nested sets (character shape, colors) are skipped.
+    pub fn parse_properties(properties: &str) -> Option<Self> {
+        let properties: Vec<char> = properties.chars().collect();
+        let mut rest = properties.as_slice();
+        let kind = FormFieldKind::from_type_name(&take_until(&mut rest, ' '))?;
+        let mut field = Self {
+            kind,
+            name: String::new(),
+            caption: String::new(),
+            value: String::new(),
+        };
+
+        while !rest.is_empty() {
+            let key = take_until(&mut rest, ':');
+            let value = match take_until(&mut rest, ':').as_str() {
+                "wstring" | "set" => {
+                    let len = take_until(&mut rest, ':').parse().unwrap_or(0);
+                    let text: String = rest.iter().take(len).collect();
+                    rest = &rest[len.min(rest.len())..];
+                    take_until(&mut rest, ' ');
+                    text
+                }
+                _ => take_until(&mut rest, ' '),
+            };
+            match key.as_str() {
+                "Name" => field.name = value,
+                "Caption" => field.caption = value,
+                "Value" | "Text" => field.value = value,
+                _ => {}
+            }
+        }
+
+        Some(field)
+    }
+
+    /// Serialize as FORM_OBJECT record data, laid out as
+    /// [`from_form_object`](Self::from_form_object) reads it
+    ///
+    /// The caption is drawn with a 10pt character shape.
+    pub fn to_form_object(&self) -> Vec<u8> {
+        let wstring =
+            |key: &str, text: &str| format!(" {key}:wstring:{}:{text}", text.chars().count());
+        let char_shape = "Height:int:1000 Bold:int:0 Italic:int:0";
+        let mut properties = self.kind.type_name().to_string();
+        properties.push_str(&wstring("Name", &self.name));
+        properties.push_str(&wstring("Caption", &self.caption));
+        properties.push_str(&format!(
+            " CharShape:set:{}:{char_shape}",
+            char_shape.chars().count()
+        ));
+        if self.kind.has_state() {
+            properties.push_str(&format!(" Value:int:{}", u8::from(self.is_checked())));
+        } else {
+            properties.push_str(&wstring("Text", &self.value));
+        }
+
+        let units: Vec<u16> = properties.encode_utf16().collect();
+        let type_id = self.kind.type_id().to_le_bytes();
+        let mut data = [type_id, type_id].concat();
+        data.extend((units.len() as u16).to_le_bytes());
+        data.extend(units.iter().flat_map(|unit| unit.to_le_bytes()));
+        data
+    }
+}
+
+/// Take chars up to `delimiter`, consuming the delimiter as well
+fn take_until(rest: &mut &[char], delimiter: char) -> String {
+    let end = rest
+        .iter()
+        .position(|&ch| ch == delimiter)
+        .unwrap_or(rest.len());
+    let token = rest[..end].iter().collect();
+    *rest = &rest[(end + 1).min(rest.len())..];
+    token
+}
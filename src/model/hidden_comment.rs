@@ -0,0 +1,12 @@
+/// Control id of hidden comments (`tcmt`, 숨은 설명)
+pub const CTRL_HIDDEN_COMMENT: u32 = u32::from_be_bytes(*b"tcmt");
+
+/// Control character anchoring a hidden comment in paragraph text
+pub const HIDDEN_COMMENT_CTRL: u16 = 0x000F;
+
+/// A hidden comment anchored in a paragraph, kept in the file but not shown
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HiddenComment {
+    /// Comment body, one line per paragraph
+    pub text: String,
+}
@@ -0,0 +1,76 @@
+use crate::error::Result;
+use crate::parser::record::Record;
+use crate::preview::SummaryInfo;
+use crate::reader::StreamReader;
+
+/// Record opening a history item
+const HISTORY_STAMP: u16 = 0x10;
+/// Record holding the save time of a history item
+const HISTORY_DATE: u16 = 0x12;
+/// Record holding the author of a history item
+const HISTORY_WRITER: u16 = 0x13;
+/// Record holding the comment of a history item
+const HISTORY_DESCRIPTION: u16 = 0x14;
+
+/// One saved revision from the `DocHistory` storage
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub author: String,
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    pub comment: String,
+}
+
+impl HistoryEntry {
+    /// Parse the history items of a (decompressed) `DocHistory/VersionLog*` stream
+    pub fn parse_stream(data: Vec<u8>) -> Result<Vec<Self>> {
+        let mut reader = StreamReader::new(data);
+        let mut entries: Vec<Self> = Vec::new();
+
+        while reader.remaining() >= 4 {
+            let record = Record::parse(&mut reader)?;
+            if record.tag_id() == HISTORY_STAMP || entries.is_empty() {
+                entries.push(Self::default());
+            }
+            let Some(entry) = entries.last_mut() else {
+                continue;
+            };
+
+            match record.tag_id() {
+                HISTORY_DATE => entry.timestamp = parse_timestamp(&record.data),
+                HISTORY_WRITER => entry.author = utf16_string(&record.data),
+                HISTORY_DESCRIPTION => entry.comment = utf16_string(&record.data),
+                _ => {}
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Decode a SYSTEMTIME (16 bytes) or FILETIME (8 bytes) value
+fn parse_timestamp(data: &[u8]) -> Option<chrono::DateTime<chrono::Utc>> {
+    match data.len() {
+        16 => {
+            let field = |i: usize| u16::from_le_bytes([data[i * 2], data[i * 2 + 1]]) as u32;
+            // year, month, day of week, day, hour, minute, second, milliseconds
+            chrono::NaiveDate::from_ymd_opt(field(0) as i32, field(1), field(3))?
+                .and_hms_milli_opt(field(4), field(5), field(6), field(7))
+                .map(|time| time.and_utc())
+        }
+        8 => {
+            let filetime = u64::from_le_bytes(data.try_into().ok()?);
+            SummaryInfo::filetime_to_datetime(filetime)
+        }
+        _ => None,
+    }
+}
+
+fn utf16_string(data: &[u8]) -> String {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16_lossy(&units)
+        .trim_end_matches('\0')
+        .to_string()
+}
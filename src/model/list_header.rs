@@ -50,6 +50,11 @@ impl ListHeader {
         (self.properties & 0x02) != 0
     }
 
+    /// Vertical alignment of the list's paragraphs
+    pub fn vertical_align(&self) -> crate::model::VAlign {
+        crate::model::VAlign::from_list_properties(self.properties)
+    }
+
     pub fn is_editable_at_form_mode(&self) -> bool {
         (self.properties & 0x04) != 0
     }
@@ -1,42 +1,77 @@
 pub mod bin_data;
 pub mod border_fill;
 pub mod char_shape;
+pub mod color;
+pub mod column_def;
 pub mod control;
 pub mod ctrl_header;
+pub mod diff;
 pub mod document;
+pub mod extraction;
+pub mod field;
+pub mod footnote;
+pub mod form;
 pub mod header_footer;
+pub mod hidden_comment;
+pub mod history;
 pub mod hyperlink;
 pub mod list_header;
 pub mod numbering;
+pub mod page_border_fill;
 pub mod page_def;
 pub mod page_layout;
+pub mod page_setup;
 pub mod para_char_shape;
 pub mod para_line_seg;
 pub mod para_shape;
 pub mod paragraph;
+pub mod ruby;
+pub mod script;
 pub mod section_def;
+pub mod shape;
 pub mod style;
+pub mod styled_run;
 pub mod tab_def;
 pub mod text_box;
 
-pub use self::char_shape::{CharShape, FaceName};
-pub use self::control::{Control, Table, TableCell};
+pub use self::bin_data::EmbeddedImage;
+pub use self::char_shape::{CharShape, FaceName, UnderlineStyle};
+pub use self::color::Color;
+pub use self::column_def::ColumnDef;
+pub use self::control::{Control, Table, TableCell, TableCellRef, VAlign};
 pub use self::ctrl_header::{ControlType, CtrlHeader};
+pub use self::diff::DiffOp;
 pub use self::document::{DocumentProperties, FormattedText, HwpDocument};
+pub use self::extraction::{
+    ExtractionOptions, ExtractionReport, RubyHandling, TextLocation, TextLocator, TextSegment,
+};
+pub use self::field::{CaptionKind, Field, FieldKind};
+pub use self::footnote::Footnote;
+pub use self::form::{FormField, FormFieldKind};
 pub use self::header_footer::{
     HeaderFooter, HeaderFooterAlignment, HeaderFooterCollection, HeaderFooterType, PageApplyType,
     PageNumberFormat,
 };
+pub use self::hidden_comment::HiddenComment;
+pub use self::history::HistoryEntry;
 pub use self::hyperlink::{Hyperlink, HyperlinkDisplay, HyperlinkType};
 pub use self::list_header::ListHeader;
+pub use self::numbering::{ListItem, OutlineEntry};
+pub use self::page_border_fill::PageBorderFill;
 pub use self::page_def::PageDef;
 pub use self::page_layout::{
     hwp_units_to_inches, hwp_units_to_mm, inches_to_hwp_units, mm_to_hwp_units, MarginUnit,
     PageLayout, PageMargins, PageOrientation, PaperSize,
 };
+pub use self::page_setup::{PageBackground, PageSetup};
 pub use self::para_char_shape::{CharPositionShape, ParaCharShape};
 pub use self::para_line_seg::{LineSegment, ParaLineSeg};
-pub use self::para_shape::ParaShape;
+pub use self::para_shape::{ParaHeadType, ParaShape};
 pub use self::paragraph::{ParaText, Paragraph, Section};
-pub use self::section_def::SectionDef;
+pub use self::ruby::Ruby;
+pub use self::script::Script;
+pub use self::section_def::{LineNumbering, SectionDef};
+pub use self::shape::{Shape, ShapeKind};
+pub use self::style::NamedStyle;
+pub use self::styled_run::StyledRun;
 pub use self::text_box::{TextBox, TextBoxAlignment, TextBoxBorderStyle, TextBoxFillType};
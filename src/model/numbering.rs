@@ -1,6 +1,9 @@
 use crate::error::Result;
 use crate::parser::record::Record;
 
+/// Size of a numbering level with empty prefix and suffix
+const MIN_LEVEL_SIZE: usize = 17;
+
 #[derive(Debug, Clone)]
 pub struct Numbering {
     pub levels: Vec<NumberingLevel>,
@@ -21,6 +24,24 @@ pub struct NumberingLevel {
     pub char_shape_id: u16,
 }
 
+/// A paragraph that belongs to a numbered or bulleted list
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListItem {
+    pub text: String,
+    /// Whether the item is numbered rather than bulleted
+    pub ordered: bool,
+    /// Nesting level, 0 for the outermost list
+    pub level: u8,
+}
+
+/// A heading paragraph, from outline head types
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineEntry {
+    pub text: String,
+    /// Heading level, 1 for the top level
+    pub level: u8,
+}
+
 #[derive(Debug, Clone)]
 pub struct Bullet {
     pub para_shape_id: u16,
@@ -44,7 +65,7 @@ impl Numbering {
 
         // Read numbering levels - typically 7 levels maximum
         for _ in 0..7 {
-            if reader.remaining() < 20 {
+            if reader.remaining() < MIN_LEVEL_SIZE {
                 break; // Not enough data for a complete level
             }
 
@@ -68,7 +89,7 @@ impl Numbering {
                 ".".to_string()
             };
 
-            if reader.remaining() >= 10 {
+            if reader.remaining() >= 9 {
                 let level = NumberingLevel {
                     para_shape_id,
                     number_format,
@@ -248,6 +269,14 @@ impl Numbering {
         }
     }
 
+    /// Create a numbering definition using the same format on all 7 levels
+    pub fn new_for_all_levels(list_type: crate::writer::style::ListType) -> Self {
+        let level = Self::new_for_list(list_type).levels.remove(0);
+        Self {
+            levels: vec![level; 7],
+        }
+    }
+
     /// Serialize to bytes for HWP format
     pub fn to_bytes(&self) -> Vec<u8> {
         use byteorder::{LittleEndian, WriteBytesExt};
@@ -256,15 +285,11 @@ impl Numbering {
         let mut data = Vec::new();
         let mut writer = Cursor::new(&mut data);
 
-        // Write number of levels
-        writer
-            .write_u16::<LittleEndian>(self.levels.len() as u16)
-            .unwrap();
-
         for level in &self.levels {
             writer
                 .write_u16::<LittleEndian>(level.para_shape_id)
                 .unwrap();
+            writer.write_u8(level.number_format).unwrap();
             writer.write_u8(level.number_type).unwrap();
 
             // Write prefix text
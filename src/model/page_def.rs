@@ -36,9 +36,10 @@ impl PageDef {
         let mut right_margin = 8504; // 30mm
         let mut top_margin = 5669; // 20mm
         let mut bottom_margin = 4252; // 15mm
-        let footer_margin = 4252; // 15mm
-        let gutter_margin = 0;
-        let properties = 0;
+        let mut header_margin = 4252; // 15mm
+        let mut footer_margin = 4252; // 15mm
+        let mut gutter_margin = 0;
+        let mut properties = 0;
 
         // Try to parse the first few values as simple u32s
         if data.len() >= 8 {
@@ -79,6 +80,14 @@ impl PageDef {
                     bottom_margin = m4;
                 }
             }
+
+            // Header/footer/gutter margins and the attribute word follow
+            if reader.remaining() >= 16 {
+                header_margin = reader.read_u32().unwrap_or(header_margin);
+                footer_margin = reader.read_u32().unwrap_or(footer_margin);
+                gutter_margin = reader.read_u32().unwrap_or(gutter_margin);
+                properties = reader.read_u32().unwrap_or(properties);
+            }
         }
 
         Ok(Self {
@@ -88,7 +97,7 @@ impl PageDef {
             right_margin,
             top_margin,
             bottom_margin,
-            header_margin: footer_margin,
+            header_margin,
             footer_margin,
             gutter_margin,
             properties,
@@ -143,7 +152,10 @@ impl PageDef {
             header_margin: layout.margins.header,
             footer_margin: layout.margins.footer,
             gutter_margin: layout.margins.gutter,
-            properties: 0, // Will be set based on layout properties
+            // Bit 0: paper orientation, set for landscape
+            properties: u32::from(
+                layout.orientation == crate::model::page_layout::PageOrientation::Landscape,
+            ),
             footnote_shape_id: 0,
             page_border_fill_id: 0,
             header_footer: HeaderFooterCollection::new(),
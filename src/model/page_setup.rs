@@ -0,0 +1,147 @@
+use crate::model::border_fill::BorderFill;
+use crate::model::page_def::PageDef;
+use crate::model::section_def::SectionDef;
+use crate::model::{Color, PageBorderFill};
+
+/// Fill behind the page content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageBackground {
+    Color(Color),
+    /// Image (watermark) stored in BinData
+    Image {
+        bin_data_id: u16,
+    },
+}
+
+impl PageBackground {
+    /// Resolve the background of a page border fill against the document's border fills
+    pub fn from_page_border_fill(
+        page_border_fill: &PageBorderFill,
+        border_fills: &[BorderFill],
+    ) -> Option<Self> {
+        let fill_info =
+            &BorderFill::from_reference(border_fills, page_border_fill.border_fill_id)?.fill_info;
+        if let Some(image_info) = &fill_info.image_info {
+            Some(PageBackground::Image {
+                bin_data_id: image_info.bin_data_id,
+            })
+        } else {
+            (fill_info.fill_type & 0x01 != 0)
+                .then(|| PageBackground::Color(Color::from_rgb(fill_info.back_color)))
+        }
+    }
+}
+
+/// Resolved page settings of a section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageSetup {
+    /// Paper width (HWP units)
+    pub width: u32,
+    /// Paper height (HWP units)
+    pub height: u32,
+    pub left_margin: u32,
+    pub right_margin: u32,
+    pub top_margin: u32,
+    pub bottom_margin: u32,
+    pub header_margin: u32,
+    pub footer_margin: u32,
+    pub gutter_margin: u32,
+    /// Vertical line grid spacing (HWP units, 0 = off)
+    pub line_grid: u16,
+    /// Horizontal character grid spacing (HWP units, 0 = off)
+    pub char_grid: u16,
+    /// Default tab stop interval (HWP units, 0 = application default)
+    pub default_tab_stop: u32,
+    /// Page background color or watermark, set by [`HwpDocument::page_settings`]
+    ///
+    /// [`HwpDocument::page_settings`]: crate::HwpDocument::page_settings
+    pub background: Option<PageBackground>,
+}
+
+impl PageSetup {
+    /// Build page settings from a section's page and section definitions
+    pub fn from_defs(page_def: Option<&PageDef>, section_def: Option<&SectionDef>) -> Self {
+        let default_page;
+        let page_def = match page_def {
+            Some(def) => def,
+            None => {
+                default_page = PageDef::new_default();
+                &default_page
+            }
+        };
+
+        Self {
+            width: page_def.width,
+            height: page_def.height,
+            left_margin: page_def.left_margin,
+            right_margin: page_def.right_margin,
+            top_margin: page_def.top_margin,
+            bottom_margin: page_def.bottom_margin,
+            header_margin: page_def.header_margin,
+            footer_margin: page_def.footer_margin,
+            gutter_margin: page_def.gutter_margin,
+            line_grid: section_def.map_or(0, |def| def.vertical_line_align),
+            char_grid: section_def.map_or(0, |def| def.horizontal_line_align),
+            default_tab_stop: section_def.map_or(0, |def| def.default_tab_stop),
+            background: None,
+        }
+    }
+
+    /// Whether the paper is wider than it is tall
+    pub fn is_landscape(&self) -> bool {
+        self.width > self.height
+    }
+
+    /// Width of the text area (HWP units)
+    pub fn body_width(&self) -> u32 {
+        self.width
+            .saturating_sub(self.left_margin)
+            .saturating_sub(self.right_margin)
+            .saturating_sub(self.gutter_margin)
+    }
+
+    /// Height of the text area, excluding header and footer space (HWP units)
+    pub fn body_height(&self) -> u32 {
+        self.height
+            .saturating_sub(self.top_margin)
+            .saturating_sub(self.bottom_margin)
+            .saturating_sub(self.header_margin)
+            .saturating_sub(self.footer_margin)
+    }
+
+    /// Default tab stop interval in millimeters, if one is set
+    pub fn default_tab_width_mm(&self) -> Option<f32> {
+        (self.default_tab_stop != 0)
+            .then(|| crate::model::page_layout::hwp_units_to_mm(self.default_tab_stop))
+    }
+
+    /// Whether lines snap to a baseline grid
+    pub fn has_line_grid(&self) -> bool {
+        self.line_grid != 0
+    }
+
+    /// Baseline grid spacing in millimeters, if lines snap to a grid
+    pub fn line_grid_mm(&self) -> Option<f32> {
+        self.has_line_grid()
+            .then(|| crate::model::page_layout::hwp_units_to_mm(self.line_grid as u32))
+    }
+
+    /// Whether a manuscript (원고지) style grid is set
+    pub fn has_grid(&self) -> bool {
+        self.line_grid != 0 && self.char_grid != 0
+    }
+
+    /// Grid size as (rows, cols), derived from the grid spacing
+    pub fn grid(&self) -> Option<(u32, u32)> {
+        if !self.has_grid() {
+            return None;
+        }
+        let rows = div_round(self.body_height(), self.line_grid as u32);
+        let cols = div_round(self.body_width(), self.char_grid as u32);
+        Some((rows, cols))
+    }
+}
+
+fn div_round(value: u32, divisor: u32) -> u32 {
+    (value + divisor / 2) / divisor
+}
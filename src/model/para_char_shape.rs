@@ -8,6 +8,10 @@ pub struct ParaCharShape {
 
 #[derive(Debug, Clone)]
 pub struct CharPositionShape {
+    /// Char index in the paragraph text where the shape starts
+    ///
+    /// HWP records count UTF-16 units instead; positions are converted when
+    /// paragraphs are read and written.
     pub position: u32,
     pub char_shape_id: u16,
 }
@@ -31,6 +35,19 @@ impl ParaCharShape {
         Ok(Self { char_positions })
     }
 
+    /// Convert positions from UTF-16 unit offsets to char indices, using the
+    /// char index of each unit offset from [`ParaText::from_record_with_offsets`]
+    ///
+    /// [`ParaText::from_record_with_offsets`]: crate::model::ParaText::from_record_with_offsets
+    pub(crate) fn units_to_chars(&mut self, offsets: &[usize]) {
+        let Some(&end) = offsets.last() else {
+            return;
+        };
+        for pos in &mut self.char_positions {
+            pos.position = offsets.get(pos.position as usize).copied().unwrap_or(end) as u32;
+        }
+    }
+
     /// Get the character shape ID at a specific character position
     pub fn get_shape_at_position(&self, char_pos: u32) -> Option<u16> {
         // Find the shape that applies to this position
@@ -1,7 +1,22 @@
 use crate::error::Result;
 use crate::parser::record::Record;
 
-#[derive(Debug, Clone)]
+/// Kind of head (numbering or bullet) a paragraph shape puts before its text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParaHeadType {
+    None = 0,
+    Outline = 1,
+    Numbering = 2,
+    Bullet = 3,
+}
+
+/// Properties1 bit keeping the paragraph on the same page as the next one
+const KEEP_WITH_NEXT: u32 = 1 << 17;
+
+/// Properties1 bit keeping all lines of the paragraph on one page
+const KEEP_LINES_TOGETHER: u32 = 1 << 18;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParaShape {
     pub properties1: u32,
     pub left_margin: i32,
@@ -28,6 +43,61 @@ impl ParaShape {
         ((self.properties1 >> 2) & 0x7) as u8
     }
 
+    /// Set the alignment in bits 2-4 of properties1
+    pub fn set_alignment(&mut self, alignment: u8) {
+        self.properties1 = (self.properties1 & !0x1C) | (((alignment & 0x7) as u32) << 2);
+    }
+
+    /// Paragraph head kind from bits 23-24 of properties1
+    pub fn head_type(&self) -> ParaHeadType {
+        match (self.properties1 >> 23) & 0x3 {
+            1 => ParaHeadType::Outline,
+            2 => ParaHeadType::Numbering,
+            3 => ParaHeadType::Bullet,
+            _ => ParaHeadType::None,
+        }
+    }
+
+    /// Paragraph level (0-6) from bits 25-27 of properties1
+    pub fn paragraph_level(&self) -> u8 {
+        ((self.properties1 >> 25) & 0x7) as u8
+    }
+
+    /// Whether the paragraph is kept on the same page as the next one (bit 17)
+    pub fn keeps_with_next(&self) -> bool {
+        self.properties1 & KEEP_WITH_NEXT != 0
+    }
+
+    /// Whether the lines of the paragraph are kept on one page (bit 18)
+    pub fn keeps_lines_together(&self) -> bool {
+        self.properties1 & KEEP_LINES_TOGETHER != 0
+    }
+
+    /// Set whether the paragraph is kept with the next one
+    pub fn set_keep_with_next(&mut self, keep: bool) {
+        self.set_flag(KEEP_WITH_NEXT, keep);
+    }
+
+    /// Set whether the lines of the paragraph are kept together
+    pub fn set_keep_lines_together(&mut self, keep: bool) {
+        self.set_flag(KEEP_LINES_TOGETHER, keep);
+    }
+
+    fn set_flag(&mut self, flag: u32, on: bool) {
+        if on {
+            self.properties1 |= flag;
+        } else {
+            self.properties1 &= !flag;
+        }
+    }
+
+    /// Set the paragraph head kind and level
+    pub fn set_head(&mut self, head_type: ParaHeadType, level: u8) {
+        self.properties1 &= !(0x1F << 23);
+        self.properties1 |= (head_type as u32) << 23;
+        self.properties1 |= (level.min(6) as u32) << 25;
+    }
+
     pub fn get_line_spacing_percent(&self) -> i32 {
         // Line spacing depends on line_space_type
         match self.line_space_type {
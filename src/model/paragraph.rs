@@ -1,4 +1,7 @@
 use crate::error::Result;
+use crate::model::field::{AUTO_NUMBER_CTRL, FIELD_BEGIN_CTRL, FIELD_END_CTRL};
+use crate::model::hidden_comment::HIDDEN_COMMENT_CTRL;
+use crate::model::ruby::{CTRL_RUBY, RUBY_ANCHOR, RUBY_CTRL, RUBY_TERMINATOR};
 use crate::parser::record::Record;
 
 #[derive(Debug, Default)]
@@ -6,6 +9,25 @@ pub struct Section {
     pub paragraphs: Vec<Paragraph>,
     pub section_def: Option<crate::model::SectionDef>,
     pub page_def: Option<crate::model::PageDef>,
+    pub column_def: Option<crate::model::ColumnDef>,
+    /// Page borders and backgrounds for both, even and odd pages
+    pub page_border_fills: Vec<crate::model::PageBorderFill>,
+    /// Control records whose content the parser does not interpret
+    pub unhandled_controls: usize,
+}
+
+impl Section {
+    /// Number of text columns, 1 when the section has no column definition
+    pub fn column_count(&self) -> u16 {
+        self.column_def
+            .as_ref()
+            .map_or(1, |column_def| column_def.column_count())
+    }
+
+    /// Line numbering of the section, if enabled
+    pub fn line_numbering(&self) -> Option<&crate::model::LineNumbering> {
+        self.section_def.as_ref()?.line_numbering.as_ref()
+    }
 }
 
 #[derive(Debug, Default)]
@@ -29,16 +51,35 @@ pub struct Paragraph {
     pub text_box_data: Option<crate::model::text_box::TextBox>,
     // Store hyperlinks for this paragraph
     pub hyperlinks: Vec<crate::model::hyperlink::Hyperlink>,
+    pub footnotes: Vec<crate::model::footnote::Footnote>,
+    pub shapes: Vec<crate::model::shape::Shape>,
+    pub fields: Vec<crate::model::field::Field>,
+    pub form_fields: Vec<crate::model::form::FormField>,
+    pub hidden_comments: Vec<crate::model::hidden_comment::HiddenComment>,
 }
 
 impl Paragraph {
     pub fn from_header_record(record: &Record) -> Result<Self> {
         let mut reader = record.data_reader();
 
-        // For tag 0x42, we have a simpler structure
+        // Tag 0x42 is the spec PARA_HEADER, led by the character count
         if record.tag_id() == 0x42 {
-            // This is a minimal paragraph header
-            return Ok(Self::default());
+            if reader.remaining() < 22 {
+                // This is a minimal paragraph header
+                return Ok(Self::default());
+            }
+            reader.read_u32()?; // nChars
+            return Ok(Self {
+                control_mask: reader.read_u32()?,
+                para_shape_id: reader.read_u16()?,
+                style_id: reader.read_u8()?,
+                column_type: reader.read_u8()?,
+                char_shape_count: reader.read_u16()?,
+                range_tag_count: reader.read_u16()?,
+                line_align_count: reader.read_u16()?,
+                instance_id: reader.read_u32()?,
+                ..Default::default()
+            });
         }
 
         // Standard paragraph header (tag 0x50)
@@ -62,6 +103,16 @@ impl Paragraph {
         })
     }
 
+    /// Paragraph text without control codes or the trailing paragraph marker
+    pub fn text(&self) -> String {
+        self.text
+            .as_ref()
+            .map(|text| {
+                crate::model::ExtractionOptions::default().clean_paragraph_text(&text.content)
+            })
+            .unwrap_or_default()
+    }
+
     pub fn parse_char_shapes(&mut self, _record: &Record) -> Result<()> {
         // Character shape parsing logic would go here
         // For now, we'll skip the implementation
@@ -69,6 +120,37 @@ impl Paragraph {
     }
 }
 
+/// Marker left in paragraph text where an inline object (picture, drawing,
+/// table anchor) was removed
+pub const OBJECT_REPLACEMENT_CHAR: char = '\u{FFFC}';
+
+/// Control code of an inline object anchor (extended control, 8 code units)
+const INLINE_OBJECT_CTRL: u16 = 0x000B;
+
+/// Control code of a non-breaking space, read as U+00A0
+pub const NBSP_CTRL: u16 = 0x001E;
+
+/// Control code of a fixed-width space, read as U+2007 (figure space)
+pub const FIXED_SPACE_CTRL: u16 = 0x001F;
+
+/// Text character for a space control code, if `code` is one
+pub(crate) fn space_for_ctrl(code: u16) -> Option<char> {
+    match code {
+        NBSP_CTRL => Some('\u{00A0}'),
+        FIXED_SPACE_CTRL => Some('\u{2007}'),
+        _ => None,
+    }
+}
+
+/// Space control code written for a text character, if `ch` has one
+pub(crate) fn ctrl_for_space(ch: char) -> Option<u16> {
+    match ch {
+        '\u{00A0}' => Some(NBSP_CTRL),
+        '\u{2007}' => Some(FIXED_SPACE_CTRL),
+        _ => None,
+    }
+}
+
 #[derive(Debug)]
 pub struct ParaText {
     pub content: String,
@@ -76,6 +158,15 @@ pub struct ParaText {
 
 impl ParaText {
     pub fn from_record(record: &Record) -> Result<Self> {
+        Self::from_record_with_offsets(record).map(|(text, _)| text)
+    }
+
+    /// Parse paragraph text along with the char index in `content` of every
+    /// UTF-16 unit offset of the record, up to and including its end
+    ///
+    /// Records count positions, such as those of PARA_CHAR_SHAPE, in UTF-16
+    /// units: controls take 8 units and non-BMP chars 2.
+    pub fn from_record_with_offsets(record: &Record) -> Result<(Self, Vec<usize>)> {
         // Check if this is a table marker record
         if record.tag_id() == 0x43 && record.data.len() == 18 {
             // Check for the specific table marker pattern
@@ -87,15 +178,18 @@ impl ParaText {
                 && record.data[5] == 0x74
             {
                 // This is a table marker, return empty text
-                return Ok(Self {
+                let text = Self {
                     content: String::new(),
-                });
+                };
+                return Ok((text, vec![0; 10]));
             }
         }
 
         let mut reader = record.data_reader();
         let mut content = String::new();
         let mut chars = Vec::new();
+        // Byte offset in `content` where each unit's text starts
+        let mut byte_offsets = Vec::new();
 
         // Read all UTF-16LE characters
         while reader.remaining() >= 2 {
@@ -109,6 +203,9 @@ impl ParaText {
             let mut i = 0;
             while i < chars.len() {
                 let ch = chars[i];
+                if byte_offsets.len() <= i {
+                    byte_offsets.resize(i + 1, content.len());
+                }
 
                 // Check for control sequences
                 if ch == 0x0002 && i + 1 < chars.len() {
@@ -130,6 +227,40 @@ impl ParaText {
                     }
                 }
 
+                // Inline objects take 8 code units: code, 6 units of data, code
+                if ch == INLINE_OBJECT_CTRL {
+                    if let Some(next) = skip_control(&chars, i) {
+                        content.push(OBJECT_REPLACEMENT_CHAR);
+                        i = next;
+                        continue;
+                    }
+                }
+
+                // Ruby annotations leave an empty span for their CTRL_HEADER to fill
+                if ch == RUBY_CTRL {
+                    if let Some(next) = skip_control(&chars, i) {
+                        let ctrl_id = u32::from(chars[i + 1]) | u32::from(chars[i + 2]) << 16;
+                        if ctrl_id == CTRL_RUBY {
+                            content.push(RUBY_ANCHOR);
+                            content.push(RUBY_TERMINATOR);
+                        }
+                        i = next;
+                        continue;
+                    }
+                }
+
+                // Field, automatic number and hidden comment controls have the
+                // same layout; a field's cached value follows as plain text
+                if matches!(
+                    ch,
+                    FIELD_BEGIN_CTRL | FIELD_END_CTRL | AUTO_NUMBER_CTRL | HIDDEN_COMMENT_CTRL
+                ) {
+                    if let Some(next) = skip_control(&chars, i) {
+                        i = next;
+                        continue;
+                    }
+                }
+
                 // Process normal characters
                 match ch {
                     0x0000 => {
@@ -166,6 +297,7 @@ impl ParaText {
                     }
                     0x000A => content.push('\n'), // Line feed
                     0x000D => content.push('\r'), // Carriage return
+                    NBSP_CTRL | FIXED_SPACE_CTRL => content.extend(space_for_ctrl(ch)),
                     0x000E..=0x001F => {
                         // Skip other control characters
                     }
@@ -200,16 +332,29 @@ impl ParaText {
                     }
                     _ => {
                         // Regular characters
-                        if let Some(unicode_char) = std::char::from_u32(ch as u32) {
-                            content.push(unicode_char);
-                        }
+                        let (unicode_char, len) = decode_unit(&chars, i);
+                        content.push(unicode_char);
+                        i += len - 1;
                     }
                 }
                 i += 1;
             }
         } else {
             // Standard text processing for other tags
-            for &ch in &chars {
+            let mut i = 0;
+            while i < chars.len() {
+                let ch = chars[i];
+                if byte_offsets.len() <= i {
+                    byte_offsets.resize(i + 1, content.len());
+                }
+                if ch == INLINE_OBJECT_CTRL {
+                    if let Some(next) = skip_control(&chars, i) {
+                        content.push(OBJECT_REPLACEMENT_CHAR);
+                        i = next;
+                        continue;
+                    }
+                }
+
                 match ch {
                     0x0000..=0x001F => {
                         // Control characters
@@ -217,7 +362,8 @@ impl ParaText {
                             0x000A => content.push('\n'), // Line feed
                             0x000D => content.push('\r'), // Carriage return
                             0x0009 => content.push('\t'), // Tab
-                            _ => {}                       // Skip other control characters
+                            NBSP_CTRL | FIXED_SPACE_CTRL => content.extend(space_for_ctrl(ch)),
+                            _ => {} // Skip other control characters
                         }
                     }
                     0xF020..=0xF07F => {
@@ -225,14 +371,45 @@ impl ParaText {
                     }
                     _ => {
                         // Regular characters
-                        if let Some(unicode_char) = std::char::from_u32(ch as u32) {
-                            content.push(unicode_char);
-                        }
+                        let (unicode_char, len) = decode_unit(&chars, i);
+                        content.push(unicode_char);
+                        i += len - 1;
                     }
                 }
+                i += 1;
             }
         }
 
-        Ok(Self { content })
+        byte_offsets.resize(chars.len() + 1, content.len());
+
+        // Byte offsets never decrease, so one pass turns them into char indices
+        let mut char_starts = content.char_indices().map(|(start, _)| start).peekable();
+        let mut char_index = 0;
+        let offsets = byte_offsets
+            .into_iter()
+            .map(|byte| {
+                while char_starts.next_if(|&start| start < byte).is_some() {
+                    char_index += 1;
+                }
+                char_index
+            })
+            .collect();
+
+        Ok((Self { content }, offsets))
+    }
+}
+
+/// Decode the char starting at `chars[start]` and the number of units it takes
+///
+/// Surrogate pairs are combined; an unpaired surrogate becomes U+FFFD.
+fn decode_unit(chars: &[u16], start: usize) -> (char, usize) {
+    match char::decode_utf16(chars[start..].iter().copied()).next() {
+        Some(Ok(ch)) => (ch, ch.len_utf16()),
+        _ => (char::REPLACEMENT_CHARACTER, 1),
     }
 }
+
+/// Return the index just past an 8-unit control starting at `start`
+fn skip_control(chars: &[u16], start: usize) -> Option<usize> {
+    (chars.get(start + 7) == chars.get(start)).then_some(start + 8)
+}
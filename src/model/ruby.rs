@@ -0,0 +1,79 @@
+use crate::parser::record::Record;
+
+/// Control id of ruby annotations (`tdut`, 덧말)
+pub const CTRL_RUBY: u32 = u32::from_be_bytes(*b"tdut");
+
+/// Control character anchoring a ruby annotation
+pub const RUBY_CTRL: u16 = 0x0017;
+
+/// Starts an annotated span in paragraph text: `ANCHOR base SEPARATOR ruby TERMINATOR`
+pub const RUBY_ANCHOR: char = '\u{FFF9}';
+
+/// Separates the base text from its ruby reading
+pub const RUBY_SEPARATOR: char = '\u{FFFA}';
+
+/// Ends an annotated span
+pub const RUBY_TERMINATOR: char = '\u{FFFB}';
+
+/// A piece of paragraph text: plain text or an annotated span
+pub(crate) enum TextPiece<'a> {
+    Text(&'a str),
+    Ruby(Ruby),
+}
+
+/// Base text annotated with a ruby reading, such as Hanja with its Hangul reading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ruby {
+    pub base: String,
+    pub ruby: String,
+}
+
+impl Ruby {
+    /// Parse a ruby from a `tdut` CTRL_HEADER record
+    pub fn from_ctrl_header(record: &Record) -> Option<Self> {
+        let mut reader = record.data_reader();
+        if reader.read_u32().ok()? != CTRL_RUBY {
+            return None;
+        }
+        let base_len = reader.read_u16().ok()? as usize;
+        let base = reader.read_string(base_len * 2).ok()?;
+        let ruby_len = reader.read_u16().ok()? as usize;
+        let ruby = reader.read_string(ruby_len * 2).ok()?;
+        Some(Self { base, ruby })
+    }
+
+    /// How the ruby is kept in paragraph text
+    pub fn annotation(&self) -> String {
+        format!(
+            "{RUBY_ANCHOR}{}{RUBY_SEPARATOR}{}{RUBY_TERMINATOR}",
+            self.base, self.ruby
+        )
+    }
+
+    /// Split paragraph text into plain pieces and rubies, in order
+    pub(crate) fn split_annotations(content: &str) -> Vec<TextPiece<'_>> {
+        let mut pieces = Vec::new();
+        let mut rest = content;
+        while let Some(start) = rest.find(RUBY_ANCHOR) {
+            let body = &rest[start + RUBY_ANCHOR.len_utf8()..];
+            let Some(end) = body.find(RUBY_TERMINATOR) else {
+                break;
+            };
+            if start > 0 {
+                pieces.push(TextPiece::Text(&rest[..start]));
+            }
+            let (base, ruby) = body[..end]
+                .split_once(RUBY_SEPARATOR)
+                .unwrap_or((&body[..end], ""));
+            pieces.push(TextPiece::Ruby(Self {
+                base: base.to_string(),
+                ruby: ruby.to_string(),
+            }));
+            rest = &body[end + RUBY_TERMINATOR.len_utf8()..];
+        }
+        if !rest.is_empty() {
+            pieces.push(TextPiece::Text(rest));
+        }
+        pieces
+    }
+}
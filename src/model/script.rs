@@ -0,0 +1,131 @@
+use crate::error::{HwpError, Result};
+use crate::utils::compression::decompress_stream;
+
+/// Whitespace-free source of the blank script template Hangul stores in every document
+const BLANK_TEMPLATE_SOURCE: &str = "functionOnDocument_New(){//todo:}";
+
+/// A document script stream (e.g. `Scripts/DefaultJScript`)
+#[derive(Debug, Clone)]
+pub struct Script {
+    /// Stream path inside the compound file
+    pub stream_name: String,
+    /// Script header (object bindings)
+    pub header: String,
+    /// Script body
+    pub source: String,
+    /// Code run before the body
+    pub pre_source: String,
+    /// Code run after the body
+    pub post_source: String,
+}
+
+impl Script {
+    /// Parse a script stream: length-prefixed UTF-16LE header, source, pre and post blocks
+    ///
+    /// Script streams are stored raw by some writers and deflated by others, so the raw
+    /// layout is tried first.
+    pub fn from_bytes(stream_name: &str, data: &[u8]) -> Result<Self> {
+        match Self::parse_blocks(stream_name, data) {
+            Ok(script) => Ok(script),
+            Err(_) => Self::parse_blocks(stream_name, &decompress_stream(data)?),
+        }
+    }
+
+    fn parse_blocks(stream_name: &str, data: &[u8]) -> Result<Self> {
+        let mut offset = 0;
+
+        let header = read_block(data, &mut offset)?;
+        let source = read_block(data, &mut offset)?;
+        let pre_source = read_block(data, &mut offset).unwrap_or_default();
+        let post_source = read_block(data, &mut offset).unwrap_or_default();
+
+        Ok(Self {
+            stream_name: stream_name.to_string(),
+            header,
+            source,
+            pre_source,
+            post_source,
+        })
+    }
+
+    /// Scripting language of the stream, from its name (`DefaultJScript`, `...VBScript`)
+    pub fn language(&self) -> Option<&'static str> {
+        let name = self.stream_name.rsplit('/').next().unwrap_or_default();
+        if name.contains("VBScript") {
+            Some("VBScript")
+        } else if name.contains("JScript") {
+            Some("JScript")
+        } else {
+            None
+        }
+    }
+
+    /// Whether this is the empty template Hangul writes into every document
+    pub fn is_blank_template(&self) -> bool {
+        let compact = |s: &str| s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+        let source = compact(&self.source);
+        (source.is_empty() || source == BLANK_TEMPLATE_SOURCE)
+            && compact(&self.pre_source).is_empty()
+            && compact(&self.post_source).is_empty()
+    }
+}
+
+fn read_block(data: &[u8], offset: &mut usize) -> Result<String> {
+    let len_bytes = data
+        .get(*offset..*offset + 4)
+        .ok_or_else(|| HwpError::ParseError("Script block length truncated".to_string()))?;
+    let len = u32::from_le_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]);
+    *offset += 4;
+
+    // 0xFFFFFFFF marks the end of the stream
+    if len == u32::MAX {
+        return Ok(String::new());
+    }
+
+    let byte_len = len as usize * 2;
+    let bytes = data
+        .get(*offset..*offset + byte_len)
+        .ok_or_else(|| HwpError::ParseError(format!("Script block of {len} chars truncated")))?;
+    *offset += byte_len;
+
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .collect();
+    Ok(String::from_utf16_lossy(&units))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(s: &str) -> Vec<u8> {
+        let units: Vec<u16> = s.encode_utf16().collect();
+        let mut data = (units.len() as u32).to_le_bytes().to_vec();
+        data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+        data
+    }
+
+    #[test]
+    fn test_blank_template_detected() {
+        let mut data = block("var Documents = XHwpDocuments;\r\n");
+        data.extend(block(
+            "function OnDocument_New()\r\n{\r\n\t//todo : \r\n}\r\n\r\n",
+        ));
+        data.extend([0; 8]);
+        data.extend(u32::MAX.to_le_bytes());
+
+        let script = Script::from_bytes("Scripts/DefaultJScript", &data).unwrap();
+        assert!(script.is_blank_template());
+    }
+
+    #[test]
+    fn test_custom_source_detected() {
+        let mut data = block("");
+        data.extend(block("function OnDocument_Open() { Shell(\"calc\"); }"));
+
+        let script = Script::from_bytes("Scripts/DefaultJScript", &data).unwrap();
+        assert!(!script.is_blank_template());
+        assert!(script.source.contains("OnDocument_Open"));
+    }
+}
@@ -14,20 +14,79 @@ pub struct SectionDef {
     pub table_starting_number: u16,
     pub equation_starting_number: u16,
     pub default_language: u16,
+    /// Line numbers shown in the margin, read from the start of `extra`
+    pub line_numbering: Option<LineNumbering>,
+    /// Bytes after the documented fields, kept as read
+    pub extra: Vec<u8>,
+}
+
+/// Size of the documented SECTION_DEF fields, up to the representative language
+const DOCUMENTED_SIZE: usize = 26;
+
+/// Line numbering of a section (줄 번호)
+///
+/// The format documentation ends SECTION_DEF at the representative language,
+/// but Hangul appends 8 more bytes, the fields of the HWPX `lineNumberShape`
+/// element as UINT16s: restartType, countBy, distance, startNumber.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineNumbering {
+    /// Number of the first line
+    pub start: u16,
+    /// Show a number on every `interval`-th line
+    pub interval: u16,
+    /// Distance between the numbers and the text (HWP units)
+    pub distance: u16,
+    /// When numbering restarts
+    pub restart_type: u16,
+}
+
+impl LineNumbering {
+    const SIZE: usize = 8;
+
+    /// Decode the numbering from the bytes after the documented fields
+    ///
+    /// A zero countBy means numbering is off.
+    fn from_extra(extra: &[u8]) -> Option<Self> {
+        let values: Vec<u16> = extra
+            .get(..Self::SIZE)?
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        let numbering = Self {
+            restart_type: values[0],
+            interval: values[1],
+            distance: values[2],
+            start: values[3],
+        };
+        (numbering.interval != 0).then_some(numbering)
+    }
+
+    fn to_bytes(self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        for (chunk, value) in bytes.chunks_exact_mut(2).zip([
+            self.restart_type,
+            self.interval,
+            self.distance,
+            self.start,
+        ]) {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
 }
 
 impl SectionDef {
     pub fn from_record(record: &Record) -> Result<Self> {
         let mut reader = record.data_reader();
 
-        if reader.remaining() < 26 {
+        if reader.remaining() < DOCUMENTED_SIZE {
             return Err(crate::error::HwpError::ParseError(format!(
                 "SectionDef record too small: {} bytes",
                 reader.remaining()
             )));
         }
 
-        Ok(Self {
+        let mut section_def = Self {
             properties: reader.read_u32()?,
             column_gap: reader.read_u16()?,
             vertical_line_align: reader.read_u16()?,
@@ -43,7 +102,13 @@ impl SectionDef {
             } else {
                 0
             },
-        })
+            line_numbering: None,
+            extra: Vec::new(),
+        };
+        section_def.extra = reader.read_bytes(reader.remaining())?;
+        section_def.line_numbering = LineNumbering::from_extra(&section_def.extra);
+
+        Ok(section_def)
     }
 
     pub fn column_count(&self) -> u16 {
@@ -76,6 +141,8 @@ impl SectionDef {
             table_starting_number: 1,
             equation_starting_number: 1,
             default_language: 0x0412, // Korean
+            line_numbering: None,
+            extra: Vec::new(),
         }
     }
 
@@ -117,6 +184,13 @@ impl SectionDef {
             .write_u16::<LittleEndian>(self.default_language)
             .unwrap();
 
+        let mut extra = self.extra.clone();
+        if let Some(numbering) = self.line_numbering {
+            extra.resize(extra.len().max(LineNumbering::SIZE), 0);
+            extra[..LineNumbering::SIZE].copy_from_slice(&numbering.to_bytes());
+        }
+        data.extend_from_slice(&extra);
+
         data
     }
 }
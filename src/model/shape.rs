@@ -0,0 +1,75 @@
+use crate::error::Result;
+use crate::parser::record::Record;
+
+/// Kind of drawing object, from the shape component id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeKind {
+    Line,
+    Rectangle,
+    Ellipse,
+    Arc,
+    Polygon,
+    Curve,
+    Picture,
+    Ole,
+    Container,
+    Unknown,
+}
+
+impl ShapeKind {
+    pub fn from_component_id(id: u32) -> Self {
+        match &id.to_be_bytes() {
+            b"$lin" => Self::Line,
+            b"$rec" => Self::Rectangle,
+            b"$ell" => Self::Ellipse,
+            b"$arc" => Self::Arc,
+            b"$pol" => Self::Polygon,
+            b"$cur" => Self::Curve,
+            b"$pic" => Self::Picture,
+            b"$ole" => Self::Ole,
+            b"$con" => Self::Container,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// A drawing object (`gso ` control) anchored in a paragraph
+#[derive(Debug, Clone)]
+pub struct Shape {
+    pub kind: ShapeKind,
+    /// Horizontal offset in HWP units
+    pub x: i32,
+    /// Vertical offset in HWP units
+    pub y: i32,
+    /// Width in HWP units
+    pub width: u32,
+    /// Height in HWP units
+    pub height: u32,
+    /// Text of the shape's paragraph list, one line per paragraph
+    pub text: String,
+    /// BinData entry shown by a picture shape
+    pub bin_data_id: Option<u16>,
+}
+
+impl Shape {
+    /// Parse the common object properties of a `gso ` CTRL_HEADER record
+    pub fn from_ctrl_header(record: &Record) -> Result<Self> {
+        let mut reader = record.data_reader();
+        reader.read_u32()?; // ctrl id
+        reader.read_u32()?; // properties
+        let y = reader.read_i32()?;
+        let x = reader.read_i32()?;
+        let width = reader.read_u32()?;
+        let height = reader.read_u32()?;
+
+        Ok(Self {
+            kind: ShapeKind::Unknown,
+            x,
+            y,
+            width,
+            height,
+            text: String::new(),
+            bin_data_id: None,
+        })
+    }
+}
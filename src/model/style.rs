@@ -1,6 +1,39 @@
 use crate::error::Result;
 use crate::parser::record::Record;
 
+/// Windows language identifiers (LCID) for the locale tags the writer accepts
+const LANGUAGE_IDS: [(&str, u16); 10] = [
+    ("ko-KR", 0x0412),
+    ("en-US", 0x0409),
+    ("en-GB", 0x0809),
+    ("ja-JP", 0x0411),
+    ("zh-CN", 0x0804),
+    ("zh-TW", 0x0404),
+    ("fr-FR", 0x040C),
+    ("de-DE", 0x0407),
+    ("es-ES", 0x0C0A),
+    ("ru-RU", 0x0419),
+];
+
+/// Look up the language identifier for a locale tag such as `"ko-KR"`
+pub fn lang_id_for(tag: &str) -> Option<u16> {
+    LANGUAGE_IDS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(tag))
+        .map(|&(_, id)| id)
+}
+
+/// A named style and the shapes it applies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedStyle {
+    /// Index in the style list, as referenced by `Paragraph::style_id`
+    pub id: usize,
+    pub name: String,
+    pub english_name: String,
+    pub char_shape_id: u16,
+    pub para_shape_id: u16,
+}
+
 #[derive(Debug, Clone)]
 pub struct Style {
     pub name: String,
@@ -81,4 +114,23 @@ impl Style {
             char_shape_id: 0,
         }
     }
+
+    /// Summary of the style as the `id`-th entry of the style list
+    pub fn to_named(&self, id: usize) -> NamedStyle {
+        NamedStyle {
+            id,
+            name: self.name.clone(),
+            english_name: self.english_name.clone(),
+            char_shape_id: self.char_shape_id,
+            para_shape_id: self.para_shape_id,
+        }
+    }
+
+    /// Locale tag of the style's language, if it is one the writer knows
+    pub fn language(&self) -> Option<&'static str> {
+        LANGUAGE_IDS
+            .iter()
+            .find(|&&(_, id)| id == self.lang_id)
+            .map(|&(name, _)| name)
+    }
 }
@@ -0,0 +1,118 @@
+use crate::model::extraction::is_stripped;
+use crate::model::{CharShape, Color, Paragraph, UnderlineStyle};
+
+/// A span of paragraph text sharing one character shape
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyledRun {
+    pub text: String,
+    /// Index of the run's CharShape in the DocInfo table
+    pub char_shape_id: u16,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    /// Underline line shape, `None` without an underline
+    pub underline_style: Option<UnderlineStyle>,
+    pub underline_color: Color,
+    pub strikethrough: bool,
+    /// Font size in points
+    pub font_size: f32,
+    /// Text color, [`Color::Auto`] when none is set
+    pub color: Color,
+}
+
+impl StyledRun {
+    fn new(text: String, char_shape_id: u16, char_shape: Option<&CharShape>) -> Self {
+        let default_shape;
+        let shape = match char_shape {
+            Some(shape) => shape,
+            None => {
+                default_shape = CharShape::new_default();
+                &default_shape
+            }
+        };
+
+        Self {
+            text,
+            char_shape_id,
+            bold: shape.is_bold(),
+            italic: shape.is_italic(),
+            underline: shape.is_underline(),
+            underline_style: shape.underline_style(),
+            underline_color: Color::from_text_color(shape.underline_color),
+            strikethrough: shape.is_strikethrough(),
+            font_size: shape.base_size as f32 / 100.0,
+            color: Color::from_text_color(shape.text_color),
+        }
+    }
+
+    /// Split a paragraph's text into runs at its character shape boundaries
+    pub(crate) fn from_paragraph(paragraph: &Paragraph, char_shapes: &[CharShape]) -> Vec<Self> {
+        let Some(text) = &paragraph.text else {
+            return Vec::new();
+        };
+        let chars: Vec<char> = text.content.chars().collect();
+
+        let mut runs = Vec::new();
+        for (start, end, char_shape_id) in run_bounds(paragraph, chars.len()) {
+            let run_text: String = chars[start..end]
+                .iter()
+                .filter(|&&c| !is_stripped(c))
+                .collect();
+            if !run_text.is_empty() {
+                runs.push(Self::new(
+                    run_text,
+                    char_shape_id,
+                    char_shapes.get(char_shape_id as usize),
+                ));
+            }
+        }
+
+        runs
+    }
+
+    /// Index among [`from_paragraph`](Self::from_paragraph)'s runs of the run
+    /// holding the char at `char_index` of the paragraph text
+    pub(crate) fn run_index_at(paragraph: &Paragraph, char_index: usize) -> usize {
+        let Some(text) = &paragraph.text else {
+            return 0;
+        };
+        let chars: Vec<char> = text.content.chars().collect();
+
+        run_bounds(paragraph, chars.len())
+            .into_iter()
+            .filter(|&(start, end, _)| chars[start..end].iter().any(|&c| !is_stripped(c)))
+            .take_while(|&(_, end, _)| end <= char_index)
+            .count()
+    }
+}
+
+/// (start, end, char shape id) of each non-empty char shape span of a paragraph
+/// whose text is `len` chars long
+fn run_bounds(paragraph: &Paragraph, len: usize) -> Vec<(usize, usize, u16)> {
+    let mut boundaries: Vec<(usize, u16)> = paragraph
+        .char_shapes
+        .as_ref()
+        .map(|shapes| {
+            shapes
+                .char_positions
+                .iter()
+                .map(|pos| (pos.position as usize, pos.char_shape_id))
+                .collect()
+        })
+        .unwrap_or_default();
+    if boundaries.first().is_none_or(|&(start, _)| start > 0) {
+        boundaries.insert(0, (0, 0));
+    }
+
+    boundaries
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &(start, char_shape_id))| {
+            let end = boundaries
+                .get(i + 1)
+                .map_or(len, |&(next, _)| next)
+                .min(len);
+            (start < end).then_some((start, end, char_shape_id))
+        })
+        .collect()
+}
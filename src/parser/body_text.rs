@@ -1,36 +1,258 @@
 use crate::error::Result;
+use crate::model::column_def::CTRL_COLUMN_DEF;
+use crate::model::control::{CTRL_TABLE, TAG_LIST_HEADER, TAG_TABLE};
+use crate::model::extraction::TextSpan;
+use crate::model::field::field_values;
+use crate::model::form::{FormField, CTRL_FORM, TAG_FORM_OBJECT};
+use crate::model::hidden_comment::{HiddenComment, CTRL_HIDDEN_COMMENT};
+use crate::model::ruby::{RUBY_ANCHOR, RUBY_TERMINATOR};
 use crate::model::{
-    CtrlHeader, ListHeader, PageDef, ParaCharShape, ParaLineSeg, ParaText, Paragraph, Section,
-    SectionDef,
+    ColumnDef, CtrlHeader, ExtractionOptions, Field, ListHeader, PageBorderFill, PageDef,
+    ParaCharShape, ParaLineSeg, ParaText, Paragraph, Ruby, Section, SectionDef, Shape, ShapeKind,
+    Table, TableCell,
 };
-use crate::parser::record::{HwpTag, Record};
-use crate::reader::StreamReader;
+use crate::parser::record::{HwpTag, Record, RecordReader, DEFAULT_MAX_RECORD_SIZE};
 use crate::utils::compression::decompress_stream;
 
+/// Control id of the section definition control (`secd`)
+const CTRL_SECTION_DEF: u32 = u32::from_be_bytes(*b"secd");
+
+/// Control id of drawing objects (`gso `)
+const CTRL_DRAWING_OBJECT: u32 = u32::from_be_bytes(*b"gso ");
+
+/// Spec tag of SHAPE_COMPONENT, the first child of a drawing object control
+const TAG_SHAPE_COMPONENT: u16 = 0x4C;
+
+/// Spec tag of SHAPE_COMPONENT_PICTURE
+const TAG_SHAPE_COMPONENT_PICTURE: u16 = 0x55;
+
+/// Offset of the BinData item id in SHAPE_COMPONENT_PICTURE
+const PICTURE_BIN_ID_OFFSET: usize = 71;
+
+/// A drawing object being read: records nested below its control header belong to it
+struct OpenShape {
+    level: u8,
+    shape: Shape,
+    texts: Vec<String>,
+}
+
+impl OpenShape {
+    /// Handle a record nested in the drawing object
+    fn push(&mut self, record: &Record) {
+        match record.tag_id() {
+            TAG_SHAPE_COMPONENT if self.shape.kind == ShapeKind::Unknown => {
+                if let Some(id) = control_id(record) {
+                    self.shape.kind = ShapeKind::from_component_id(id);
+                }
+            }
+            TAG_SHAPE_COMPONENT_PICTURE => {
+                if let Some(id) = record
+                    .data
+                    .get(PICTURE_BIN_ID_OFFSET..PICTURE_BIN_ID_OFFSET + 2)
+                {
+                    self.shape.bin_data_id = Some(u16::from_le_bytes([id[0], id[1]]));
+                }
+            }
+            // PARA_HEADER and PARA_TEXT of the shape's paragraph list
+            0x42 => self.texts.push(String::new()),
+            0x43 => {
+                if let (Some(text), Ok(para_text)) =
+                    (self.texts.last_mut(), ParaText::from_record(record))
+                {
+                    ExtractionOptions::default().push_paragraph_text(&para_text.content, text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> Shape {
+        self.shape.text = self.texts.join("\n");
+        self.shape
+    }
+}
+
+/// A hidden comment being read: records nested below its control header hold
+/// its paragraph list
+struct OpenComment {
+    level: u8,
+    texts: Vec<String>,
+}
+
+impl OpenComment {
+    /// Handle a record nested in the comment
+    fn push(&mut self, record: &Record) {
+        match record.tag_id() {
+            0x42 => self.texts.push(String::new()),
+            0x43 => {
+                if let (Some(text), Ok(para_text)) =
+                    (self.texts.last_mut(), ParaText::from_record(record))
+                {
+                    ExtractionOptions::default().push_paragraph_text(&para_text.content, text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(self) -> HiddenComment {
+        HiddenComment {
+            text: self.texts.join("\n"),
+        }
+    }
+}
+
+/// A table being read: its TABLE record and cell LIST_HEADERs sit one level
+/// below its control header, followed by the cells' paragraphs
+struct OpenTable {
+    level: u8,
+    /// Index of the table's paragraph in the section
+    paragraph_index: usize,
+    table: Table,
+}
+
+impl OpenTable {
+    /// Handle a record one level below the control header
+    fn push(&mut self, record: &Record) {
+        match record.tag_id() {
+            TAG_TABLE => {
+                if let Ok(table) = Table::from_record(record) {
+                    self.table = Table {
+                        cells: std::mem::take(&mut self.table.cells),
+                        ..table
+                    };
+                }
+            }
+            TAG_LIST_HEADER => {
+                let list_id = self.table.cells.len() as u32;
+                if let Ok(cell) = TableCell::from_list_header(record, list_id) {
+                    self.table.cells.push(cell);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Attach the table to its paragraph, which may still be the current one
+    fn finish(self, section: &mut Section, current: Option<&mut Paragraph>) {
+        let para = match section.paragraphs.get_mut(self.paragraph_index) {
+            Some(para) => Some(para),
+            None => current,
+        };
+        if let Some(para) = para {
+            para.table_data = Some(self.table);
+        }
+    }
+}
+
+/// Controls that only carry layout, so skipping them loses no content
+const LAYOUT_CONTROLS: [u32; 2] = [CTRL_SECTION_DEF, CTRL_COLUMN_DEF];
+
 pub struct BodyTextParser;
 
 impl BodyTextParser {
     pub fn parse(data: Vec<u8>, is_compressed: bool) -> Result<BodyText> {
+        Self::parse_with_limit(data, is_compressed, DEFAULT_MAX_RECORD_SIZE)
+    }
+
+    /// Parse a section stream, rejecting records larger than `max_record_size` bytes
+    pub fn parse_with_limit(
+        data: Vec<u8>,
+        is_compressed: bool,
+        max_record_size: usize,
+    ) -> Result<BodyText> {
         let data = if is_compressed {
-            decompress_stream(&data)?
+            decompress_stream(&data).map_err(|e| e.in_stream("BodyText", 0))?
         } else {
             data
         };
 
-        let mut reader = StreamReader::new(data);
+        let mut records = RecordReader::new(data).with_max_record_size(max_record_size);
         let mut sections = Vec::new();
         let mut current_section = Section::default();
         let mut current_paragraph: Option<Paragraph> = None;
 
         let mut first_section = true;
+        let mut open_shape: Option<OpenShape> = None;
+        let mut open_comment: Option<OpenComment> = None;
+        let mut open_tables: Vec<OpenTable> = Vec::new();
+        // Cached values of the fields in the current paragraph's text, in order
+        let mut pending_field_values = std::collections::VecDeque::new();
+        // Char index of each UTF-16 unit of the current paragraph's text
+        let mut unit_offsets = Vec::new();
 
-        while reader.remaining() >= 4 {
-            // Need at least 4 bytes for record header
-            let record = match Record::parse(&mut reader) {
-                Ok(r) => r,
-                Err(_) => break, // Stop parsing on error
+        loop {
+            let offset = records.position();
+            let Some(record) = records.next() else {
+                break;
             };
 
+            // Records below a drawing object's control header describe the object
+            if let Some(shape) = open_shape.as_mut() {
+                if record.header.level > shape.level {
+                    shape.push(&record);
+                    continue;
+                }
+                let shape = open_shape.take().map(OpenShape::finish);
+                if let (Some(para), Some(shape)) = (current_paragraph.as_mut(), shape) {
+                    para.shapes.push(shape);
+                }
+            }
+
+            // Records below a hidden comment's control header hold its text
+            if let Some(comment) = open_comment.as_mut() {
+                if record.header.level > comment.level {
+                    comment.push(&record);
+                    continue;
+                }
+                let comment = open_comment.take().map(OpenComment::finish);
+                if let (Some(para), Some(comment)) = (current_paragraph.as_mut(), comment) {
+                    para.hidden_comments.push(comment);
+                }
+            }
+
+            // Tables end at the next record at or above their control header
+            while open_tables
+                .last()
+                .is_some_and(|t| record.header.level <= t.level)
+            {
+                if let Some(table) = open_tables.pop() {
+                    table.finish(&mut current_section, current_paragraph.as_mut());
+                }
+            }
+            if let Some(table) = open_tables.last_mut() {
+                if record.header.level == table.level + 1
+                    && matches!(record.tag_id(), TAG_TABLE | TAG_LIST_HEADER)
+                {
+                    table.push(&record);
+                    continue;
+                }
+            }
+            // Cells own every paragraph read until the next cell starts
+            if record.tag_id() == 0x42 && !first_section {
+                for table in &mut open_tables {
+                    if let Some(cell) = table.table.cells.last_mut() {
+                        cell.paragraph_count += 1;
+                    }
+                }
+            }
+
+            if let Some(page_border_fill) = PageBorderFill::from_record(&record) {
+                current_section.page_border_fills.push(page_border_fill);
+                continue;
+            }
+
+            // FORM_OBJECT below a form control holds the form field's properties
+            if record.tag_id() == TAG_FORM_OBJECT {
+                if let (Some(para), Some(field)) = (
+                    current_paragraph.as_mut(),
+                    FormField::from_form_object(&record),
+                ) {
+                    para.form_fields.push(field);
+                }
+                continue;
+            }
+
             match HwpTag::from_u16(record.tag_id()) {
                 // Page Definition - only appears once at the beginning
                 Some(HwpTag::PageDef) => {
@@ -48,33 +270,81 @@ impl BodyTextParser {
                         if let Some(para) = current_paragraph.take() {
                             current_section.paragraphs.push(para);
                         }
-                        current_paragraph = Some(Paragraph::default());
+                        current_paragraph =
+                            Some(Paragraph::from_header_record(&record).unwrap_or_default());
+                        unit_offsets.clear();
                     }
                 }
 
                 // Tag 0x43 - Contains text content
                 Some(HwpTag::ColumnDefine) => {
                     if let Some(ref mut para) = current_paragraph {
-                        if let Ok(text) = ParaText::from_record(&record) {
+                        if let Ok((text, offsets)) = ParaText::from_record_with_offsets(&record) {
                             para.text = Some(text);
+                            unit_offsets = offsets;
+                        }
+                        pending_field_values = field_values(&record).into();
+                    }
+                }
+
+                // CTRL_HEADER (0x47) - The `secd` control carries the section definition
+                Some(HwpTag::LineInfo) => {
+                    if let Some(section_def) = parse_section_def_control(&record) {
+                        current_section.section_def = Some(section_def);
+                    } else if control_id(&record) == Some(CTRL_DRAWING_OBJECT) {
+                        open_shape = Shape::from_ctrl_header(&record)
+                            .ok()
+                            .map(|shape| OpenShape {
+                                level: record.header.level,
+                                shape,
+                                texts: Vec::new(),
+                            });
+                    } else if control_id(&record) == Some(CTRL_HIDDEN_COMMENT) {
+                        open_comment = Some(OpenComment {
+                            level: record.header.level,
+                            texts: Vec::new(),
+                        });
+                    } else if control_id(&record) == Some(CTRL_TABLE) {
+                        if let Some(ref mut para) = current_paragraph {
+                            para.ctrl_header = CtrlHeader::from_record(&record).ok();
+                        }
+                        open_tables.push(OpenTable {
+                            level: record.header.level,
+                            paragraph_index: current_section.paragraphs.len(),
+                            table: Table::new_default(0, 0),
+                        });
+                    } else if let Some(mut field) = Field::from_ctrl_header(&record) {
+                        if !field.is_auto_number() {
+                            field.cached_value =
+                                pending_field_values.pop_front().unwrap_or_default();
                         }
+                        if let Some(ref mut para) = current_paragraph {
+                            para.fields.push(field);
+                        }
+                    } else if let Some(column_def) = ColumnDef::from_ctrl_header(&record) {
+                        current_section.column_def.get_or_insert(column_def);
+                    } else if let Some(ruby) = Ruby::from_ctrl_header(&record) {
+                        if let Some(para) = current_paragraph.as_mut() {
+                            fill_ruby(para, &ruby);
+                        }
+                    } else if control_id(&record) == Some(CTRL_FORM) {
+                        // Described by the FORM_OBJECT record that follows
+                    } else if !is_layout_control(&record) {
+                        current_section.unhandled_controls += 1;
                     }
                 }
 
-                // TableControl (0x44) - Contains paragraph properties
+                // PAGE_DEF (0x49) - Written under the `secd` control
+                Some(HwpTag::HeaderFooter) if current_section.page_def.is_none() => {
+                    current_section.page_def = PageDef::from_record(&record).ok();
+                }
+
+                // PARA_CHAR_SHAPE (0x44) - Character shape positions of the paragraph
                 Some(HwpTag::TableControl) => {
                     if let Some(ref mut para) = current_paragraph {
-                        // Try to parse as paragraph header
-                        if let Ok(new_para) = Paragraph::from_header_record(&record) {
-                            // Copy properties from parsed paragraph
-                            para.control_mask = new_para.control_mask;
-                            para.para_shape_id = new_para.para_shape_id;
-                            para.style_id = new_para.style_id;
-                            para.column_type = new_para.column_type;
-                            para.char_shape_count = new_para.char_shape_count;
-                            para.range_tag_count = new_para.range_tag_count;
-                            para.line_align_count = new_para.line_align_count;
-                            para.instance_id = new_para.instance_id;
+                        para.char_shapes = ParaCharShape::from_record(&record).ok();
+                        if let Some(shapes) = &mut para.char_shapes {
+                            shapes.units_to_chars(&unit_offsets);
                         }
                     }
                 }
@@ -91,7 +361,10 @@ impl BodyTextParser {
                 }
                 Some(HwpTag::ParaText) => {
                     if let Some(ref mut para) = current_paragraph {
-                        para.text = Some(ParaText::from_record(&record)?);
+                        para.text = Some(
+                            ParaText::from_record(&record)
+                                .map_err(|e| e.in_stream("BodyText", offset))?,
+                        );
                     }
                 }
                 Some(HwpTag::ParaCharShape) => {
@@ -135,35 +408,225 @@ impl BodyTextParser {
             }
         }
 
+        if let Some(e) = records.take_error() {
+            return Err(e.in_stream("BodyText", records.position()));
+        }
+
         // Add last paragraph and section
+        while let Some(table) = open_tables.pop() {
+            table.finish(&mut current_section, current_paragraph.as_mut());
+        }
+        if let (Some(para), Some(shape)) = (current_paragraph.as_mut(), open_shape) {
+            para.shapes.push(shape.finish());
+        }
+        if let (Some(para), Some(comment)) = (current_paragraph.as_mut(), open_comment) {
+            para.hidden_comments.push(comment.finish());
+        }
         if let Some(para) = current_paragraph {
             current_section.paragraphs.push(para);
         }
         // Always add the section even if empty - there's at least one section
         sections.push(current_section);
 
-        Ok(BodyText { sections })
+        Ok(BodyText {
+            sections,
+            source: SectionSource::BodyText,
+            raw_xml: None,
+            raw_records: None,
+        })
     }
 }
 
+/// Fill the first span of `para` still waiting for its ruby, moving the
+/// character shapes after it along with the text
+fn fill_ruby(para: &mut Paragraph, ruby: &Ruby) {
+    let Some(text) = para.text.as_mut() else {
+        return;
+    };
+    let empty = format!("{RUBY_ANCHOR}{RUBY_TERMINATOR}");
+    let Some(byte) = text.content.find(&empty) else {
+        return;
+    };
+    let annotation = ruby.annotation();
+    let anchor = text.content[..byte].chars().count() as u32;
+    let added = annotation.chars().count() as u32 - 2;
+    text.content
+        .replace_range(byte..byte + empty.len(), &annotation);
+
+    if let Some(shapes) = &mut para.char_shapes {
+        for pos in shapes
+            .char_positions
+            .iter_mut()
+            .filter(|p| p.position > anchor)
+        {
+            pos.position += added;
+        }
+    }
+}
+
+/// Control id at the start of a control header record
+fn control_id(record: &Record) -> Option<u32> {
+    let bytes = record.data.get(..4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn is_layout_control(record: &Record) -> bool {
+    control_id(record).is_some_and(|ctrl_id| LAYOUT_CONTROLS.contains(&ctrl_id))
+}
+
+/// Parse a `secd` control header into a section definition
+fn parse_section_def_control(record: &Record) -> Option<SectionDef> {
+    if control_id(record)? != CTRL_SECTION_DEF {
+        return None;
+    }
+
+    let body = Record {
+        header: record.header,
+        data: record.data[4..].to_vec(),
+    };
+    SectionDef::from_record(&body).ok()
+}
+
+/// Storage a section stream was read from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SectionSource {
+    /// `BodyText/Section<n>`, used by regular documents
+    #[default]
+    BodyText,
+    /// `ViewText/Section<n>`, used by distribution documents
+    ViewText,
+}
+
 #[derive(Debug, Default)]
 pub struct BodyText {
     pub sections: Vec<Section>,
+    /// Storage the section stream came from
+    pub source: SectionSource,
+    /// Raw section XML, for sections read from HWPX
+    pub raw_xml: Option<String>,
+    /// Uncompressed section records, written verbatim in place of `sections`
+    pub raw_records: Option<Vec<u8>>,
 }
 
 impl BodyText {
     pub fn extract_text(&self) -> String {
-        let mut result = String::new();
+        self.extract_text_with(&ExtractionOptions::default())
+    }
+
+    pub fn extract_text_with(&self, options: &ExtractionOptions) -> String {
+        let mut result = String::with_capacity(self.text_capacity());
+        self.push_text_with(options, &mut result);
+        result
+    }
+
+    /// Raw text length plus line breaks, used to size the output buffer once
+    pub(crate) fn text_capacity(&self) -> usize {
+        let shape_len: usize = self
+            .paragraphs()
+            .flat_map(|para| para.shapes.iter())
+            .map(|shape| shape.text.len() + 1)
+            .sum();
+        let text_len: usize = self
+            .paragraph_texts()
+            .map(|text| text.content.len() + 1)
+            .sum();
+        text_len + shape_len
+    }
+
+    /// Append the extracted text to `out`, with shape text after its anchor paragraph
+    pub(crate) fn push_text_with(&self, options: &ExtractionOptions, out: &mut String) {
+        self.push_text_tracked(options, out, 0, None);
+    }
 
-        for section in &self.sections {
-            for para in &section.paragraphs {
-                if let Some(ref text) = para.text {
-                    result.push_str(&text.content);
-                    result.push('\n');
+    /// Append the extracted text to `out`, recording where each paragraph landed
+    ///
+    /// Sections are numbered from `first_section`. Footnotes and hidden
+    /// comments follow their paragraph when included.
+    pub(crate) fn push_text_tracked(
+        &self,
+        options: &ExtractionOptions,
+        out: &mut String,
+        first_section: usize,
+        mut spans: Option<&mut Vec<TextSpan>>,
+    ) {
+        let paragraphs = self.sections.iter().enumerate().flat_map(|(i, section)| {
+            section
+                .paragraphs
+                .iter()
+                .enumerate()
+                .map(move |(j, para)| (first_section + i, j, para))
+        });
+        // Cell paragraphs of a skipped table that are still to come
+        let mut skipped_cell_paragraphs = 0usize;
+        for (section, paragraph_index, para) in paragraphs {
+            if skipped_cell_paragraphs > 0 {
+                skipped_cell_paragraphs -= 1;
+                continue;
+            }
+            if let (Some(table), false) = (&para.table_data, options.include_tables) {
+                skipped_cell_paragraphs = table
+                    .cells
+                    .iter()
+                    .map(|cell| cell.paragraph_count as usize)
+                    .sum();
+            }
+            if let Some(text) = &para.text {
+                match spans.as_deref_mut() {
+                    Some(spans) => {
+                        let start = out.len();
+                        let mut pieces = Vec::new();
+                        options.push_paragraph_text_tracked(&text.content, out, |index, byte| {
+                            pieces.push((byte, index))
+                        });
+                        spans.push(TextSpan {
+                            section,
+                            paragraph_index,
+                            start,
+                            end: out.len(),
+                            pieces,
+                        });
+                    }
+                    None => options.push_paragraph_text(&text.content, out),
+                }
+                out.push_str(&options.paragraph_separator);
+            }
+            for shape in para.shapes.iter().filter(|shape| !shape.text.is_empty()) {
+                if let Some(marker) = &options.shape_marker {
+                    out.push_str(marker);
+                }
+                out.push_str(&shape.text);
+                out.push_str(&options.paragraph_separator);
+            }
+            if options.include_footnotes {
+                for footnote in para.footnotes.iter().filter(|note| !note.text.is_empty()) {
+                    out.push_str(&footnote.text);
+                    out.push_str(&options.paragraph_separator);
+                }
+            }
+            if options.include_hidden {
+                for comment in para.hidden_comments.iter().filter(|c| !c.text.is_empty()) {
+                    out.push_str(&comment.text);
+                    out.push_str(&options.paragraph_separator);
                 }
             }
         }
+    }
 
-        result
+    fn paragraphs(&self) -> impl Iterator<Item = &Paragraph> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.paragraphs.iter())
+    }
+
+    /// Control records skipped across all sections
+    pub fn unhandled_controls(&self) -> usize {
+        self.sections
+            .iter()
+            .map(|section| section.unhandled_controls)
+            .sum()
+    }
+
+    fn paragraph_texts(&self) -> impl Iterator<Item = &ParaText> {
+        self.paragraphs().filter_map(|para| para.text.as_ref())
     }
 }
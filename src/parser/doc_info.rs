@@ -5,71 +5,89 @@ use crate::model::numbering::{Bullet, Numbering};
 use crate::model::style::Style;
 use crate::model::tab_def::TabDef;
 use crate::model::{CharShape, DocumentProperties, FaceName, ParaShape};
-use crate::parser::record::{HwpTag, Record};
-use crate::reader::StreamReader;
+use crate::parser::record::{HwpTag, Record, RecordReader, DEFAULT_MAX_RECORD_SIZE};
 use crate::utils::compression::decompress_stream;
 
 pub struct DocInfoParser;
 
 impl DocInfoParser {
     pub fn parse(data: Vec<u8>, is_compressed: bool) -> Result<DocInfo> {
+        Self::parse_with_limit(data, is_compressed, DEFAULT_MAX_RECORD_SIZE)
+    }
+
+    /// Parse DocInfo, rejecting records larger than `max_record_size` bytes
+    pub fn parse_with_limit(
+        data: Vec<u8>,
+        is_compressed: bool,
+        max_record_size: usize,
+    ) -> Result<DocInfo> {
         let data = if is_compressed {
-            decompress_stream(&data)?
+            decompress_stream(&data).map_err(|e| e.in_stream("DocInfo", 0))?
         } else {
             data
         };
 
-        let mut reader = StreamReader::new(data);
+        let mut records = RecordReader::new(data).with_max_record_size(max_record_size);
         let mut doc_info = DocInfo::default();
 
-        while reader.remaining() >= 4 {
-            // Need at least 4 bytes for record header
-            let record = match Record::parse(&mut reader) {
-                Ok(r) => r,
-                Err(_) => break, // Stop parsing on error
+        loop {
+            let offset = records.position();
+            let Some(record) = records.next() else {
+                break;
             };
 
-            match HwpTag::from_u16(record.tag_id()) {
-                Some(HwpTag::DocumentProperties) => {
-                    doc_info.properties = Some(DocumentProperties::from_record(&record)?);
-                }
-                Some(HwpTag::FaceName) => {
-                    doc_info.face_names.push(FaceName::from_record(&record)?);
-                }
-                Some(HwpTag::CharShape) => {
-                    doc_info.char_shapes.push(CharShape::from_record(&record)?);
-                }
-                Some(HwpTag::ParaShape) => {
-                    doc_info.para_shapes.push(ParaShape::from_record(&record)?);
-                }
-                Some(HwpTag::Style) => {
-                    doc_info.styles.push(Style::from_record(&record)?);
-                }
-                Some(HwpTag::BorderFill) => {
-                    doc_info
-                        .border_fills
-                        .push(BorderFill::from_record(&record)?);
-                }
-                Some(HwpTag::TabDef) => {
-                    doc_info.tab_defs.push(TabDef::from_record(&record)?);
-                }
-                Some(HwpTag::Numbering) => {
-                    doc_info.numberings.push(Numbering::from_record(&record)?);
-                }
-                Some(HwpTag::Bullet) => {
-                    doc_info.bullets.push(Bullet::from_record(&record)?);
-                }
-                Some(HwpTag::BinData) => {
-                    doc_info.bin_data.push(BinData::from_record(&record)?);
-                }
-                _ => {
-                    // Skip unknown or unimplemented tags
-                }
-            }
+            Self::parse_record(&mut doc_info, &record)
+                .map_err(|e| e.in_stream("DocInfo", offset))?;
+        }
+
+        if let Some(e) = records.take_error() {
+            return Err(e.in_stream("DocInfo", records.position()));
         }
 
         Ok(doc_info)
     }
+
+    fn parse_record(doc_info: &mut DocInfo, record: &Record) -> Result<()> {
+        match HwpTag::from_u16(record.tag_id()) {
+            Some(HwpTag::DocumentProperties) => {
+                doc_info.properties = Some(DocumentProperties::from_record(record)?);
+            }
+            Some(HwpTag::FaceName) => {
+                doc_info.face_names.push(FaceName::from_record(record)?);
+            }
+            Some(HwpTag::CharShape) => {
+                doc_info.char_shapes.push(CharShape::from_record(record)?);
+                doc_info.raw_char_shapes.push(record.data.clone());
+            }
+            Some(HwpTag::ParaShape) => {
+                doc_info.para_shapes.push(ParaShape::from_record(record)?);
+                doc_info.raw_para_shapes.push(record.data.clone());
+            }
+            Some(HwpTag::Style) => {
+                doc_info.styles.push(Style::from_record(record)?);
+            }
+            Some(HwpTag::BorderFill) => {
+                doc_info.border_fills.push(BorderFill::from_record(record)?);
+            }
+            Some(HwpTag::TabDef) => {
+                doc_info.tab_defs.push(TabDef::from_record(record)?);
+            }
+            Some(HwpTag::Numbering) => {
+                doc_info.numberings.push(Numbering::from_record(record)?);
+            }
+            Some(HwpTag::Bullet) => {
+                doc_info.bullets.push(Bullet::from_record(record)?);
+            }
+            Some(HwpTag::BinData) => {
+                doc_info.bin_data.push(BinData::from_record(record)?);
+            }
+            _ => {
+                // Skip unknown or unimplemented tags
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default)]
@@ -84,4 +102,8 @@ pub struct DocInfo {
     pub numberings: Vec<Numbering>,
     pub bullets: Vec<Bullet>,
     pub bin_data: Vec<BinData>,
+    /// Record data of each character shape as read, empty for created documents
+    pub raw_char_shapes: Vec<Vec<u8>>,
+    /// Record data of each paragraph shape as read, empty for created documents
+    pub raw_para_shapes: Vec<Vec<u8>>,
 }
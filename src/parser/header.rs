@@ -12,11 +12,15 @@ pub struct FileHeader {
 }
 
 impl FileHeader {
+    /// Parse the known fields of a FileHeader stream
+    ///
+    /// Bytes past the first 256, which some producers pad with zeros, are ignored.
     pub fn parse(data: Vec<u8>) -> Result<Self> {
         if data.len() < 256 {
-            return Err(HwpError::InvalidFormat(
-                "FileHeader must be 256 bytes".to_string(),
-            ));
+            return Err(HwpError::InvalidFormat(format!(
+                "FileHeader must be at least 256 bytes, got {}",
+                data.len()
+            )));
         }
 
         let mut reader = StreamReader::new(data);
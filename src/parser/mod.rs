@@ -4,4 +4,4 @@ pub mod header;
 pub mod record;
 
 pub use self::header::FileHeader;
-pub use self::record::{HwpTag, Record, RecordHeader};
+pub use self::record::{HwpTag, Record, RecordHeader, RecordReader, DEFAULT_MAX_RECORD_SIZE};
@@ -34,6 +34,9 @@ impl RecordHeader {
     }
 }
 
+/// Largest record data size accepted by default (8 MiB)
+pub const DEFAULT_MAX_RECORD_SIZE: usize = 8 * 1024 * 1024;
+
 #[derive(Debug)]
 pub struct Record {
     pub header: RecordHeader,
@@ -42,6 +45,11 @@ pub struct Record {
 
 impl Record {
     pub fn parse(reader: &mut StreamReader) -> Result<Self> {
+        Self::parse_with_limit(reader, DEFAULT_MAX_RECORD_SIZE)
+    }
+
+    /// Parse a record, rejecting data larger than `max_size` bytes
+    pub fn parse_with_limit(reader: &mut StreamReader, max_size: usize) -> Result<Self> {
         if reader.remaining() < 4 {
             return Err(crate::error::HwpError::ParseError(
                 "Not enough data for record header".to_string(),
@@ -53,6 +61,13 @@ impl Record {
         // Size field contains the data size (without header)
         let data_size = header.size as usize;
 
+        if data_size > max_size {
+            return Err(crate::error::HwpError::ParseError(format!(
+                "Record size {} exceeds the limit of {} bytes",
+                data_size, max_size
+            )));
+        }
+
         if data_size > reader.remaining() {
             return Err(crate::error::HwpError::ParseError(format!(
                 "Record size {} exceeds remaining data {}",
@@ -71,11 +86,79 @@ impl Record {
         self.header.tag_id
     }
 
+    /// Nesting level of the record
+    pub fn level(&self) -> u8 {
+        self.header.level
+    }
+
+    /// Size of the record data in bytes
+    pub fn size(&self) -> u32 {
+        self.header.size
+    }
+
     pub fn data_reader(&self) -> StreamReader {
         StreamReader::new(self.data.clone())
     }
 }
 
+/// Iterator over the records of a decompressed stream
+///
+/// Iteration ends at the end of the stream or at the first record that cannot
+/// be read, such as one whose size exceeds the remaining data or the size
+/// limit; [`take_error`](Self::take_error) then returns why.
+pub struct RecordReader {
+    reader: StreamReader,
+    max_record_size: usize,
+    error: Option<crate::error::HwpError>,
+}
+
+impl RecordReader {
+    pub fn new(data: Vec<u8>) -> Self {
+        Self {
+            reader: StreamReader::new(data),
+            max_record_size: DEFAULT_MAX_RECORD_SIZE,
+            error: None,
+        }
+    }
+
+    /// Set the largest record data size accepted, in bytes
+    pub fn with_max_record_size(mut self, max_record_size: usize) -> Self {
+        self.max_record_size = max_record_size;
+        self
+    }
+
+    /// Byte offset of the next record in the stream, or of the record that
+    /// could not be read
+    pub fn position(&self) -> usize {
+        self.reader.position() as usize
+    }
+
+    /// Why iteration stopped before the end of the stream, if it did
+    pub fn take_error(&mut self) -> Option<crate::error::HwpError> {
+        self.error.take()
+    }
+}
+
+impl Iterator for RecordReader {
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Record> {
+        // A record header needs at least 4 bytes
+        if self.error.is_some() || self.reader.remaining() < 4 {
+            return None;
+        }
+        let start = self.reader.position();
+        match Record::parse_with_limit(&mut self.reader, self.max_record_size) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                self.reader.set_position(start);
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u16)]
 pub enum HwpTag {
@@ -4,4 +4,4 @@ mod summary_info;
 
 pub use preview_image::PreviewImage;
 pub use preview_text::PreviewText;
-pub use summary_info::SummaryInfo;
+pub use summary_info::{DocStatistics, DocumentMetadata, SummaryInfo};
@@ -1,4 +1,4 @@
-use crate::error::{HwpError, Result};
+use crate::error::Result;
 
 #[derive(Debug, Clone)]
 pub struct PreviewText {
@@ -6,19 +6,10 @@ pub struct PreviewText {
 }
 
 impl PreviewText {
+    /// Decode a PrvText stream
+    ///
+    /// A trailing odd byte, as left by truncated streams, is ignored.
     pub fn from_bytes(data: &[u8]) -> Result<Self> {
-        if data.is_empty() {
-            return Ok(Self {
-                content: String::new(),
-            });
-        }
-
-        if !data.len().is_multiple_of(2) {
-            return Err(HwpError::ParseError(
-                "PreviewText data length must be even (UTF-16LE)".to_string(),
-            ));
-        }
-
         let u16_data: Vec<u16> = data
             .chunks_exact(2)
             .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
@@ -50,6 +41,15 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_odd_length_preview_text() {
+        let result = PreviewText::from_bytes(&[0x41]).unwrap();
+        assert!(result.is_empty());
+
+        let result = PreviewText::from_bytes(&[0x48, 0x00, 0x69, 0x00, 0x21]).unwrap();
+        assert_eq!(result.text(), "Hi");
+    }
+
     #[test]
     fn test_utf16le_decode() {
         let data: Vec<u8> = "Hello"
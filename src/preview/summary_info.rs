@@ -9,13 +9,88 @@ pub struct SummaryInfo {
     pub comments: Option<String>,
     pub last_saved_by: Option<String>,
     pub revision_number: Option<String>,
-    pub creation_date: Option<i64>,
-    pub last_saved_date: Option<i64>,
+    /// Raw OLE FILETIME (100ns intervals since 1601-01-01 UTC)
+    pub creation_date: Option<u64>,
+    /// Raw OLE FILETIME (100ns intervals since 1601-01-01 UTC)
+    pub last_saved_date: Option<u64>,
     pub page_count: Option<i32>,
     pub word_count: Option<i32>,
     pub char_count: Option<i32>,
 }
 
+/// Counts cached by the authoring application when the file was saved
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DocStatistics {
+    pub pages: Option<u32>,
+    pub words: Option<u32>,
+    pub characters: Option<u32>,
+}
+
+/// Document properties of the summary information, with decoded timestamps
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentMetadata {
+    pub title: Option<String>,
+    pub subject: Option<String>,
+    pub author: Option<String>,
+    pub keywords: Option<String>,
+    pub comments: Option<String>,
+    pub last_saved_by: Option<String>,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_saved: Option<chrono::DateTime<chrono::Utc>>,
+    pub page_count: Option<u32>,
+    pub word_count: Option<u32>,
+    pub char_count: Option<u32>,
+}
+
+impl DocumentMetadata {
+    /// Cached page, word and character counts, if any are recorded
+    pub fn statistics(&self) -> Option<DocStatistics> {
+        let stats = DocStatistics {
+            pages: self.page_count,
+            words: self.word_count,
+            characters: self.char_count,
+        };
+        (stats != DocStatistics::default()).then_some(stats)
+    }
+}
+
+impl From<&SummaryInfo> for DocumentMetadata {
+    fn from(info: &SummaryInfo) -> Self {
+        Self {
+            title: info.title.clone(),
+            subject: info.subject.clone(),
+            author: info.author.clone(),
+            keywords: info.keywords.clone(),
+            comments: info.comments.clone(),
+            last_saved_by: info.last_saved_by.clone(),
+            created: info.creation_datetime(),
+            last_saved: info.last_saved_datetime(),
+            page_count: info.statistics().and_then(|s| s.pages),
+            word_count: info.statistics().and_then(|s| s.words),
+            char_count: info.statistics().and_then(|s| s.characters),
+        }
+    }
+}
+
+impl From<&DocumentMetadata> for SummaryInfo {
+    fn from(metadata: &DocumentMetadata) -> Self {
+        Self {
+            title: metadata.title.clone(),
+            subject: metadata.subject.clone(),
+            author: metadata.author.clone(),
+            keywords: metadata.keywords.clone(),
+            comments: metadata.comments.clone(),
+            last_saved_by: metadata.last_saved_by.clone(),
+            creation_date: metadata.created.and_then(Self::datetime_to_filetime),
+            last_saved_date: metadata.last_saved.and_then(Self::datetime_to_filetime),
+            page_count: metadata.page_count.and_then(|v| i32::try_from(v).ok()),
+            word_count: metadata.word_count.and_then(|v| i32::try_from(v).ok()),
+            char_count: metadata.char_count.and_then(|v| i32::try_from(v).ok()),
+            ..Default::default()
+        }
+    }
+}
+
 const PROPERTY_SET_HEADER_SIZE: usize = 28;
 const PROPERTY_ID_TITLE: u32 = 0x02;
 const PROPERTY_ID_SUBJECT: u32 = 0x03;
@@ -30,6 +105,21 @@ const PROPERTY_ID_PAGE_COUNT: u32 = 0x0E;
 const PROPERTY_ID_WORD_COUNT: u32 = 0x0F;
 const PROPERTY_ID_CHAR_COUNT: u32 = 0x10;
 
+/// FILETIME value of the Unix epoch (1970-01-01 UTC)
+const FILETIME_UNIX_EPOCH: u64 = 116_444_736_000_000_000;
+const FILETIME_TICKS_PER_SECOND: u64 = 10_000_000;
+
+const PROPERTY_ID_CODEPAGE: u32 = 0x01;
+
+/// FMTID of the HwpSummaryInformation property set, {9FA2B660-1061-11D4-B4C6-006097C09D8C}
+const FMTID_HWP_SUMMARY: [u8; 16] = [
+    0x60, 0xB6, 0xA2, 0x9F, 0x61, 0x10, 0xD4, 0x11, 0xB4, 0xC6, 0x00, 0x60, 0x97, 0xC0, 0x9D, 0x8C,
+];
+
+/// Code page of UTF-8, declared for the string properties the writer emits
+const CODEPAGE_UTF8: i16 = 65001u16 as i16;
+
+const VT_I2: u32 = 0x02;
 const VT_LPSTR: u32 = 0x1E;
 const VT_FILETIME: u32 = 0x40;
 const VT_I4: u32 = 0x03;
@@ -180,7 +270,7 @@ impl SummaryInfo {
         String::from_utf8(trimmed).ok()
     }
 
-    fn read_filetime_property(data: &[u8], offset: usize, prop_type: u32) -> Option<i64> {
+    fn read_filetime_property(data: &[u8], offset: usize, prop_type: u32) -> Option<u64> {
         if prop_type != VT_FILETIME {
             return None;
         }
@@ -194,13 +284,13 @@ impl SummaryInfo {
             data[offset + 5],
             data[offset + 6],
             data[offset + 7],
-        ]) as i64;
+        ]) as u64;
         let high = u32::from_le_bytes([
             data[offset + 8],
             data[offset + 9],
             data[offset + 10],
             data[offset + 11],
-        ]) as i64;
+        ]) as u64;
 
         Some((high << 32) | low)
     }
@@ -222,6 +312,125 @@ impl SummaryInfo {
         ]))
     }
 
+    /// Convert a raw OLE FILETIME to a UTC timestamp
+    pub fn filetime_to_datetime(filetime: u64) -> Option<chrono::DateTime<chrono::Utc>> {
+        let since_epoch = filetime.checked_sub(FILETIME_UNIX_EPOCH)?;
+        let secs = (since_epoch / FILETIME_TICKS_PER_SECOND) as i64;
+        let nanos = ((since_epoch % FILETIME_TICKS_PER_SECOND) * 100) as u32;
+        chrono::DateTime::from_timestamp(secs, nanos)
+    }
+
+    pub fn creation_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.creation_date.and_then(Self::filetime_to_datetime)
+    }
+
+    pub fn last_saved_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.last_saved_date.and_then(Self::filetime_to_datetime)
+    }
+
+    /// Cached page, word and character counts, if any are recorded
+    ///
+    /// Negative counts are treated as absent.
+    pub fn statistics(&self) -> Option<DocStatistics> {
+        let count = |value: Option<i32>| value.and_then(|v| u32::try_from(v).ok());
+        let stats = DocStatistics {
+            pages: count(self.page_count),
+            words: count(self.word_count),
+            characters: count(self.char_count),
+        };
+        (stats != DocStatistics::default()).then_some(stats)
+    }
+
+    /// Convert a UTC timestamp to a raw OLE FILETIME
+    pub fn datetime_to_filetime(datetime: chrono::DateTime<chrono::Utc>) -> Option<u64> {
+        let secs = u64::try_from(datetime.timestamp()).ok()?;
+        let ticks =
+            secs * FILETIME_TICKS_PER_SECOND + datetime.timestamp_subsec_nanos() as u64 / 100;
+        Some(FILETIME_UNIX_EPOCH + ticks)
+    }
+
+    /// Serialize as an OLE property set stream with a single section
+    ///
+    /// Strings are written as UTF-8, declared through the code page property.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut properties: Vec<(u32, Vec<u8>)> = Vec::new();
+
+        let mut codepage = VT_I2.to_le_bytes().to_vec();
+        codepage.extend_from_slice(&CODEPAGE_UTF8.to_le_bytes());
+        properties.push((PROPERTY_ID_CODEPAGE, codepage));
+
+        let strings = [
+            (PROPERTY_ID_TITLE, &self.title),
+            (PROPERTY_ID_SUBJECT, &self.subject),
+            (PROPERTY_ID_AUTHOR, &self.author),
+            (PROPERTY_ID_KEYWORDS, &self.keywords),
+            (PROPERTY_ID_COMMENTS, &self.comments),
+            (PROPERTY_ID_LAST_SAVED_BY, &self.last_saved_by),
+            (PROPERTY_ID_REVISION_NUMBER, &self.revision_number),
+        ];
+        for (id, value) in strings {
+            let Some(value) = value else { continue };
+            let mut bytes = VT_LPSTR.to_le_bytes().to_vec();
+            bytes.extend_from_slice(&(value.len() as u32 + 1).to_le_bytes());
+            bytes.extend_from_slice(value.as_bytes());
+            bytes.push(0);
+            properties.push((id, bytes));
+        }
+
+        let dates = [
+            (PROPERTY_ID_CREATION_DATE, self.creation_date),
+            (PROPERTY_ID_LAST_SAVED_DATE, self.last_saved_date),
+        ];
+        for (id, value) in dates {
+            let Some(value) = value else { continue };
+            let mut bytes = VT_FILETIME.to_le_bytes().to_vec();
+            bytes.extend_from_slice(&value.to_le_bytes());
+            properties.push((id, bytes));
+        }
+
+        let counts = [
+            (PROPERTY_ID_PAGE_COUNT, self.page_count),
+            (PROPERTY_ID_WORD_COUNT, self.word_count),
+            (PROPERTY_ID_CHAR_COUNT, self.char_count),
+        ];
+        for (id, value) in counts {
+            let Some(value) = value else { continue };
+            let mut bytes = VT_I4.to_le_bytes().to_vec();
+            bytes.extend_from_slice(&value.to_le_bytes());
+            properties.push((id, bytes));
+        }
+
+        // Section: size, count, (id, offset) pairs, then 4-byte aligned values
+        let mut index = Vec::new();
+        let mut values = Vec::new();
+        let count = properties.len();
+        let values_start = 8 + count * 8;
+        for (id, mut value) in properties {
+            index.extend_from_slice(&id.to_le_bytes());
+            index.extend_from_slice(&((values_start + values.len()) as u32).to_le_bytes());
+            value.resize(value.len().next_multiple_of(4), 0);
+            values.extend(value);
+        }
+        let mut section = ((values_start + values.len()) as u32)
+            .to_le_bytes()
+            .to_vec();
+        section.extend_from_slice(&(count as u32).to_le_bytes());
+        section.extend(index);
+        section.extend(values);
+
+        let section_offset = PROPERTY_SET_HEADER_SIZE + 20;
+        let mut data = Vec::with_capacity(section_offset + section.len());
+        data.extend_from_slice(&0xFFFEu16.to_le_bytes());
+        data.extend_from_slice(&0u16.to_le_bytes()); // format version
+        data.extend_from_slice(&0u32.to_le_bytes()); // system identifier
+        data.extend_from_slice(&[0u8; 16]); // CLSID
+        data.extend_from_slice(&1u32.to_le_bytes()); // property set count
+        data.extend_from_slice(&FMTID_HWP_SUMMARY);
+        data.extend_from_slice(&(section_offset as u32).to_le_bytes());
+        data.extend(section);
+        data
+    }
+
     pub fn has_metadata(&self) -> bool {
         self.title.is_some()
             || self.author.is_some()
@@ -247,6 +456,102 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_filetime_to_datetime() {
+        use chrono::TimeZone;
+
+        // 2021-01-01T00:00:00Z
+        let expected = chrono::Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            SummaryInfo::filetime_to_datetime(132_539_328_000_000_000),
+            Some(expected)
+        );
+        // Dates before the Unix epoch are not representable here
+        assert_eq!(SummaryInfo::filetime_to_datetime(0), None);
+    }
+
+    #[test]
+    fn test_creation_date_property() {
+        let filetime: u64 = 132_539_328_000_000_000;
+        let mut data = vec![0xFE, 0xFF];
+        data.extend(vec![0u8; 42]);
+        data.extend(48u32.to_le_bytes()); // section offset
+        data.extend(28u32.to_le_bytes()); // section size
+        data.extend(1u32.to_le_bytes()); // property count
+        data.extend(PROPERTY_ID_CREATION_DATE.to_le_bytes());
+        data.extend(16u32.to_le_bytes()); // value offset within section
+        data.extend(VT_FILETIME.to_le_bytes());
+        data.extend(filetime.to_le_bytes());
+
+        let info = SummaryInfo::from_bytes(&data).unwrap();
+        assert_eq!(info.creation_date, Some(filetime));
+        assert_eq!(
+            info.creation_datetime().unwrap().to_rfc3339(),
+            "2021-01-01T00:00:00+00:00"
+        );
+        assert!(info.last_saved_datetime().is_none());
+    }
+
+    #[test]
+    fn test_statistics_properties() {
+        let mut data = vec![0xFE, 0xFF];
+        data.extend(vec![0u8; 42]);
+        data.extend(48u32.to_le_bytes()); // section offset
+        data.extend(48u32.to_le_bytes()); // section size
+        data.extend(3u32.to_le_bytes()); // property count
+        for (i, id) in [
+            PROPERTY_ID_PAGE_COUNT,
+            PROPERTY_ID_WORD_COUNT,
+            PROPERTY_ID_CHAR_COUNT,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            data.extend(id.to_le_bytes());
+            data.extend((32 + i as u32 * 8).to_le_bytes());
+        }
+        for value in [3i32, 120, -1] {
+            data.extend(VT_I4.to_le_bytes());
+            data.extend(value.to_le_bytes());
+        }
+
+        let info = SummaryInfo::from_bytes(&data).unwrap();
+        assert_eq!(
+            info.statistics(),
+            Some(DocStatistics {
+                pages: Some(3),
+                words: Some(120),
+                characters: None,
+            })
+        );
+        assert_eq!(
+            DocumentMetadata::from(&info).statistics(),
+            info.statistics()
+        );
+        assert_eq!(SummaryInfo::default().statistics(), None);
+        assert_eq!(DocumentMetadata::default().statistics(), None);
+    }
+
+    #[test]
+    fn test_to_bytes_roundtrip() {
+        let info = SummaryInfo {
+            title: Some("분기 보고서".to_string()),
+            author: Some("홍길동".to_string()),
+            subject: Some("Q3".to_string()),
+            creation_date: Some(132_539_328_000_000_000),
+            page_count: Some(4),
+            ..Default::default()
+        };
+
+        let parsed = SummaryInfo::from_bytes(&info.to_bytes()).unwrap();
+        assert_eq!(parsed.title, info.title);
+        assert_eq!(parsed.author, info.author);
+        assert_eq!(parsed.subject, info.subject);
+        assert_eq!(parsed.keywords, None);
+        assert_eq!(parsed.creation_date, info.creation_date);
+        assert_eq!(parsed.page_count, Some(4));
+    }
+
     #[test]
     fn test_minimal_valid_header() {
         let mut data = vec![0xFE, 0xFF];
@@ -1,10 +1,105 @@
 use crate::error::{HwpError, Result};
-use crate::{HwpReader, HwpxReader};
+use crate::model::{ExtractionOptions, RubyHandling};
+use crate::{HwpDocument, HwpReader, HwpxReader};
+use std::collections::HashSet;
 use std::path::Path;
 
+/// What [`extract_text_for_rag_with`] does when the text is shorter than `min_chars`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ShortPolicy {
+    /// Fail with [`HwpError::InvalidFormat`]
+    #[default]
+    Error,
+    /// Return the text that was extracted
+    ReturnAnyway,
+    /// Return an empty string
+    Empty,
+}
+
+/// Options for [`extract_text_for_rag_with`]
+#[derive(Debug, Clone)]
+pub struct RagOptions {
+    /// Minimum number of characters in the normalized text
+    pub min_chars: usize,
+    /// Handling of text shorter than `min_chars`
+    pub on_short: ShortPolicy,
+    /// Text emitted for ruby annotations (Hanja with Hangul readings)
+    pub ruby_handling: RubyHandling,
+    /// Include the text of table cells
+    pub include_tables: bool,
+    /// Fail with [`HwpError::InvalidFormat`] when the text [looks garbled](looks_like_mojibake)
+    pub reject_mojibake: bool,
+    /// Remove table of contents entries, see [`RagOptions::strip_toc`]
+    pub strip_toc: bool,
+}
+
+impl Default for RagOptions {
+    fn default() -> Self {
+        Self {
+            min_chars: 50,
+            on_short: ShortPolicy::Error,
+            ruby_handling: RubyHandling::Base,
+            include_tables: true,
+            reject_mojibake: false,
+            strip_toc: false,
+        }
+    }
+}
+
+impl RagOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the minimum number of characters
+    pub fn min_chars(mut self, min_chars: usize) -> Self {
+        self.min_chars = min_chars;
+        self
+    }
+
+    /// Set the handling of short text
+    pub fn on_short(mut self, policy: ShortPolicy) -> Self {
+        self.on_short = policy;
+        self
+    }
+
+    /// Set which text of ruby annotations is emitted
+    pub fn ruby_handling(mut self, handling: RubyHandling) -> Self {
+        self.ruby_handling = handling;
+        self
+    }
+
+    /// Set whether table cell text is extracted, `false` for prose only
+    pub fn include_tables(mut self, include: bool) -> Self {
+        self.include_tables = include;
+        self
+    }
+
+    /// Set whether garbled text is rejected
+    pub fn reject_mojibake(mut self, reject: bool) -> Self {
+        self.reject_mojibake = reject;
+        self
+    }
+
+    /// Set whether table of contents entries are removed
+    ///
+    /// Removed are a "목차" (or "차례") heading with the entries following it,
+    /// lines with a dot leader before a page number, and runs of three or more
+    /// short lines ending in a page number.
+    pub fn strip_toc(mut self, strip: bool) -> Self {
+        self.strip_toc = strip;
+        self
+    }
+}
+
 /// Extract text from HWP or HWPX file for RAG pipeline use.
 /// Detects format by file extension (.hwp or .hwpx).
 pub fn extract_text_for_rag(file_path: &str) -> Result<String> {
+    extract_text_for_rag_with(file_path, &RagOptions::default())
+}
+
+/// Extract text for RAG use with the given options.
+pub fn extract_text_for_rag_with(file_path: &str, options: &RagOptions) -> Result<String> {
     let path = Path::new(file_path);
 
     let extension = path
@@ -23,23 +118,280 @@ pub fn extract_text_for_rag(file_path: &str) -> Result<String> {
         }
     };
 
-    let text = doc.extract_text();
+    let extraction = ExtractionOptions::new()
+        .ruby(options.ruby_handling)
+        .include_tables(options.include_tables);
+    let mut text = doc.extract_text_with(&extraction);
+    if options.strip_toc {
+        text = strip_toc_lines(&text);
+    }
     let normalized = normalize_text(&text);
 
-    if normalized.chars().count() < 50 {
+    if options.reject_mojibake && looks_like_mojibake(&normalized) {
         return Err(HwpError::InvalidFormat(
-            "Extracted text too short (less than 50 characters)".to_string(),
+            "Extracted text looks garbled (decoding failure)".to_string(),
         ));
     }
 
+    if normalized.chars().count() < options.min_chars {
+        return match options.on_short {
+            ShortPolicy::Error => Err(HwpError::InvalidFormat(format!(
+                "Extracted text too short (less than {} characters)",
+                options.min_chars
+            ))),
+            ShortPolicy::ReturnAnyway => Ok(normalized),
+            ShortPolicy::Empty => Ok(String::new()),
+        };
+    }
+
     Ok(normalized)
 }
 
+/// Headings introducing a table of contents, compared with spaces removed
+const TOC_HEADINGS: [&str; 3] = ["목차", "차례", "contents"];
+
+/// Consecutive page-number-terminated lines needed to count as a TOC without a heading
+const TOC_MIN_RUN: usize = 3;
+
+/// Remove table of contents lines from extracted text
+fn strip_toc_lines(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut removed = vec![false; lines.len()];
+
+    let mut i = 0;
+    while i < lines.len() {
+        let heading: String = lines[i]
+            .split_whitespace()
+            .collect::<String>()
+            .to_lowercase();
+        if TOC_HEADINGS.contains(&heading.as_str()) {
+            removed[i] = true;
+            i += 1;
+            while i < lines.len() && (lines[i].trim().is_empty() || is_toc_entry(lines[i])) {
+                removed[i] = true;
+                i += 1;
+            }
+            continue;
+        }
+
+        let run = lines[i..]
+            .iter()
+            .take_while(|line| is_toc_entry(line))
+            .count();
+        if has_dot_leader(lines[i]) || run >= TOC_MIN_RUN {
+            removed[i..i + run.max(1)].fill(true);
+        }
+        i += run.max(1);
+    }
+
+    lines
+        .iter()
+        .zip(removed)
+        .filter(|(_, removed)| !removed)
+        .map(|(line, _)| *line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Split a line into its title and the page number ending it
+fn split_page_number(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+    let title = line.trim_end_matches(|ch: char| ch.is_ascii_digit());
+    let page = &line[title.len()..];
+    if page.is_empty() || page.len() > 4 || title.chars().count() > 80 {
+        return None;
+    }
+    Some((title, page))
+}
+
+/// Whether a line looks like a contents entry, a short title ending in a page number
+fn is_toc_entry(line: &str) -> bool {
+    split_page_number(line).is_some_and(|(title, _)| {
+        title.ends_with(|ch: char| ch.is_whitespace() || is_leader(ch))
+            && title.chars().any(char::is_alphanumeric)
+    })
+}
+
+/// Whether a contents entry reaches its page number through a dot leader
+fn has_dot_leader(line: &str) -> bool {
+    split_page_number(line).is_some_and(|(title, _)| {
+        let leader = title
+            .trim_end()
+            .chars()
+            .rev()
+            .take_while(|&ch| is_leader(ch) || ch == ' ')
+            .filter(|&ch| is_leader(ch))
+            .count();
+        leader >= 3 && title.chars().any(char::is_alphanumeric)
+    })
+}
+
+fn is_leader(ch: char) -> bool {
+    matches!(ch, '.' | '·' | '…' | '‥' | '-' | '_')
+}
+
+/// Share of suspicious characters above which text is considered garbled
+const MOJIBAKE_RATIO: f64 = 0.05;
+
+/// Whether text looks like the result of a decoding failure
+///
+/// Counts replacement characters (U+FFFD), C1 controls, private use and
+/// noncharacter codepoints, and runs of Latin-1 supplement characters such as
+/// `ÇÑ±Û` (Korean read as Latin-1). The text is flagged when more than 5% of
+/// its non-whitespace characters are suspicious.
+pub fn looks_like_mojibake(text: &str) -> bool {
+    fn is_latin1_supplement(ch: char) -> bool {
+        ('\u{80}'..='\u{FF}').contains(&ch)
+    }
+
+    let chars: Vec<char> = text.chars().filter(|ch| !ch.is_whitespace()).collect();
+    if chars.is_empty() {
+        return false;
+    }
+
+    let suspicious = chars
+        .iter()
+        .enumerate()
+        .filter(|&(i, &ch)| match ch {
+            '\u{FFFD}' | '\u{FFFE}' | '\u{FFFF}' => true,
+            '\u{80}'..='\u{9F}' | '\u{E000}'..='\u{F8FF}' => true,
+            _ if is_latin1_supplement(ch) => {
+                let prev = i.checked_sub(1).map(|j| chars[j]);
+                let next = chars.get(i + 1).copied();
+                prev.is_some_and(is_latin1_supplement) || next.is_some_and(is_latin1_supplement)
+            }
+            _ => false,
+        })
+        .count();
+
+    suspicious as f64 / chars.len() as f64 > MOJIBAKE_RATIO
+}
+
+/// Split the document text into chunks of at most `max_chars` characters,
+/// each prefixed with the path of headings it falls under.
+///
+/// Headings come from the document outline; a chunk never spans two headings.
+/// The path is joined with ` > ` and put on the chunk's first line, e.g.
+/// `제1장 > 1.1 범위`. Chunks before the first heading have no prefix, and the
+/// prefix does not count towards `max_chars`.
+pub fn chunk_text_with_headings(doc: &HwpDocument, max_chars: usize) -> Vec<String> {
+    let max_chars = max_chars.max(1);
+    let mut chunks = Vec::new();
+    let mut path: Vec<(u8, String)> = Vec::new();
+    let mut body = String::new();
+
+    let flush = |chunks: &mut Vec<String>, path: &[(u8, String)], body: &mut String| {
+        if body.is_empty() {
+            return;
+        }
+        let heading = path
+            .iter()
+            .map(|(_, text)| text.as_str())
+            .collect::<Vec<_>>()
+            .join(" > ");
+        if heading.is_empty() {
+            chunks.push(std::mem::take(body));
+        } else {
+            chunks.push(format!("{}\n{}", heading, body));
+            body.clear();
+        }
+    };
+
+    for paragraph in doc.paragraphs() {
+        let text = normalize_text(&paragraph.text());
+        if let Some(level) = doc.heading_level(paragraph) {
+            flush(&mut chunks, &path, &mut body);
+            path.retain(|(parent, _)| *parent < level);
+            if !text.is_empty() {
+                path.push((level, text));
+            }
+            continue;
+        }
+
+        for line in text.lines() {
+            let chars: Vec<char> = line.chars().collect();
+            for piece in chars.chunks(max_chars) {
+                let used = body.chars().count();
+                if used > 0 && used + 1 + piece.len() > max_chars {
+                    flush(&mut chunks, &path, &mut body);
+                }
+                if !body.is_empty() {
+                    body.push('\n');
+                }
+                body.extend(piece);
+            }
+        }
+    }
+    flush(&mut chunks, &path, &mut body);
+
+    chunks
+}
+
+/// Extract the text under the first heading containing `heading_pattern`
+///
+/// The text starts with the heading itself and runs until the next heading of
+/// the same or a higher level, so subsections are included. Headings come from
+/// the document outline. Returns `None` when no heading matches.
+pub fn extract_under_heading(doc: &HwpDocument, heading_pattern: &str) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut matched_level = None;
+
+    for paragraph in doc.paragraphs() {
+        let text = normalize_text(&paragraph.text());
+        match (doc.heading_level(paragraph), matched_level) {
+            (Some(level), Some(matched)) if level <= matched => break,
+            (Some(level), None) if text.contains(heading_pattern) => {
+                matched_level = Some(level);
+            }
+            (_, None) => continue,
+            _ => {}
+        }
+        if !text.is_empty() {
+            lines.push(text);
+        }
+    }
+
+    matched_level.map(|_| lines.join("\n"))
+}
+
+/// Options for [`normalize_text_with`]
+#[derive(Debug, Clone, Default)]
+pub struct NormalizeOptions {
+    /// Keep the original line structure: only trailing whitespace is trimmed
+    /// from each line, and blank lines are kept.
+    pub preserve_line_breaks: bool,
+    /// Convert full-width ASCII variants (U+FF01-U+FF5E, e.g. `１２３`) to
+    /// their half-width forms.
+    pub fold_fullwidth: bool,
+}
+
 /// Normalize text for RAG consumption.
 /// - Trim whitespace from each line
 /// - Remove empty lines (consecutive newlines -> single newline)
 /// - Trim overall result
 pub fn normalize_text(text: &str) -> String {
+    normalize_text_with(text, &NormalizeOptions::default())
+}
+
+/// Normalize text with the given options.
+pub fn normalize_text_with(text: &str, options: &NormalizeOptions) -> String {
+    if options.fold_fullwidth {
+        let folded: String = text.chars().map(fold_fullwidth_char).collect();
+        let options = NormalizeOptions {
+            fold_fullwidth: false,
+            ..options.clone()
+        };
+        return normalize_text_with(&folded, &options);
+    }
+
+    if options.preserve_line_breaks {
+        return text
+            .lines()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
     let lines: Vec<&str> = text
         .lines()
         .map(|line| line.trim())
@@ -48,3 +400,69 @@ pub fn normalize_text(text: &str) -> String {
 
     lines.join("\n").trim().to_string()
 }
+
+/// Map a full-width ASCII variant to its half-width character
+fn fold_fullwidth_char(ch: char) -> char {
+    match ch {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(ch as u32 - 0xFEE0).unwrap_or(ch),
+        _ => ch,
+    }
+}
+
+/// Remove repeated paragraphs (lines) from text, keeping the first occurrence.
+///
+/// Paragraphs are compared case-insensitively with whitespace collapsed. At a
+/// `similarity_threshold` of 1.0 only exact repeats are removed; below that, a
+/// paragraph is also dropped when the Jaccard similarity of its character
+/// bigrams with an earlier paragraph reaches the threshold. Blank lines are kept.
+pub fn deduplicate_paragraphs(text: &str, similarity_threshold: f64) -> String {
+    let fuzzy = similarity_threshold < 1.0;
+    let mut seen = HashSet::new();
+    let mut seen_shingles: Vec<HashSet<(char, char)>> = Vec::new();
+    let mut kept = Vec::new();
+
+    for line in text.lines() {
+        let key = line
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if key.is_empty() {
+            kept.push(line);
+            continue;
+        }
+        if !seen.insert(key.clone()) {
+            continue;
+        }
+
+        if fuzzy {
+            let shingles = bigrams(&key);
+            if seen_shingles
+                .iter()
+                .any(|other| jaccard(&shingles, other) >= similarity_threshold)
+            {
+                continue;
+            }
+            seen_shingles.push(shingles);
+        }
+        kept.push(line);
+    }
+
+    kept.join("\n")
+}
+
+fn bigrams(text: &str) -> HashSet<(char, char)> {
+    let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if chars.len() == 1 {
+        return HashSet::from([(chars[0], chars[0])]);
+    }
+    chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+fn jaccard(a: &HashSet<(char, char)>, b: &HashSet<(char, char)>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
@@ -1,6 +1,6 @@
 use crate::error::{HwpError, Result};
 use cfb::CompoundFile;
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
 use std::path::Path;
 
 pub struct CfbReader<F> {
@@ -9,20 +9,23 @@ pub struct CfbReader<F> {
 
 impl CfbReader<std::fs::File> {
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let file = std::fs::File::open(path)?;
-        let cfb = CompoundFile::open(file)
-            .map_err(|e| HwpError::Cfb(format!("Failed to open CFB: {e}")))?;
-        Ok(Self { cfb })
+        Self::new(std::fs::File::open(path)?)
     }
 }
 
 impl<F: Read + Seek> CfbReader<F> {
-    pub fn new(reader: F) -> Result<Self> {
+    pub fn new(mut reader: F) -> Result<Self> {
+        check_sector_shift(&mut reader)?;
         let cfb = CompoundFile::open(reader)
             .map_err(|e| HwpError::Cfb(format!("Failed to open CFB: {e}")))?;
         Ok(Self { cfb })
     }
 
+    /// Sector size of the compound file: 512 bytes (version 3) or 4096 bytes (version 4)
+    pub fn sector_size(&self) -> usize {
+        self.cfb.version().sector_len()
+    }
+
     pub fn read_stream(&mut self, path: &str) -> Result<Vec<u8>> {
         let mut stream = self
             .cfb
@@ -48,3 +51,26 @@ impl<F: Read + Seek> CfbReader<F> {
         streams
     }
 }
+
+/// Offset of the sector shift field in the compound file header
+const SECTOR_SHIFT_OFFSET: u64 = 30;
+
+/// Check the header's sector shift: 9 (512-byte sectors) or 12 (4096-byte sectors)
+fn check_sector_shift<F: Read + Seek>(reader: &mut F) -> Result<()> {
+    let start = reader.stream_position()?;
+    reader.seek(SeekFrom::Start(start + SECTOR_SHIFT_OFFSET))?;
+    let mut shift = [0u8; 2];
+    let read = reader.read_exact(&mut shift);
+    reader.seek(SeekFrom::Start(start))?;
+
+    // A truncated header is reported by the CFB parser itself
+    if read.is_err() {
+        return Ok(());
+    }
+    match u16::from_le_bytes(shift) {
+        9 | 12 => Ok(()),
+        shift => Err(HwpError::Cfb(format!(
+            "Unsupported CFB sector shift {shift} (expected 9 or 12)"
+        ))),
+    }
+}
@@ -0,0 +1,69 @@
+use super::renderer::escape_xml;
+use super::LineEnding;
+use crate::model::PageBackground;
+use crate::HwpDocument;
+
+/// Render the document body as a standalone HTML page
+///
+/// Paragraphs become `<p>` elements, line breaks inside a paragraph become
+/// `<br>`, and horizontal rules become `<hr>`. The first section's page
+/// background is applied to the body; a watermark image is referenced by its
+/// `BinData` stream name.
+pub fn to_html(document: &HwpDocument) -> String {
+    to_html_with(document, LineEnding::default())
+}
+
+/// Render the document body as HTML, ending lines with `line_ending`
+pub fn to_html_with(document: &HwpDocument, line_ending: LineEnding) -> String {
+    let mut lines = vec![
+        "<!DOCTYPE html>".to_string(),
+        "<html>".to_string(),
+        "<head>".to_string(),
+        "<meta charset=\"utf-8\">".to_string(),
+    ];
+    if let Some(title) = document.title() {
+        lines.push(format!("<title>{}</title>", escape_xml(title)));
+    }
+    lines.push("</head>".to_string());
+    match body_style(document) {
+        Some(style) => lines.push(format!("<body style=\"{}\">", escape_xml(&style))),
+        None => lines.push("<body>".to_string()),
+    }
+
+    for paragraph in document.paragraphs() {
+        if document.is_horizontal_line(paragraph) {
+            lines.push("<hr>".to_string());
+            continue;
+        }
+
+        let text = paragraph.text();
+        let text = text.trim_end_matches(['\r', '\n']);
+        if !text.trim().is_empty() {
+            let body: Vec<String> = text.lines().map(escape_xml).collect();
+            lines.push(format!("<p>{}</p>", body.join("<br>")));
+        }
+    }
+
+    lines.push("</body>".to_string());
+    lines.push("</html>".to_string());
+
+    let mut html = lines.join(line_ending.as_str());
+    html.push_str(line_ending.as_str());
+    html
+}
+
+fn body_style(document: &HwpDocument) -> Option<String> {
+    match document.page_settings().first()?.background? {
+        PageBackground::Color(color) => {
+            let rgb = color.to_rgb();
+            Some(format!("background-color: #{rgb:06x}"))
+        }
+        PageBackground::Image { bin_data_id } => {
+            let bin_data = document.get_bin_data(bin_data_id)?;
+            Some(format!(
+                "background-image: url('{}')",
+                bin_data.stream_name()
+            ))
+        }
+    }
+}
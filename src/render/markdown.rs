@@ -0,0 +1,36 @@
+use super::LineEnding;
+use crate::HwpDocument;
+
+/// Render the document body as Markdown
+///
+/// Paragraphs become blocks separated by a blank line, and horizontal rules
+/// become `---`.
+pub fn to_markdown(document: &HwpDocument) -> String {
+    to_markdown_with(document, LineEnding::default())
+}
+
+/// Render the document body as Markdown, ending lines with `line_ending`
+pub fn to_markdown_with(document: &HwpDocument, line_ending: LineEnding) -> String {
+    let mut blocks = Vec::new();
+
+    for paragraph in document.paragraphs() {
+        if document.is_horizontal_line(paragraph) {
+            blocks.push("---".to_string());
+            continue;
+        }
+
+        let text = paragraph.text();
+        if !text.trim().is_empty() {
+            blocks.push(text);
+        }
+    }
+
+    let mut markdown = blocks.join("\n\n");
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+    match line_ending {
+        LineEnding::Lf => markdown,
+        LineEnding::CrLf => markdown.replace("\r\n", "\n").replace('\n', "\r\n"),
+    }
+}
@@ -1,5 +1,28 @@
+pub mod html;
 pub mod layout;
+pub mod markdown;
 pub mod renderer;
 
+pub use html::{to_html, to_html_with};
 pub use layout::{LayoutEngine, LayoutResult, RenderedPage};
+pub use markdown::{to_markdown, to_markdown_with};
 pub use renderer::{HwpRenderer, RenderOptions};
+
+/// Line terminator used in rendered text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl LineEnding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
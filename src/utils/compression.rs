@@ -1,7 +1,10 @@
-use crate::error::Result;
+use crate::error::{HwpError, Result};
 use flate2::read::ZlibDecoder;
 use std::io::Read;
 
+/// First tag id of the HWP record tag range (`HWPTAG_BEGIN`)
+const FIRST_RECORD_TAG: u32 = 0x10;
+
 pub fn decompress_stream(data: &[u8]) -> Result<Vec<u8>> {
     if data.is_empty() {
         return Ok(Vec::new());
@@ -22,11 +25,47 @@ pub fn decompress_stream(data: &[u8]) -> Result<Vec<u8>> {
 
             match decoder.read_to_end(&mut decompressed) {
                 Ok(_) => Ok(decompressed),
-                Err(_) => {
-                    // If both fail, return data as-is (might not be compressed)
-                    Ok(data.to_vec())
-                }
+                // Some writers set the compression flag but store records raw
+                Err(_) if looks_like_records(data) => Ok(data.to_vec()),
+                Err(_) => Err(HwpError::CompressionError(
+                    "compressed stream is corrupt or not deflate-encoded".to_string(),
+                )),
             }
         }
     }
 }
+
+/// Whether `data` parses as a complete sequence of uncompressed record headers
+fn looks_like_records(data: &[u8]) -> bool {
+    let mut offset = 0;
+    while offset < data.len() {
+        let Some(header) = read_u32(data, offset) else {
+            return false;
+        };
+        let tag = header & 0x3FF;
+        let level = (header >> 10) & 0x3FF;
+        let mut size = (header >> 20) as usize;
+        offset += 4;
+
+        if tag < FIRST_RECORD_TAG || (offset == 4 && level != 0) {
+            return false;
+        }
+        if size == 0xFFF {
+            let Some(extended) = read_u32(data, offset) else {
+                return false;
+            };
+            size = extended as usize;
+            offset += 4;
+        }
+        offset = match offset.checked_add(size) {
+            Some(end) if end <= data.len() => end,
+            _ => return false,
+        };
+    }
+    true
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
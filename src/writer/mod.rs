@@ -12,7 +12,11 @@ use crate::model::{
     tab_def::TabDef,
     HwpDocument,
 };
-use crate::parser::{body_text::BodyText, doc_info::DocInfo, header::FileHeader};
+use crate::parser::{
+    body_text::{BodyText, SectionSource},
+    doc_info::DocInfo,
+    header::FileHeader,
+};
 use std::path::Path;
 
 pub struct HwpWriter {
@@ -65,6 +69,10 @@ impl HwpWriter {
                 preview_text: None,
                 preview_image: None,
                 summary_info: None,
+                scripts: Vec::new(),
+                history: Vec::new(),
+                xml_template: None,
+                undecrypted_streams: Vec::new(),
             },
             current_section_idx: 0,
             next_instance_id: 1,
@@ -100,6 +108,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: None,
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Get the current section and add paragraph
@@ -112,6 +125,31 @@ impl HwpWriter {
         Ok(())
     }
 
+    /// Add an empty paragraph, read back as a blank line
+    pub fn add_blank_line(&mut self) -> Result<()> {
+        self.add_paragraph("")
+    }
+
+    /// Add `text` followed by a line break, without ending the paragraph
+    ///
+    /// Consecutive calls continue the same paragraph; the next `add_paragraph`
+    /// starts a new one.
+    pub fn add_line(&mut self, text: &str) -> Result<()> {
+        let open = self
+            .current_section_mut()
+            .and_then(|section| section.paragraphs.last_mut())
+            .and_then(|para| para.text.as_mut())
+            .filter(|para_text| para_text.content.ends_with('\n'));
+        match open {
+            Some(para_text) => {
+                para_text.content.push_str(text);
+                para_text.content.push('\n');
+                Ok(())
+            }
+            None => self.add_paragraph(&format!("{text}\n")),
+        }
+    }
+
     /// Add a paragraph with custom text style
     pub fn add_paragraph_with_style(&mut self, text: &str, style: &style::TextStyle) -> Result<()> {
         use crate::model::para_char_shape::{CharPositionShape, ParaCharShape};
@@ -129,7 +167,7 @@ impl HwpWriter {
 
         // Create character shape from style
         let char_shape = style.to_char_shape(face_name_id);
-        let char_shape_id = self.add_char_shape(char_shape)?;
+        let char_shape_id = self.intern_char_shape(char_shape)?;
 
         // Create character shape information for the paragraph
         let char_shapes = ParaCharShape {
@@ -157,6 +195,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: None,
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Get the current section and add paragraph
@@ -169,6 +212,79 @@ impl HwpWriter {
         Ok(())
     }
 
+    /// Add a paragraph using the named style `style_name`
+    ///
+    /// The style is looked up by its Korean or English name; styles other than
+    /// the defaults must first be created with [`HwpWriter::add_named_style`].
+    pub fn add_paragraph_with_named_style(&mut self, text: &str, style_name: &str) -> Result<()> {
+        use crate::model::para_char_shape::{CharPositionShape, ParaCharShape};
+
+        let style_id = self
+            .find_style(style_name)
+            .ok_or_else(|| HwpError::InvalidInput(format!("Unknown style: {style_name}")))?;
+        let style = &self.document.doc_info.styles[style_id as usize];
+
+        let paragraph = Paragraph {
+            text: Some(ParaText {
+                content: text.to_string(),
+            }),
+            para_shape_id: style.para_shape_id,
+            style_id,
+            char_shape_count: 1,
+            char_shapes: Some(ParaCharShape {
+                char_positions: vec![CharPositionShape {
+                    position: 0,
+                    char_shape_id: style.char_shape_id,
+                }],
+            }),
+            instance_id: self.next_instance_id(),
+            ..Default::default()
+        };
+
+        if let Some(section) = self.current_section_mut() {
+            section.paragraphs.push(paragraph);
+        }
+
+        Ok(())
+    }
+
+    /// Add a named style with the shapes of the default style, returning its id
+    ///
+    /// If a style with that name already exists, its id is returned instead.
+    pub fn add_named_style(&mut self, name: &str) -> Result<u8> {
+        use crate::model::style::Style;
+
+        if let Some(id) = self.find_style(name) {
+            return Ok(id);
+        }
+
+        let styles = &mut self.document.doc_info.styles;
+        let id = u8::try_from(styles.len())
+            .map_err(|_| HwpError::InvalidInput("Too many styles".to_string()))?;
+        let base = styles.first().cloned().unwrap_or_else(Style::new_default);
+        styles.push(Style {
+            name: name.to_string(),
+            english_name: name.to_string(),
+            properties: 0,
+            next_style_id: id,
+            lang_id: base.lang_id,
+            para_shape_id: base.para_shape_id,
+            char_shape_id: base.char_shape_id,
+        });
+
+        Ok(id)
+    }
+
+    /// Find a style by its Korean or English name
+    fn find_style(&self, name: &str) -> Option<u8> {
+        self.document
+            .doc_info
+            .styles
+            .iter()
+            .position(|style| style.name == name || style.english_name == name)
+            .and_then(|id| u8::try_from(id).ok())
+    }
+
     /// Add a heading with specified level (1-6)
     pub fn add_heading(&mut self, text: &str, level: u8) -> Result<()> {
         use crate::model::para_char_shape::{CharPositionShape, ParaCharShape};
@@ -185,13 +301,14 @@ impl HwpWriter {
 
         // Create character shape from heading text style
         let char_shape = heading_style.text_style.to_char_shape(face_name_id);
-        let char_shape_id = self.add_char_shape(char_shape)?;
+        let char_shape_id = self.intern_char_shape(char_shape)?;
 
         // Create paragraph shape with heading-specific spacing
         let mut para_shape = ParaShape::new_default();
         para_shape.top_para_space = heading_style.spacing_before;
         para_shape.bottom_para_space = heading_style.spacing_after;
-        let para_shape_id = self.add_para_shape(para_shape)?;
+        para_shape.set_head(crate::model::ParaHeadType::Outline, level.clamp(1, 7) - 1);
+        let para_shape_id = self.intern_para_shape(para_shape)?;
 
         // Create paragraph text
         let para_text = ParaText {
@@ -224,6 +341,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: None,
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Add paragraph to current section
@@ -264,7 +386,7 @@ impl HwpWriter {
     }
 
     /// Add a simple list with specified type
-    pub fn add_list(&mut self, items: &[&str], list_type: style::ListType) -> Result<()> {
+    pub fn add_list_of_type(&mut self, items: &[&str], list_type: style::ListType) -> Result<()> {
         self.start_list(list_type)?;
         for item in items {
             self.add_list_item(item)?;
@@ -272,6 +394,61 @@ impl HwpWriter {
         self.end_list()
     }
 
+    /// Add a list backed by a DocInfo numbering (`ordered`) or bullet definition
+    ///
+    /// Unlike [`add_list_of_type`](Self::add_list_of_type), the markers are not
+    /// written into the text; Hangul draws them from the paragraph head at the
+    /// given `level` (0-6).
+    pub fn add_list(&mut self, items: &[&str], ordered: bool, level: u8) -> Result<()> {
+        use crate::model::numbering::{Bullet, Numbering};
+        use crate::model::para_shape::{ParaHeadType, ParaShape};
+
+        // Numbering and bullet references are 1-based
+        let (head_type, numbering_id) = if ordered {
+            let numbering = Numbering::new_for_all_levels(style::ListType::Numbered);
+            self.document.doc_info.numberings.push(numbering);
+            (
+                ParaHeadType::Numbering,
+                self.document.doc_info.numberings.len() as u16,
+            )
+        } else {
+            let mut bullet = Bullet::new_default();
+            bullet.bullet_char = style::ListType::Bullet.get_format(level);
+            self.document.doc_info.bullets.push(bullet);
+            (
+                ParaHeadType::Bullet,
+                self.document.doc_info.bullets.len() as u16,
+            )
+        };
+
+        let mut para_shape = ParaShape::new_default();
+        para_shape.set_head(head_type, level);
+        para_shape.numbering_id = numbering_id;
+        para_shape.left_margin = 1000 * (level.min(6) as i32 + 1);
+        let para_shape_id = self.intern_para_shape(para_shape)?;
+
+        for item in items {
+            let paragraph = Paragraph {
+                text: Some(ParaText {
+                    content: item.to_string(),
+                }),
+                para_shape_id,
+                char_shape_count: 1,
+                line_align_count: 1,
+                instance_id: self.next_instance_id(),
+                char_shapes: Some(
+                    crate::model::para_char_shape::ParaCharShape::new_single_shape(0),
+                ),
+                ..Default::default()
+            };
+            if let Some(section) = self.current_section_mut() {
+                section.paragraphs.push(paragraph);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Start a list with specified type
     pub fn start_list(&mut self, list_type: style::ListType) -> Result<()> {
         // For now, we'll implement lists as styled paragraphs with appropriate prefixes
@@ -297,7 +474,7 @@ impl HwpWriter {
             let mut para_shape = crate::model::para_shape::ParaShape::new_default();
             para_shape.left_margin = left_margin;
             para_shape.indent = 0; // No first line indent for list items
-            let para_shape_id = self.add_para_shape(para_shape)?;
+            let para_shape_id = self.intern_para_shape(para_shape)?;
 
             // Create the paragraph with proper para_shape_id
             use crate::model::para_char_shape::{CharPositionShape, ParaCharShape};
@@ -329,6 +506,11 @@ impl HwpWriter {
                 picture_data: None,
                 text_box_data: None,
                 hyperlinks: Vec::new(),
+                footnotes: Vec::new(),
+                shapes: Vec::new(),
+                fields: Vec::new(),
+                form_fields: Vec::new(),
+                hidden_comments: Vec::new(),
             };
 
             // Add paragraph to current section
@@ -439,6 +621,19 @@ impl HwpWriter {
         self.add_image_with_options(data, format, &options)
     }
 
+    /// Add an image of `width` x `height` mm with a caption stored in its picture control
+    pub fn add_image_with_caption(
+        &mut self,
+        data: &[u8],
+        format: style::ImageFormat,
+        width: u32,
+        height: u32,
+        caption: &str,
+    ) -> Result<()> {
+        let options = style::ImageOptions::new().width(width).height(height);
+        self.add_picture(data, format, &options, Some(caption))
+    }
+
     /// Add an image with custom options
     pub fn add_image_with_options(
         &mut self,
@@ -446,7 +641,26 @@ impl HwpWriter {
         format: style::ImageFormat,
         options: &style::ImageOptions,
     ) -> Result<()> {
-        use crate::model::bin_data::BinData;
+        self.add_picture(data, format, options, None)?;
+
+        // If caption is provided, add it as a separate paragraph
+        if let Some(caption) = &options.caption {
+            let caption_text = format!("그림: {}", caption);
+            self.add_paragraph(&caption_text)?;
+        }
+
+        Ok(())
+    }
+
+    /// Add the BinData entry and picture control paragraph of an image
+    fn add_picture(
+        &mut self,
+        data: &[u8],
+        format: style::ImageFormat,
+        options: &style::ImageOptions,
+        caption: Option<&str>,
+    ) -> Result<()> {
+        use crate::model::bin_data::{BinData, BinDataType};
         use crate::model::control::Picture;
         use crate::model::ctrl_header::{ControlType, CtrlHeader};
 
@@ -455,9 +669,9 @@ impl HwpWriter {
 
         // Create binary data entry
         let bin_data = BinData {
-            properties: 0,
-            abs_name: format!("image{}.{}", bin_id, format.extension()),
-            rel_name: format!("image_{}.{}", self.next_instance_id(), format.extension()),
+            properties: BinDataType::Embedding as u16,
+            abs_name: String::new(),
+            rel_name: String::new(),
             bin_id,
             extension: format.extension().to_string(),
             data: data.to_vec(),
@@ -488,6 +702,7 @@ impl HwpWriter {
             border_fill_id: 0,
             image_width: width as u32,
             image_height: height as u32,
+            caption: caption.map(str::to_string),
         };
 
         // Create control header
@@ -516,6 +731,11 @@ impl HwpWriter {
             picture_data: Some(picture),
             text_box_data: None,
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Add the picture control paragraph to the document
@@ -525,12 +745,6 @@ impl HwpWriter {
             }
         }
 
-        // If caption is provided, add it as a separate paragraph
-        if let Some(caption) = &options.caption {
-            let caption_text = format!("그림: {}", caption);
-            self.add_paragraph(&caption_text)?;
-        }
-
         Ok(())
     }
 
@@ -618,7 +832,7 @@ impl HwpWriter {
 
         // Get or create font for the hyperlink style
         let char_shape = hyperlink_style.to_char_shape(0); // Use default font
-        let char_shape_id = self.add_char_shape(char_shape)?;
+        let char_shape_id = self.intern_char_shape(char_shape)?;
 
         // Create character shape information
         let char_shapes = ParaCharShape {
@@ -646,6 +860,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: None,
             hyperlinks: vec![hyperlink],
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Add the paragraph to the document
@@ -723,7 +942,7 @@ impl HwpWriter {
 
         // Create character shape
         let char_shape = style::TextStyle::new().to_char_shape(0);
-        let char_shape_id = self.add_char_shape(char_shape)?;
+        let char_shape_id = self.intern_char_shape(char_shape)?;
 
         // Create character shape information
         let char_shapes = ParaCharShape {
@@ -752,6 +971,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: None,
             hyperlinks,
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Add the paragraph to the document
@@ -838,9 +1062,8 @@ impl HwpWriter {
 
         // Create para shape with alignment
         let mut para_shape = ParaShape::new_default();
-        // Set alignment in properties1 (bits 2-4)
-        para_shape.properties1 = (para_shape.properties1 & !0x1C) | ((alignment as u32) << 2);
-        let para_shape_id = self.add_para_shape(para_shape)?;
+        para_shape.set_alignment(alignment as u8);
+        let para_shape_id = self.intern_para_shape(para_shape)?;
 
         let para_text = ParaText {
             content: text.to_string(),
@@ -848,7 +1071,7 @@ impl HwpWriter {
 
         // Create character shape
         let char_shape = style::TextStyle::new().to_char_shape(0);
-        let char_shape_id = self.add_char_shape(char_shape)?;
+        let char_shape_id = self.intern_char_shape(char_shape)?;
 
         // Create character shape information
         let char_shapes = ParaCharShape {
@@ -877,6 +1100,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: None,
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Add the paragraph to the document
@@ -889,6 +1117,170 @@ impl HwpWriter {
         Ok(())
     }
 
+    /// Add a paragraph with paragraph-level formatting such as shading
+    pub fn add_paragraph_with_paragraph_style(
+        &mut self,
+        text: &str,
+        paragraph_style: &style::ParagraphStyle,
+    ) -> Result<()> {
+        use crate::model::para_char_shape::ParaCharShape;
+
+        let border_fill_id = match paragraph_style.to_border_fill() {
+            Some(border_fill) => self.add_border_fill(border_fill),
+            None => 0,
+        };
+        let para_shape_id =
+            self.intern_para_shape(paragraph_style.to_para_shape(border_fill_id))?;
+
+        let char_shape_id = self.intern_char_shape(style::TextStyle::new().to_char_shape(0))?;
+
+        let paragraph = Paragraph {
+            text: Some(ParaText {
+                content: text.to_string(),
+            }),
+            para_shape_id,
+            char_shape_count: 1,
+            line_align_count: 1,
+            char_shapes: Some(ParaCharShape::new_single_shape(char_shape_id)),
+            instance_id: self.next_instance_id(),
+            ..Default::default()
+        };
+
+        if let Some(section) = self.current_section_mut() {
+            section.paragraphs.push(paragraph);
+        }
+
+        Ok(())
+    }
+
+    /// Add a horizontal rule: an empty paragraph with a bottom border, as Hancom
+    /// inserts for a typed `---` line
+    pub fn add_horizontal_line(&mut self) -> Result<()> {
+        use crate::model::border_fill::{BorderFill, BorderLine};
+        use crate::model::para_shape::ParaShape;
+
+        let mut border_fill = BorderFill::new_default();
+        border_fill.bottom = BorderLine {
+            line_type: 1, // Solid line
+            thickness: 1, // 0.12mm
+            color: 0x000000,
+        };
+        let mut para_shape = ParaShape::new_default();
+        para_shape.border_fill_id = self.add_border_fill(border_fill);
+        let para_shape_id = self.intern_para_shape(para_shape)?;
+
+        let paragraph = Paragraph {
+            text: Some(ParaText {
+                content: String::new(),
+            }),
+            para_shape_id,
+            char_shape_count: 1,
+            line_align_count: 1,
+            char_shapes: Some(crate::model::para_char_shape::ParaCharShape::new_single_shape(0)),
+            instance_id: self.next_instance_id(),
+            ..Default::default()
+        };
+
+        if let Some(section) = self.current_section_mut() {
+            section.paragraphs.push(paragraph);
+        }
+
+        Ok(())
+    }
+
+    /// Add a paragraph holding a checkbox followed by `label`
+    pub fn add_checkbox(&mut self, checked: bool, label: &str) -> Result<()> {
+        let name = format!("CheckBox{}", self.document.form_fields().len() + 1);
+        let paragraph = Paragraph {
+            text: Some(ParaText {
+                content: label.to_string(),
+            }),
+            char_shape_count: 1,
+            line_align_count: 1,
+            char_shapes: Some(crate::model::para_char_shape::ParaCharShape::new_single_shape(0)),
+            instance_id: self.next_instance_id(),
+            form_fields: vec![crate::model::FormField::checkbox(&name, label, checked)],
+            ..Default::default()
+        };
+
+        if let Some(section) = self.current_section_mut() {
+            section.paragraphs.push(paragraph);
+        }
+
+        Ok(())
+    }
+
+    /// Add a paragraph holding a page number field, shown as 1 until the viewer updates it
+    pub fn add_page_number_field(&mut self) -> Result<()> {
+        self.add_field_paragraph(crate::model::Field::page_number(), "")
+    }
+
+    /// Add a paragraph holding a date field
+    ///
+    /// `format` uses strftime syntax and is stored as the field command; today's
+    /// date rendered with it is cached as the initial value.
+    pub fn add_date_field(&mut self, format: &str) -> Result<()> {
+        use std::fmt::Write as _;
+
+        let mut today = String::new();
+        write!(today, "{}", chrono::Local::now().format(format))
+            .map_err(|_| HwpError::InvalidInput(format!("Invalid date format: {format}")))?;
+        self.add_field_paragraph(crate::model::Field::date(format, &today), "")
+    }
+
+    /// Add a paragraph holding a bookmark named `name`
+    ///
+    /// Names must be unique within the document.
+    pub fn add_bookmark(&mut self, name: &str) -> Result<()> {
+        if name.is_empty() {
+            return Err(HwpError::InvalidInput(
+                "Bookmark name must not be empty".to_string(),
+            ));
+        }
+        if self.document.bookmarks().contains(&name) {
+            return Err(HwpError::InvalidInput(format!(
+                "Bookmark already exists: {name}"
+            )));
+        }
+        self.add_field_paragraph(crate::model::Field::bookmark(name), "")
+    }
+
+    /// Add a caption paragraph such as "그림 1 text", numbered by an automatic number
+    ///
+    /// The number follows the captions of the same kind already in the document;
+    /// the viewer renumbers them when captions are moved.
+    pub fn add_caption(&mut self, kind: crate::model::CaptionKind, text: &str) -> Result<()> {
+        let count = self
+            .document
+            .fields()
+            .iter()
+            .filter(|field| field.kind == crate::model::FieldKind::CaptionNumber(kind))
+            .count();
+        let number = u16::try_from(count + 1)
+            .map_err(|_| HwpError::InvalidInput(format!("Too many {} captions", kind.label())))?;
+        self.add_field_paragraph(crate::model::Field::caption_number(kind, number), text)
+    }
+
+    fn add_field_paragraph(&mut self, field: crate::model::Field, text: &str) -> Result<()> {
+        let paragraph = Paragraph {
+            text: Some(ParaText {
+                content: text.to_string(),
+            }),
+            char_shape_count: 1,
+            line_align_count: 1,
+            char_shapes: Some(crate::model::para_char_shape::ParaCharShape::new_single_shape(0)),
+            instance_id: self.next_instance_id(),
+            fields: vec![field],
+            ..Default::default()
+        };
+
+        if let Some(section) = self.current_section_mut() {
+            section.paragraphs.push(paragraph);
+        }
+
+        Ok(())
+    }
+
     /// Add a paragraph with custom spacing
     pub fn add_paragraph_with_spacing(
         &mut self,
@@ -905,7 +1297,7 @@ impl HwpWriter {
         para_shape.line_space = (line_spacing_percent * 100) as i32; // Convert percent to internal units
         para_shape.top_para_space = (before_spacing_mm * 283.465) as i32; // Convert mm to HWP units
         para_shape.bottom_para_space = (after_spacing_mm * 283.465) as i32;
-        let para_shape_id = self.add_para_shape(para_shape)?;
+        let para_shape_id = self.intern_para_shape(para_shape)?;
 
         let para_text = ParaText {
             content: text.to_string(),
@@ -913,7 +1305,7 @@ impl HwpWriter {
 
         // Create character shape
         let char_shape = style::TextStyle::new().to_char_shape(0);
-        let char_shape_id = self.add_char_shape(char_shape)?;
+        let char_shape_id = self.intern_char_shape(char_shape)?;
 
         // Create character shape information
         let char_shapes = ParaCharShape {
@@ -942,6 +1334,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: None,
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Add the paragraph to the document
@@ -984,6 +1381,21 @@ impl HwpWriter {
         self.set_page_layout(layout)
     }
 
+    /// Set the paper size of the current section in millimeters
+    ///
+    /// `width_mm` and `height_mm` are the portrait paper dimensions; with
+    /// `landscape` the page is turned, so `width_mm` becomes the height.
+    pub fn set_page(&mut self, width_mm: f32, height_mm: f32, landscape: bool) -> Result<()> {
+        use crate::model::page_layout::PageOrientation;
+
+        let orientation = if landscape {
+            PageOrientation::Landscape
+        } else {
+            PageOrientation::Portrait
+        };
+        self.set_custom_page_size(width_mm, height_mm, orientation)
+    }
+
     /// Set page margins in millimeters
     pub fn set_page_margins_mm(&mut self, left: f32, right: f32, top: f32, bottom: f32) {
         use crate::model::page_layout::mm_to_hwp_units;
@@ -1008,11 +1420,17 @@ impl HwpWriter {
         self.page_layout.margins = crate::model::page_layout::PageMargins::wide();
     }
 
-    /// Set multiple columns
+    /// Set multiple columns, written as the column definition of the current section
     pub fn set_columns(&mut self, columns: u16, spacing_mm: f32) {
         use crate::model::page_layout::mm_to_hwp_units;
         self.page_layout.columns = columns;
         self.page_layout.column_spacing = mm_to_hwp_units(spacing_mm);
+
+        let count = columns.clamp(1, u8::MAX as u16) as u8;
+        let gap = self.page_layout.column_spacing.min(u16::MAX as u32) as u16;
+        if let Some(section) = self.current_section_mut() {
+            section.column_def = Some(crate::model::ColumnDef::new(count, gap));
+        }
     }
 
     /// Set page background color
@@ -1031,6 +1449,136 @@ impl HwpWriter {
         self.set_page_layout(layout)
     }
 
+    /// Set a manuscript (원고지) style grid of `rows` lines by `cols` characters
+    /// for the current section
+    pub fn set_grid(&mut self, rows: u32, cols: u32) -> Result<()> {
+        use crate::model::{page_def::PageDef, section_def::SectionDef, PageSetup};
+
+        if rows == 0 || cols == 0 {
+            return Err(HwpError::InvalidInput(format!(
+                "Grid must have at least one row and column, got {rows}x{cols}"
+            )));
+        }
+
+        let layout = self.page_layout.clone();
+        let section = self
+            .current_section_mut()
+            .ok_or_else(|| HwpError::InvalidInput("No current section".to_string()))?;
+
+        let page_def = section
+            .page_def
+            .get_or_insert_with(|| PageDef::from_layout(layout));
+        let setup = PageSetup::from_defs(Some(page_def), None);
+        let line_grid = (setup.body_height() / rows).min(u16::MAX as u32) as u16;
+        let char_grid = (setup.body_width() / cols).min(u16::MAX as u32) as u16;
+
+        let section_def = section
+            .section_def
+            .get_or_insert_with(SectionDef::new_default);
+        section_def.vertical_line_align = line_grid.max(1);
+        section_def.horizontal_line_align = char_grid.max(1);
+
+        Ok(())
+    }
+
+    /// Snap the lines of the current section to a baseline grid `spacing_mm` apart,
+    /// or turn the grid off
+    pub fn set_line_grid(&mut self, enabled: bool, spacing_mm: f32) -> Result<()> {
+        use crate::model::section_def::SectionDef;
+
+        let spacing = if enabled {
+            let units = crate::model::page_layout::mm_to_hwp_units(spacing_mm);
+            if !spacing_mm.is_finite() || spacing_mm <= 0.0 || units > u16::MAX as u32 {
+                return Err(HwpError::InvalidInput(format!(
+                    "Line grid spacing out of range, got {spacing_mm}mm"
+                )));
+            }
+            (units as u16).max(1)
+        } else {
+            0
+        };
+
+        let section = self
+            .current_section_mut()
+            .ok_or_else(|| HwpError::InvalidInput("No current section".to_string()))?;
+        section
+            .section_def
+            .get_or_insert_with(SectionDef::new_default)
+            .vertical_line_align = spacing;
+
+        Ok(())
+    }
+
+    /// Set the default tab stop interval, in millimeters, for the current section
+    pub fn set_default_tab_width(&mut self, mm: f32) -> Result<()> {
+        use crate::model::section_def::SectionDef;
+
+        if !mm.is_finite() || mm <= 0.0 {
+            return Err(HwpError::InvalidInput(format!(
+                "Tab width must be positive, got {mm}mm"
+            )));
+        }
+
+        let section = self
+            .current_section_mut()
+            .ok_or_else(|| HwpError::InvalidInput("No current section".to_string()))?;
+        section
+            .section_def
+            .get_or_insert_with(SectionDef::new_default)
+            .default_tab_stop = crate::model::page_layout::mm_to_hwp_units(mm);
+
+        Ok(())
+    }
+
+    /// Number every `interval`-th line of the current section in the margin,
+    /// counting from `start`
+    pub fn set_line_numbering(&mut self, start: u16, interval: u16) -> Result<()> {
+        use crate::model::section_def::{LineNumbering, SectionDef};
+
+        if interval == 0 {
+            return Err(HwpError::InvalidInput(
+                "Line numbering interval must be at least 1".to_string(),
+            ));
+        }
+
+        let section = self
+            .current_section_mut()
+            .ok_or_else(|| HwpError::InvalidInput("No current section".to_string()))?;
+        section
+            .section_def
+            .get_or_insert_with(SectionDef::new_default)
+            .line_numbering = Some(LineNumbering {
+            start,
+            interval,
+            distance: crate::model::page_layout::mm_to_hwp_units(5.0) as u16,
+            restart_type: 0,
+        });
+
+        Ok(())
+    }
+
+    /// Append already encoded section records as the next section stream
+    ///
+    /// The stream is `BodyText/Section<n>`. `records` are the uncompressed
+    /// records of the section and are written verbatim, compressed like the
+    /// other sections. They must parse as a section; content added afterwards
+    /// still goes to the current section.
+    pub fn add_raw_section(&mut self, records: &[u8]) -> Result<()> {
+        let mut body_text =
+            crate::parser::body_text::BodyTextParser::parse(records.to_vec(), false)?;
+        body_text.raw_records = Some(records.to_vec());
+        self.document.body_texts.push(body_text);
+        Ok(())
+    }
+
+    /// Get the current section for modification
+    fn current_section_mut(&mut self) -> Option<&mut Section> {
+        self.document
+            .body_texts
+            .get_mut(self.current_section_idx)
+            .and_then(|body_text| body_text.sections.get_mut(0))
+    }
+
     /// Convert the document to bytes
     pub fn to_bytes(&self) -> Result<Vec<u8>> {
         serializer::serialize_document(&self.document)
@@ -1065,6 +1613,8 @@ impl HwpWriter {
             numberings: Vec::new(),
             bullets: Vec::new(),
             bin_data: Vec::new(),
+            raw_char_shapes: Vec::new(),
+            raw_para_shapes: Vec::new(),
         }
     }
 
@@ -1074,10 +1624,16 @@ impl HwpWriter {
             paragraphs: Vec::new(),
             section_def: None,
             page_def: None,
+            column_def: None,
+            page_border_fills: Vec::new(),
+            unhandled_controls: 0,
         };
 
         BodyText {
             sections: vec![section],
+            source: SectionSource::BodyText,
+            raw_xml: None,
+            raw_records: None,
         }
     }
 
@@ -1103,6 +1659,50 @@ impl HwpWriter {
         Ok((self.document.doc_info.face_names.len() - 1) as u16)
     }
 
+    /// Embed a TrueType/OpenType font for the face `name`, registering the face if needed
+    ///
+    /// The font file is stored as an embedded BinData entry, which the FaceName
+    /// record is marked embedded with and references; embedding the same face
+    /// again replaces the data. Fonts are stored whole: they are not subset to
+    /// the glyphs the document uses.
+    pub fn embed_font(&mut self, name: &str, font_bytes: &[u8]) -> Result<()> {
+        use crate::model::bin_data::{BinData, BinDataType};
+
+        let extension = match font_bytes.get(..4) {
+            Some([0x00, 0x01, 0x00, 0x00]) | Some(b"true") => "ttf",
+            Some(b"OTTO") => "otf",
+            Some(b"ttcf") => "ttc",
+            _ => {
+                return Err(HwpError::InvalidInput(format!(
+                    "Font data for '{name}' is not a TrueType or OpenType font"
+                )))
+            }
+        };
+        let face_id = self.ensure_font(name)?;
+
+        let doc_info = &mut self.document.doc_info;
+        let existing = doc_info.face_names[face_id as usize]
+            .embedded_bin_id
+            .and_then(|bin_id| doc_info.bin_data.iter_mut().find(|bd| bd.bin_id == bin_id));
+        if let Some(existing) = existing {
+            existing.extension = extension.to_string();
+            existing.data = font_bytes.to_vec();
+            return Ok(());
+        }
+
+        let bin_id = (doc_info.bin_data.len() + 1) as u16;
+        doc_info.bin_data.push(BinData {
+            properties: BinDataType::Embedding as u16,
+            abs_name: String::new(),
+            rel_name: String::new(),
+            bin_id,
+            extension: extension.to_string(),
+            data: font_bytes.to_vec(),
+        });
+        doc_info.face_names[face_id as usize].set_embedded(bin_id);
+        Ok(())
+    }
+
     /// Add a character shape to the document and return its ID
     pub fn add_char_shape(&mut self, char_shape: CharShape) -> Result<u16> {
         self.document.doc_info.char_shapes.push(char_shape);
@@ -1117,6 +1717,39 @@ impl HwpWriter {
         self.document.doc_info.para_shapes.push(para_shape);
         Ok((self.document.doc_info.para_shapes.len() - 1) as u16)
     }
+
+    /// Return the ID of an identical character shape, adding it if there is none
+    pub(crate) fn intern_char_shape(&mut self, char_shape: CharShape) -> Result<u16> {
+        let shapes = &self.document.doc_info.char_shapes;
+        match shapes.iter().position(|shape| *shape == char_shape) {
+            Some(id) => Ok(id as u16),
+            None => self.add_char_shape(char_shape),
+        }
+    }
+
+    /// Add a border fill to DocInfo and return its [`BorderFill::from_reference`] ID
+    ///
+    /// [`BorderFill::from_reference`]: crate::model::border_fill::BorderFill::from_reference
+    pub(crate) fn add_border_fill(
+        &mut self,
+        border_fill: crate::model::border_fill::BorderFill,
+    ) -> u16 {
+        let border_fills = &mut self.document.doc_info.border_fills;
+        border_fills.push(border_fill);
+        border_fills.len() as u16
+    }
+
+    /// Return the ID of an identical paragraph shape, adding it if there is none
+    pub(crate) fn intern_para_shape(
+        &mut self,
+        para_shape: crate::model::para_shape::ParaShape,
+    ) -> Result<u16> {
+        let shapes = &self.document.doc_info.para_shapes;
+        match shapes.iter().position(|shape| *shape == para_shape) {
+            Some(id) => Ok(id as u16),
+            None => self.add_para_shape(para_shape),
+        }
+    }
 }
 
 impl HwpWriter {
@@ -1215,16 +1848,17 @@ impl HwpWriter {
         if style.italic {
             properties |= 0x2; // Bit 1 for italic
         }
+        char_shape.properties = properties;
         if style.underline {
-            properties |= 0x1 << 2; // Bits 2-4 for underline (type 1)
+            char_shape.set_underline(style.underline_style.unwrap_or_default());
         }
         if style.strikethrough {
-            properties |= 0x1 << 5; // Bits 5-7 for strikethrough (type 1)
+            char_shape.set_strikethrough();
         }
-        char_shape.properties = properties;
 
         // Set colors
         char_shape.text_color = style.color;
+        char_shape.underline_color = style.underline_color.unwrap_or(style.color);
         if let Some(bg_color) = style.background_color {
             char_shape.shade_color = bg_color;
         }
@@ -1251,7 +1885,7 @@ impl HwpWriter {
 
         for range in ranges {
             let char_shape = self.text_style_to_char_shape(&range.style);
-            let char_shape_id = self.add_char_shape(char_shape)?;
+            let char_shape_id = self.intern_char_shape(char_shape)?;
 
             char_positions.push(CharPositionShape {
                 position: range.start as u32,
@@ -1300,7 +1934,7 @@ impl HwpWriter {
         for (start, _end) in bold_ranges {
             let mut char_shape = CharShape::new_default();
             char_shape.properties = 0x1; // Bold
-            let char_shape_id = self.add_char_shape(char_shape)?;
+            let char_shape_id = self.intern_char_shape(char_shape)?;
 
             char_positions.push(CharPositionShape {
                 position: start as u32,
@@ -1344,7 +1978,7 @@ impl HwpWriter {
         for (start, _end, color) in color_ranges {
             let mut char_shape = CharShape::new_default();
             char_shape.text_color = color;
-            let char_shape_id = self.add_char_shape(char_shape)?;
+            let char_shape_id = self.intern_char_shape(char_shape)?;
 
             char_positions.push(CharPositionShape {
                 position: start as u32,
@@ -1388,7 +2022,7 @@ impl HwpWriter {
         for (start, _end, color) in highlight_ranges {
             let mut char_shape = CharShape::new_default();
             char_shape.shade_color = color; // Use shade_color for background/highlight
-            let char_shape_id = self.add_char_shape(char_shape)?;
+            let char_shape_id = self.intern_char_shape(char_shape)?;
 
             char_positions.push(CharPositionShape {
                 position: start as u32,
@@ -1431,7 +2065,7 @@ impl HwpWriter {
 
         for (start, _end, text_style) in style_ranges {
             let char_shape = self.text_style_to_char_shape(&text_style);
-            let char_shape_id = self.add_char_shape(char_shape)?;
+            let char_shape_id = self.intern_char_shape(char_shape)?;
 
             char_positions.push(CharPositionShape {
                 position: start as u32,
@@ -1464,18 +2098,29 @@ impl HwpWriter {
 
 // Text Box Methods
 impl HwpWriter {
-    /// Add a simple text box
-    pub fn add_text_box(&mut self, text: &str) -> Result<()> {
+    /// Add a text box of `text` at (`x_mm`, `y_mm`) from the page corner, sized
+    /// `width_mm` by `height_mm`
+    ///
+    /// The box is written as a rectangle drawing object whose paragraph list
+    /// holds the text, one paragraph per line.
+    pub fn add_text_box(
+        &mut self,
+        text: &str,
+        x_mm: f32,
+        y_mm: f32,
+        width_mm: f32,
+        height_mm: f32,
+    ) -> Result<()> {
         use crate::model::ctrl_header::CtrlHeader;
-        use crate::model::paragraph::ParaText;
+        use crate::model::page_layout::mm_to_hwp_units;
         use crate::model::text_box::TextBox;
 
-        let text_box = TextBox::new(text);
-
-        let ctrl_header = CtrlHeader {
-            ctrl_id: 0x7874, // TextBox control ID
-            properties: 0,
-            instance_id: 0,
+        let text_box = TextBox {
+            x: mm_to_hwp_units(x_mm) as i32,
+            y: mm_to_hwp_units(y_mm) as i32,
+            width: mm_to_hwp_units(width_mm),
+            height: mm_to_hwp_units(height_mm),
+            ..TextBox::new(text)
         };
 
         let paragraph = Paragraph {
@@ -1483,27 +2128,18 @@ impl HwpWriter {
                 content: String::new(),
             }),
             control_mask: 0x02, // Control header present
-            para_shape_id: 0,
-            style_id: 0,
-            column_type: 0,
-            char_shape_count: 0,
-            range_tag_count: 0,
-            line_align_count: 0,
-            instance_id: 0,
-            char_shapes: None,
-            line_segments: None,
-            list_header: None,
-            ctrl_header: Some(ctrl_header),
-            table_data: None,
-            picture_data: None,
+            instance_id: self.next_instance_id(),
+            ctrl_header: Some(CtrlHeader {
+                ctrl_id: 0x7874, // TextBox control ID
+                properties: 0,
+                instance_id: 0,
+            }),
             text_box_data: Some(text_box),
-            hyperlinks: Vec::new(),
+            ..Default::default()
         };
 
-        if let Some(body_text) = self.document.body_texts.get_mut(self.current_section_idx) {
-            if let Some(section) = body_text.sections.get_mut(0) {
-                section.paragraphs.push(paragraph);
-            }
+        if let Some(section) = self.current_section_mut() {
+            section.paragraphs.push(paragraph);
         }
 
         Ok(())
@@ -1552,6 +2188,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: Some(text_box),
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         if let Some(body_text) = self.document.body_texts.get_mut(self.current_section_idx) {
@@ -1610,6 +2251,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: Some(text_box),
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         if let Some(body_text) = self.document.body_texts.get_mut(self.current_section_idx) {
@@ -1663,6 +2309,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: Some(text_box),
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         if let Some(body_text) = self.document.body_texts.get_mut(self.current_section_idx) {
@@ -1722,6 +2373,11 @@ impl HwpWriter {
             picture_data: None,
             text_box_data: Some(text_box),
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         if let Some(body_text) = self.document.body_texts.get_mut(self.current_section_idx) {
@@ -1851,6 +2507,25 @@ impl HwpWriter {
         self
     }
 
+    /// Make the document open read-only
+    ///
+    /// Sets the read-only flag of the document properties.
+    pub fn set_read_only(&mut self, read_only: bool) -> &mut Self {
+        self.document
+            .doc_info
+            .properties
+            .get_or_insert_with(crate::model::document::DocumentProperties::new)
+            .set_read_only(read_only);
+        self
+    }
+
+    /// Set the metadata written to the `\x05HwpSummaryInformation` stream,
+    /// such as title, author and creation date
+    pub fn set_metadata(&mut self, metadata: &crate::preview::DocumentMetadata) -> &mut Self {
+        self.document.summary_info = Some(metadata.into());
+        self
+    }
+
     /// Set document keywords
     pub fn set_document_keywords(&mut self, keywords: &str) -> &mut Self {
         if let Some(props) = self.document.doc_info.properties.as_mut() {
@@ -1867,6 +2542,18 @@ impl HwpWriter {
         self
     }
 
+    /// Set the document language from a locale tag such as `"ko-KR"`
+    ///
+    /// The language is stored on every style, where Hangul reads it for spell checking.
+    pub fn set_language(&mut self, lang: &str) -> Result<()> {
+        let lang_id = crate::model::style::lang_id_for(lang)
+            .ok_or_else(|| HwpError::InvalidInput(format!("Unsupported language: {lang}")))?;
+        for style in &mut self.document.doc_info.styles {
+            style.lang_id = lang_id;
+        }
+        Ok(())
+    }
+
     /// Update document statistics (character count, word count, etc.)
     pub fn update_document_statistics(&mut self) {
         // Extract text first (immutable borrow)
@@ -1,4 +1,10 @@
 use crate::error::Result;
+use crate::model::control::{CTRL_TABLE, TAG_TABLE};
+use crate::model::field::{AUTO_NUMBER_CTRL, FIELD_BEGIN_CTRL, FIELD_END_CTRL};
+use crate::model::form::{CTRL_FORM, TAG_FORM_OBJECT};
+use crate::model::page_border_fill::{PageBorderFill, TAG_PAGE_BORDER_FILL};
+use crate::model::paragraph::ctrl_for_space;
+use crate::model::ruby::{Ruby, TextPiece, CTRL_RUBY, RUBY_CTRL};
 use crate::model::HwpDocument;
 use crate::utils::encoding::string_to_utf16le;
 use byteorder::{LittleEndian, WriteBytesExt};
@@ -47,7 +53,10 @@ pub fn serialize_document(document: &HwpDocument) -> Result<Vec<u8>> {
 
     // Serialize BodyText sections
     for (i, body_text) in document.body_texts.iter().enumerate() {
-        let section_data = serialize_body_text(body_text)?;
+        let section_data = match &body_text.raw_records {
+            Some(records) => records.clone(),
+            None => serialize_body_text(body_text)?,
+        };
         let final_section = if document.header.is_compressed() {
             compress_data(&section_data)?
         } else {
@@ -63,12 +72,18 @@ pub fn serialize_document(document: &HwpDocument) -> Result<Vec<u8>> {
     }
 
     // Create BinData storage and streams if there are images
-    if !document.doc_info.bin_data.is_empty() {
+    if document.doc_info.bin_data.iter().any(|bd| bd.is_stored()) {
         cfb.create_storage("/BinData")
             .map_err(|e| crate::error::HwpError::Io(std::io::Error::other(e)))?;
 
-        for bin_data in &document.doc_info.bin_data {
-            let stream_name = format!("/BinData/BIN{:04X}.{}", bin_data.bin_id, bin_data.extension);
+        // Linked entries point at files outside the document
+        for bin_data in document
+            .doc_info
+            .bin_data
+            .iter()
+            .filter(|bd| bd.is_stored())
+        {
+            let stream_name = format!("/BinData/{}", bin_data.stream_name());
 
             // Compress binary data if document uses compression
             let final_data = if document.header.is_compressed() {
@@ -100,6 +115,15 @@ pub fn serialize_document(document: &HwpDocument) -> Result<Vec<u8>> {
     prv_image_stream.write_all(&[])?;
     drop(prv_image_stream);
 
+    // Create the summary information property set when metadata was set
+    if let Some(summary_info) = &document.summary_info {
+        let mut summary_stream = cfb
+            .create_stream("/\x05HwpSummaryInformation")
+            .map_err(|e| crate::error::HwpError::Io(std::io::Error::other(e)))?;
+        summary_stream.write_all(&summary_info.to_bytes())?;
+        drop(summary_stream);
+    }
+
     // Create DocOptions/_LinkDoc stream
     let doc_options = create_doc_options()?;
     let mut options_stream = cfb
@@ -179,6 +203,11 @@ fn serialize_doc_info(doc_info: &crate::parser::doc_info::DocInfo) -> Result<Vec
     // Write ID mappings (required for compatibility) - level 0
     write_record(&mut writer, 0x11, 0, &serialize_id_mappings(doc_info)?)?;
 
+    // Write binary data entries - level 1
+    for bin_data in &doc_info.bin_data {
+        write_record(&mut writer, 0x12, 1, &serialize_bin_data(bin_data)?)?;
+    }
+
     // Write face names - level 1
     for face_name in &doc_info.face_names {
         write_record(&mut writer, 0x13, 1, &serialize_face_name(face_name)?)?;
@@ -199,6 +228,14 @@ fn serialize_doc_info(doc_info: &crate::parser::doc_info::DocInfo) -> Result<Vec
         write_record(&mut writer, 0x16, 1, &serialize_tab_def(tab_def)?)?;
     }
 
+    // Write numberings and bullets - level 1
+    for numbering in &doc_info.numberings {
+        write_record(&mut writer, 0x17, 1, &numbering.to_bytes())?;
+    }
+    for bullet in &doc_info.bullets {
+        write_record(&mut writer, 0x18, 1, &bullet.to_bytes())?;
+    }
+
     // Write paragraph shapes - level 1
     for para_shape in &doc_info.para_shapes {
         write_record(&mut writer, 0x19, 1, &serialize_para_shape(para_shape)?)?;
@@ -236,21 +273,20 @@ fn serialize_body_text(body_text: &crate::parser::body_text::BodyText) -> Result
 
     for section in &body_text.sections {
         // First, write section definition paragraph (required for HWP structure)
-        write_section_definition(&mut writer)?;
+        write_section_definition(&mut writer, section)?;
 
         // Then write content paragraphs
-        let para_count = section.paragraphs.len();
-        for (i, paragraph) in section.paragraphs.iter().enumerate() {
-            let is_last = i == para_count - 1;
-            write_content_paragraph(&mut writer, paragraph, is_last)?;
-        }
+        write_paragraph_list(&mut writer, &section.paragraphs, 0)?;
     }
 
     Ok(data)
 }
 
 /// Write section definition paragraph (secd + cold controls)
-fn write_section_definition<W: Write>(writer: &mut W) -> Result<()> {
+fn write_section_definition<W: Write>(
+    writer: &mut W,
+    section: &crate::model::paragraph::Section,
+) -> Result<()> {
     // PARA_HEADER for section definition (charCount=17, has section control)
     #[rustfmt::skip]
     let para_header: [u8; 24] = [
@@ -311,7 +347,17 @@ fn write_section_definition<W: Write>(writer: &mut W) -> Result<()> {
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     ];
-    write_record(writer, 0x47, 1, &ctrl_secd)?;
+    match &section.section_def {
+        Some(section_def) => {
+            let mut data = b"dces".to_vec();
+            data.extend_from_slice(&section_def.to_bytes());
+            if data.len() < ctrl_secd.len() {
+                data.resize(ctrl_secd.len(), 0);
+            }
+            write_record(writer, 0x47, 1, &data)?;
+        }
+        None => write_record(writer, 0x47, 1, &ctrl_secd)?,
+    }
 
     // PAGE_DEF (A4 size page definition)
     #[rustfmt::skip]
@@ -327,7 +373,11 @@ fn write_section_definition<W: Write>(writer: &mut W) -> Result<()> {
         0x00, 0x00, 0x00, 0x00, // gutter = 0
         0x00, 0x00, 0x00, 0x00, // properties = 0
     ];
-    write_record(writer, 0x49, 2, &page_def)?;
+    match &section.page_def {
+        // PAGE_DEF is 40 bytes; the trailing shape ids are not part of the record
+        Some(def) => write_record(writer, 0x49, 2, &def.to_bytes()[..page_def.len()])?,
+        None => write_record(writer, 0x49, 2, &page_def)?,
+    }
 
     // FOOTNOTE_SHAPE x2
     #[rustfmt::skip]
@@ -348,33 +398,103 @@ fn write_section_definition<W: Write>(writer: &mut W) -> Result<()> {
     ];
     write_record(writer, 0x4A, 2, &footnote2)?;
 
-    // PAGE_BORDER_FILL x3
-    #[rustfmt::skip]
-    let border_fill: [u8; 14] = [
-        0x01, 0x00, 0x00, 0x00, 0x89, 0x05, 0x89, 0x05,
-        0x89, 0x05, 0x89, 0x05, 0x01, 0x00,
-    ];
-    write_record(writer, 0x4B, 2, &border_fill)?;
-    write_record(writer, 0x4B, 2, &border_fill)?;
-    write_record(writer, 0x4B, 2, &border_fill)?;
+    // PAGE_BORDER_FILL x3: both, even and odd pages
+    if section.page_border_fills.is_empty() {
+        let page_border_fill = PageBorderFill::new_default().to_bytes();
+        for _ in 0..3 {
+            write_record(writer, TAG_PAGE_BORDER_FILL, 2, &page_border_fill)?;
+        }
+    }
+    for page_border_fill in &section.page_border_fills {
+        write_record(
+            writer,
+            TAG_PAGE_BORDER_FILL,
+            2,
+            &page_border_fill.to_bytes(),
+        )?;
+    }
 
-    // CTRL_HEADER for 'cold' (column define)
-    #[rustfmt::skip]
-    let ctrl_cold: [u8; 16] = [
-        0x64, 0x6C, 0x6F, 0x63, // 'cold'
-        0x04, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00,
-    ];
-    write_record(writer, 0x47, 1, &ctrl_cold)?;
+    // CTRL_HEADER for 'cold' (column define), a single column unless set otherwise
+    let column_def = section
+        .column_def
+        .clone()
+        .unwrap_or_else(|| crate::model::ColumnDef::new(1, 0));
+    write_record(writer, 0x47, 1, &column_def.to_ctrl_header())?;
 
     Ok(())
 }
 
-/// Write a content paragraph with text
-fn write_content_paragraph<W: Write>(
+/// Control id of drawing objects (`gso `)
+const CTRL_ID_GSO: u32 = u32::from_be_bytes(*b"gso ");
+
+/// Shape component id of rectangles (`$rec`)
+const SHAPE_ID_RECTANGLE: u32 = u32::from_be_bytes(*b"$rec");
+
+/// Caption properties placing the caption below the object
+const CAPTION_BOTTOM: u32 = 3;
+
+/// Shape component id of pictures (`$pic`)
+const SHAPE_ID_PICTURE: u32 = u32::from_be_bytes(*b"$pic");
+
+/// Extended control character anchoring drawing objects and tables
+const CTRL_CHAR_EXTENDED: u16 = 0x000B;
+
+/// Object property treating the object as a character of its paragraph
+const OBJECT_INLINE: u32 = 1;
+
+/// Split a flat paragraph list into its paragraphs, each with the cell
+/// paragraphs of its table following it
+fn paragraph_list(
+    paragraphs: &[crate::model::paragraph::Paragraph],
+) -> Vec<(
+    &crate::model::paragraph::Paragraph,
+    &[crate::model::paragraph::Paragraph],
+)> {
+    let mut list = Vec::new();
+    let mut rest = paragraphs;
+    while let Some((paragraph, tail)) = rest.split_first() {
+        let cell_count = paragraph
+            .table_data
+            .as_ref()
+            .map(|table| {
+                table
+                    .cells
+                    .iter()
+                    .map(|cell| cell.paragraph_count as usize)
+                    .sum()
+            })
+            .unwrap_or(0)
+            .min(tail.len());
+        let (cells, tail) = tail.split_at(cell_count);
+        list.push((paragraph, cells));
+        rest = tail;
+    }
+    list
+}
+
+/// Write a paragraph list at `level`, marking its last paragraph
+fn write_paragraph_list<W: Write>(
+    writer: &mut W,
+    paragraphs: &[crate::model::paragraph::Paragraph],
+    level: u16,
+) -> Result<()> {
+    let list = paragraph_list(paragraphs);
+    let count = list.len();
+    for (i, (paragraph, cells)) in list.into_iter().enumerate() {
+        write_paragraph(writer, paragraph, cells, i == count - 1, level)?;
+    }
+    Ok(())
+}
+
+/// Write a paragraph with its PARA_HEADER at `level`, followed by its controls
+///
+/// `cell_paragraphs` holds the content of the paragraph's table, if it has one.
+fn write_paragraph<W: Write>(
     writer: &mut W,
     paragraph: &crate::model::paragraph::Paragraph,
+    cell_paragraphs: &[crate::model::paragraph::Paragraph],
     is_last: bool,
+    level: u16,
 ) -> Result<()> {
     // Get text content
     let text_content = paragraph
@@ -383,11 +503,93 @@ fn write_content_paragraph<W: Write>(
         .map(|t| t.content.as_str())
         .unwrap_or("");
 
-    // Convert to UTF-16LE and add paragraph end marker
-    let mut text_utf16 = string_to_utf16le(text_content);
+    // Anchor the text box and fields ahead of the text
+    let mut units = Vec::new();
+    let mut control_mask = 0u32;
+    if paragraph.table_data.is_some() {
+        units.extend(control_chars(CTRL_CHAR_EXTENDED, CTRL_TABLE));
+        control_mask |= 1 << CTRL_CHAR_EXTENDED;
+    }
+    if paragraph.text_box_data.is_some() || paragraph.picture_data.is_some() {
+        units.extend(control_chars(CTRL_CHAR_EXTENDED, CTRL_ID_GSO));
+        control_mask |= 1 << CTRL_CHAR_EXTENDED;
+    }
+    for _ in &paragraph.form_fields {
+        units.extend(control_chars(CTRL_CHAR_EXTENDED, CTRL_FORM));
+        control_mask |= 1 << CTRL_CHAR_EXTENDED;
+    }
+    for field in &paragraph.fields {
+        if field.is_auto_number() {
+            // Caption numbers follow their label, e.g. "그림 1"
+            if !field.command.is_empty() {
+                units.extend(format!("{} ", field.command).encode_utf16());
+            }
+            units.extend(control_chars(AUTO_NUMBER_CTRL, field.ctrl_id()));
+            control_mask |= 1 << AUTO_NUMBER_CTRL;
+        } else {
+            // The cached value sits between the field's begin and end controls
+            units.extend(control_chars(FIELD_BEGIN_CTRL, field.ctrl_id()));
+            units.extend(field.cached_value.encode_utf16());
+            units.extend(control_chars(FIELD_END_CTRL, field.ctrl_id()));
+            control_mask |= (1 << FIELD_BEGIN_CTRL) | (1 << FIELD_END_CTRL);
+        }
+    }
+    let mut text_utf16: Vec<u8> = units.iter().flat_map(|unit| unit.to_le_bytes()).collect();
+
+    // Convert to UTF-16LE, anchoring ruby annotations with their control, and
+    // note the unit offset each char of the text starts at
+    let mut rubies = Vec::new();
+    let mut unit_offsets = Vec::new();
+    for piece in Ruby::split_annotations(text_content) {
+        let start = (text_utf16.len() / 2) as u32;
+        match piece {
+            TextPiece::Text(text) => {
+                let mut offset = start;
+                for ch in text.chars() {
+                    unit_offsets.push(offset);
+                    offset += ctrl_for_space(ch).map_or(ch.len_utf16(), |_| 1) as u32;
+                }
+                text_utf16.extend(text_to_utf16le(text));
+            }
+            TextPiece::Ruby(ruby) => {
+                for unit in control_chars(RUBY_CTRL, CTRL_RUBY) {
+                    text_utf16.extend_from_slice(&unit.to_le_bytes());
+                }
+                control_mask |= 1 << RUBY_CTRL;
+                let len = ruby.annotation().chars().count();
+                unit_offsets.extend(std::iter::repeat_n(start, len));
+                rubies.push(ruby);
+            }
+        }
+    }
+    unit_offsets.push((text_utf16.len() / 2) as u32);
     text_utf16.extend_from_slice(&[0x0D, 0x00]); // paragraph end marker
     let char_count = (text_utf16.len() / 2) as u32;
 
+    // Character shapes must cover the paragraph from position 0, including the
+    // controls written ahead of the text; other positions move to unit offsets
+    let mut char_positions = paragraph
+        .char_shapes
+        .as_ref()
+        .map(|shapes| shapes.char_positions.clone())
+        .unwrap_or_default();
+    for pos in char_positions.iter_mut().filter(|pos| pos.position > 0) {
+        let end = unit_offsets[unit_offsets.len() - 1];
+        pos.position = unit_offsets
+            .get(pos.position as usize)
+            .copied()
+            .unwrap_or(end);
+    }
+    if char_positions.first().is_none_or(|pos| pos.position > 0) {
+        char_positions.insert(
+            0,
+            crate::model::CharPositionShape {
+                position: 0,
+                char_shape_id: 0,
+            },
+        );
+    }
+
     // PARA_HEADER
     let mut para_header = Vec::new();
     let char_count_flags = if is_last {
@@ -396,23 +598,27 @@ fn write_content_paragraph<W: Write>(
         char_count // lastInList = false
     };
     para_header.write_u32::<LittleEndian>(char_count_flags)?;
-    para_header.write_u32::<LittleEndian>(0)?; // controlMask
-    para_header.write_u16::<LittleEndian>(0)?; // paraShapeId
-    para_header.write_u8(0)?; // styleId
+    para_header.write_u32::<LittleEndian>(control_mask)?; // controlMask
+    para_header.write_u16::<LittleEndian>(paragraph.para_shape_id)?; // paraShapeId
+    para_header.write_u8(paragraph.style_id)?; // styleId
     para_header.write_u8(0)?; // divideSort
-    para_header.write_u16::<LittleEndian>(1)?; // charShapeCount
+    para_header.write_u16::<LittleEndian>(char_positions.len() as u16)?; // charShapeCount
     para_header.write_u16::<LittleEndian>(0)?; // rangeTagCount
     para_header.write_u16::<LittleEndian>(1)?; // lineAlignCount
     para_header.write_u32::<LittleEndian>(0)?; // instanceId
     para_header.write_u16::<LittleEndian>(0)?; // isMergedByTrack
-    write_record(writer, 0x42, 0, &para_header)?;
+    write_record(writer, 0x42, level, &para_header)?;
 
     // PARA_TEXT
-    write_record(writer, 0x43, 1, &text_utf16)?;
+    write_record(writer, 0x43, level + 1, &text_utf16)?;
 
-    // PARA_CHAR_SHAPE
-    let char_shape: [u8; 8] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-    write_record(writer, 0x44, 1, &char_shape)?;
+    // PARA_CHAR_SHAPE (position, shape id) pairs
+    let mut char_shape = Vec::new();
+    for pos in &char_positions {
+        char_shape.write_u32::<LittleEndian>(pos.position)?;
+        char_shape.write_u32::<LittleEndian>(pos.char_shape_id as u32)?;
+    }
+    write_record(writer, 0x44, level + 1, &char_shape)?;
 
     // PARA_LINE_SEG (basic line layout)
     #[rustfmt::skip]
@@ -427,11 +633,378 @@ fn write_content_paragraph<W: Write>(
         0x18, 0xA6, 0x00, 0x00, // lineWidth = 42520
         0x00, 0x00, 0x06, 0x00, // flags
     ];
-    write_record(writer, 0x45, 1, &line_seg)?;
+    write_record(writer, 0x45, level + 1, &line_seg)?;
+
+    if let Some(table) = &paragraph.table_data {
+        write_table_control(writer, table, cell_paragraphs, level + 1)?;
+    }
+    if let Some(text_box) = &paragraph.text_box_data {
+        write_text_box_control(writer, text_box, level + 1)?;
+    }
+    if let Some(picture) = &paragraph.picture_data {
+        write_picture_control(writer, picture, level + 1)?;
+    }
+    for field in &paragraph.form_fields {
+        write_form_control(writer, field, level + 1)?;
+    }
+    for field in &paragraph.fields {
+        write_field_control(writer, field, level + 1)?;
+    }
+    for ruby in &rubies {
+        write_ruby_control(writer, ruby, level + 1)?;
+    }
+
+    Ok(())
+}
+
+/// Encode paragraph text as UTF-16LE, writing special spaces as their controls
+fn text_to_utf16le(text: &str) -> impl Iterator<Item = u8> + '_ {
+    let mut buf = [0u16; 2];
+    text.chars()
+        .flat_map(move |ch| match ctrl_for_space(ch) {
+            Some(code) => vec![code],
+            None => ch.encode_utf16(&mut buf).to_vec(),
+        })
+        .flat_map(u16::to_le_bytes)
+}
+
+/// Write a form object: its `form` CTRL_HEADER followed by the FORM_OBJECT properties
+fn write_form_control<W: Write>(
+    writer: &mut W,
+    field: &crate::model::FormField,
+    level: u16,
+) -> Result<()> {
+    // 4mm square, inline with the text
+    let size = crate::model::page_layout::mm_to_hwp_units(4.0);
+    write_object_ctrl_header(writer, CTRL_FORM, 0, (0, 0), (size, size), 0, level)?;
+    write_record(writer, TAG_FORM_OBJECT, level + 1, &field.to_form_object())
+}
+
+/// Write the CTRL_HEADER of a ruby annotation (`tdut`)
+fn write_ruby_control<W: Write>(writer: &mut W, ruby: &Ruby, level: u16) -> Result<()> {
+    let mut ctrl = Vec::new();
+    ctrl.write_u32::<LittleEndian>(CTRL_RUBY)?;
+    for text in [&ruby.base, &ruby.ruby] {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        ctrl.write_u16::<LittleEndian>(units.len() as u16)?;
+        for unit in units {
+            ctrl.write_u16::<LittleEndian>(unit)?;
+        }
+    }
+    ctrl.write_u32::<LittleEndian>(0)?; // position: above the base text
+    ctrl.write_u32::<LittleEndian>(50)?; // font size ratio (%)
+    ctrl.write_u32::<LittleEndian>(0)?; // option
+    ctrl.write_u32::<LittleEndian>(0)?; // style id
+    ctrl.write_u32::<LittleEndian>(0)?; // alignment: center
+    write_record(writer, 0x47, level, &ctrl)
+}
+
+/// An 8-unit control in paragraph text: code, control id, 4 reserved units, code
+fn control_chars(code: u16, ctrl_id: u32) -> [u16; 8] {
+    let id = ctrl_id.to_le_bytes();
+    [
+        code,
+        u16::from_le_bytes([id[0], id[1]]),
+        u16::from_le_bytes([id[2], id[3]]),
+        0,
+        0,
+        0,
+        0,
+        code,
+    ]
+}
+
+/// Write the CTRL_HEADER of a field
+///
+/// Page and caption numbers are `atno` automatic numbers; other fields carry
+/// their command.
+fn write_field_control<W: Write>(
+    writer: &mut W,
+    field: &crate::model::Field,
+    level: u16,
+) -> Result<()> {
+    let mut ctrl = Vec::new();
+    ctrl.write_u32::<LittleEndian>(field.ctrl_id())?;
+    ctrl.write_u32::<LittleEndian>(field.number_type())?; // properties: number type, digits
+    if field.is_auto_number() {
+        // Caption numbers keep a space between the number and the caption text
+        let suffix = if field.command.is_empty() {
+            0
+        } else {
+            u16::from(b' ')
+        };
+        ctrl.write_u16::<LittleEndian>(field.cached_value.parse().unwrap_or(1))?;
+        ctrl.write_u16::<LittleEndian>(0)?; // user symbol
+        ctrl.write_u16::<LittleEndian>(0)?; // prefix
+        ctrl.write_u16::<LittleEndian>(suffix)?;
+    } else {
+        let command: Vec<u16> = field.command.encode_utf16().collect();
+        ctrl.write_u8(0)?; // extra properties
+        ctrl.write_u16::<LittleEndian>(command.len() as u16)?;
+        for unit in command {
+            ctrl.write_u16::<LittleEndian>(unit)?;
+        }
+        ctrl.write_u32::<LittleEndian>(0)?; // field id
+    }
+    write_record(writer, 0x47, level, &ctrl)
+}
+
+/// Write a text box as a rectangle drawing object whose paragraph list holds the text
+///
+/// Records: CTRL_HEADER (`gso `), SHAPE_COMPONENT, LIST_HEADER and the text
+/// paragraphs, then SHAPE_COMPONENT_RECTANGLE.
+fn write_text_box_control<W: Write>(
+    writer: &mut W,
+    text_box: &crate::model::text_box::TextBox,
+    level: u16,
+) -> Result<()> {
+    use crate::model::para_char_shape::ParaCharShape;
+    use crate::model::paragraph::{ParaText, Paragraph};
+
+    let width = text_box.width;
+    let height = text_box.height;
+
+    write_object_ctrl_header(
+        writer,
+        CTRL_ID_GSO,
+        0,
+        (text_box.x, text_box.y),
+        (width, height),
+        text_box.z_order as i32,
+        level,
+    )?;
+
+    // SHAPE_COMPONENT: geometry, identity transform and line/fill
+    let mut shape = shape_component(SHAPE_ID_RECTANGLE, (width, height), text_box.rotation)?;
+    shape.write_u32::<LittleEndian>(text_box.border_color)?;
+    shape.write_i32::<LittleEndian>(text_box.border_width as i32)?;
+    shape.write_u32::<LittleEndian>(text_box.border_style as u32)?; // line properties
+    shape.write_u8(0)?; // outline style
+    shape.write_u32::<LittleEndian>(text_box.fill_type as u32)?;
+    if text_box.fill_type == crate::model::text_box::TextBoxFillType::Solid {
+        shape.write_u32::<LittleEndian>(text_box.background_color)?;
+        shape.write_u32::<LittleEndian>(0)?; // pattern color
+        shape.write_i32::<LittleEndian>(-1)?; // no pattern
+    }
+    write_record(writer, 0x4C, level + 1, &shape)?;
+
+    let paragraphs: Vec<Paragraph> = text_box
+        .text
+        .split('\n')
+        .map(|line| Paragraph {
+            text: Some(ParaText {
+                content: line.to_string(),
+            }),
+            para_shape_id: text_box.para_shape_id,
+            char_shapes: Some(ParaCharShape::new_single_shape(text_box.char_shape_id)),
+            ..Default::default()
+        })
+        .collect();
+
+    // LIST_HEADER with the inner margins of the text box
+    let mut list_header = Vec::new();
+    list_header.write_i16::<LittleEndian>(paragraphs.len() as i16)?;
+    list_header.write_u32::<LittleEndian>(0)?; // properties
+    for _ in 0..4 {
+        list_header.write_u16::<LittleEndian>(text_box.padding)?;
+    }
+    list_header.write_u32::<LittleEndian>(width)?; // max text width
+    write_record(writer, 0x48, level + 2, &list_header)?;
+
+    let count = paragraphs.len();
+    for (i, paragraph) in paragraphs.iter().enumerate() {
+        write_paragraph(writer, paragraph, &[], i == count - 1, level + 2)?;
+    }
+
+    // SHAPE_COMPONENT_RECTANGLE: corner curvature and the four corners
+    let mut rectangle = Vec::new();
+    rectangle.write_u8(0)?;
+    for (x, y) in [(0, 0), (width, 0), (width, height), (0, height)] {
+        rectangle.write_i32::<LittleEndian>(x as i32)?;
+        rectangle.write_i32::<LittleEndian>(y as i32)?;
+    }
+    write_record(writer, 0x4F, level + 2, &rectangle)?;
+
+    Ok(())
+}
+
+/// Write a picture as a drawing object referencing its BinData entry
+///
+/// Records: CTRL_HEADER (`gso `), the caption's LIST_HEADER and paragraphs,
+/// SHAPE_COMPONENT, SHAPE_COMPONENT_PICTURE.
+fn write_picture_control<W: Write>(
+    writer: &mut W,
+    picture: &crate::model::control::Picture,
+    level: u16,
+) -> Result<()> {
+    use crate::model::para_char_shape::ParaCharShape;
+    use crate::model::paragraph::{ParaText, Paragraph};
+
+    let width = (picture.right - picture.left).max(0) as u32;
+    let height = (picture.bottom - picture.top).max(0) as u32;
+
+    write_object_ctrl_header(
+        writer,
+        CTRL_ID_GSO,
+        0,
+        (picture.left, picture.top),
+        (width, height),
+        picture.z_order,
+        level,
+    )?;
+
+    if let Some(caption) = &picture.caption {
+        let paragraphs: Vec<Paragraph> = caption
+            .split('\n')
+            .map(|line| Paragraph {
+                text: Some(ParaText {
+                    content: line.to_string(),
+                }),
+                char_shapes: Some(ParaCharShape::new_single_shape(0)),
+                ..Default::default()
+            })
+            .collect();
+
+        // LIST_HEADER followed by the caption properties
+        let mut list_header = Vec::new();
+        list_header.write_i16::<LittleEndian>(paragraphs.len() as i16)?;
+        list_header.write_u32::<LittleEndian>(0)?; // properties
+        list_header.write_u32::<LittleEndian>(CAPTION_BOTTOM)?;
+        list_header.write_u32::<LittleEndian>(width)?; // caption width
+        list_header.write_u16::<LittleEndian>(850)?; // gap to the picture (3mm)
+        list_header.write_u32::<LittleEndian>(width)?; // max text width
+        write_record(writer, 0x48, level + 1, &list_header)?;
+
+        let count = paragraphs.len();
+        for (i, paragraph) in paragraphs.iter().enumerate() {
+            write_paragraph(writer, paragraph, &[], i == count - 1, level + 1)?;
+        }
+    }
+    let shape = shape_component(SHAPE_ID_PICTURE, (width, height), 0)?;
+    write_record(writer, 0x4C, level + 1, &shape)?;
+
+    // SHAPE_COMPONENT_PICTURE
+    let mut data = Vec::new();
+    data.write_u32::<LittleEndian>(0)?; // border color
+    data.write_i32::<LittleEndian>(0)?; // border thickness
+    data.write_u32::<LittleEndian>(0)?; // border properties
+    for (x, y) in [(0, 0), (width, 0), (width, height), (0, height)] {
+        data.write_i32::<LittleEndian>(x as i32)?;
+        data.write_i32::<LittleEndian>(y as i32)?;
+    }
+    for crop in [0, 0, width as i32, height as i32] {
+        data.write_i32::<LittleEndian>(crop)?; // crop left, top, right, bottom
+    }
+    for _ in 0..4 {
+        data.write_u16::<LittleEndian>(0)?; // inner margins
+    }
+    data.write_u8(0)?; // brightness
+    data.write_u8(0)?; // contrast
+    data.write_u8(0)?; // effect
+    data.write_u16::<LittleEndian>(picture.bin_item_id)?;
+    data.write_u8(0)?; // border transparency
+    data.write_u32::<LittleEndian>(picture.instance_id)?;
+    write_record(writer, 0x55, level + 1, &data)
+}
+
+/// Write a table and the paragraphs of its cells
+///
+/// Records: CTRL_HEADER (`tbl `), TABLE, then a LIST_HEADER per cell followed
+/// by the cell's paragraphs.
+fn write_table_control<W: Write>(
+    writer: &mut W,
+    table: &crate::model::control::Table,
+    cell_paragraphs: &[crate::model::paragraph::Paragraph],
+    level: u16,
+) -> Result<()> {
+    let height = table
+        .cells
+        .iter()
+        .filter(|cell| cell.cell_address.1 == 0)
+        .map(|cell| cell.height)
+        .sum();
+    write_object_ctrl_header(
+        writer,
+        CTRL_TABLE,
+        OBJECT_INLINE,
+        (0, 0),
+        (table.total_width(), height),
+        0,
+        level,
+    )?;
+    write_record(writer, TAG_TABLE, level + 1, &table.to_bytes())?;
+
+    // Cells own consecutive runs of the paragraphs, in paragraph list order
+    let mut cells: Vec<_> = table.cells.iter().collect();
+    cells.sort_by_key(|cell| cell.paragraph_list_id.unwrap_or(cell.list_header_id));
+    let mut rest = cell_paragraphs;
+    for cell in cells {
+        let (paragraphs, tail) = rest.split_at((cell.paragraph_count as usize).min(rest.len()));
+        rest = tail;
+
+        let count = paragraph_list(paragraphs).len();
+        write_record(writer, 0x48, level + 1, &cell.to_list_header(count))?;
+        write_paragraph_list(writer, paragraphs, level + 1)?;
+    }
 
     Ok(())
 }
 
+/// Write the CTRL_HEADER of a drawing object with its common object properties
+fn write_object_ctrl_header<W: Write>(
+    writer: &mut W,
+    ctrl_id: u32,
+    properties: u32,
+    (x, y): (i32, i32),
+    (width, height): (u32, u32),
+    z_order: i32,
+    level: u16,
+) -> Result<()> {
+    let mut ctrl = Vec::new();
+    ctrl.write_u32::<LittleEndian>(ctrl_id)?;
+    ctrl.write_u32::<LittleEndian>(properties)?; // 0: positioned relative to the paper
+    ctrl.write_i32::<LittleEndian>(y)?; // vertical offset
+    ctrl.write_i32::<LittleEndian>(x)?; // horizontal offset
+    ctrl.write_u32::<LittleEndian>(width)?;
+    ctrl.write_u32::<LittleEndian>(height)?;
+    ctrl.write_i32::<LittleEndian>(z_order)?;
+    for _ in 0..4 {
+        ctrl.write_u16::<LittleEndian>(0)?; // outer margins
+    }
+    ctrl.write_u32::<LittleEndian>(0)?; // instanceId
+    ctrl.write_i32::<LittleEndian>(0)?; // preventPageBreak
+    ctrl.write_u16::<LittleEndian>(0)?; // description length
+    write_record(writer, 0x47, level, &ctrl)
+}
+
+/// SHAPE_COMPONENT data up to the line and fill info: geometry and identity transform
+fn shape_component(
+    component_id: u32,
+    (width, height): (u32, u32),
+    rotation: i16,
+) -> Result<Vec<u8>> {
+    let mut shape = Vec::new();
+    shape.write_u32::<LittleEndian>(component_id)?;
+    shape.write_u32::<LittleEndian>(component_id)?;
+    shape.write_i32::<LittleEndian>(0)?; // x offset in group
+    shape.write_i32::<LittleEndian>(0)?; // y offset in group
+    shape.write_u16::<LittleEndian>(0)?; // group level
+    shape.write_u16::<LittleEndian>(1)?; // local file version
+    shape.write_u32::<LittleEndian>(width)?; // initial width
+    shape.write_u32::<LittleEndian>(height)?; // initial height
+    shape.write_u32::<LittleEndian>(width)?; // current width
+    shape.write_u32::<LittleEndian>(height)?; // current height
+    shape.write_u32::<LittleEndian>(0)?; // flip
+    shape.write_i16::<LittleEndian>(rotation)?;
+    shape.write_i32::<LittleEndian>(width as i32 / 2)?; // rotation center x
+    shape.write_i32::<LittleEndian>(height as i32 / 2)?; // rotation center y
+    shape.write_u16::<LittleEndian>(0)?; // scale/rotation matrix pairs
+    for value in [1.0f64, 0.0, 0.0, 0.0, 1.0, 0.0] {
+        shape.write_f64::<LittleEndian>(value)?; // translation matrix
+    }
+    Ok(shape)
+}
+
 // The following functions are kept for future extension (hyperlinks, images, headers/footers)
 #[allow(dead_code)]
 /// Serialize paragraph header (HWPTAG_PARA_HEADER = 0x42)
@@ -723,6 +1296,12 @@ fn serialize_document_properties(
     writer.write_u32::<LittleEndian>(0)?; // Bullet ID numbering
     writer.write_u32::<LittleEndian>(0)?; // Reserved or caret position
 
+    // Not part of the documented record: written only when a flag is set
+    let flags = props.protection_flags();
+    if flags != 0 {
+        writer.write_u32::<LittleEndian>(flags)?;
+    }
+
     Ok(data)
 }
 
@@ -780,20 +1359,44 @@ fn serialize_id_mappings(doc_info: &crate::parser::doc_info::DocInfo) -> Result<
     Ok(data)
 }
 
-/// Serialize face name
-fn serialize_face_name(face_name: &crate::model::char_shape::FaceName) -> Result<Vec<u8>> {
+/// Write `text` as a WORD character count followed by its UTF-16LE units
+fn write_length_prefixed<W: Write>(writer: &mut W, text: &str) -> Result<()> {
+    let utf16 = string_to_utf16le(text);
+    writer.write_u16::<LittleEndian>(utf16.len() as u16 / 2)?;
+    writer.write_all(&utf16)?;
+    Ok(())
+}
+
+/// Serialize binary data entry (the content itself lives in the BinData storage)
+fn serialize_bin_data(bin_data: &crate::model::bin_data::BinData) -> Result<Vec<u8>> {
+    use crate::model::bin_data::BinDataType;
+
     let mut data = Vec::new();
     let mut writer = Cursor::new(&mut data);
 
-    writer.write_u8(face_name.properties)?;
+    writer.write_u16::<LittleEndian>(bin_data.properties)?;
 
-    let font_name_utf16 = string_to_utf16le(&face_name.font_name);
-    writer.write_u16::<LittleEndian>(font_name_utf16.len() as u16 / 2)?;
-    writer.write_all(&font_name_utf16)?;
+    // File names only for LINK entries, the stream id for the others
+    match bin_data.get_type() {
+        BinDataType::Link => {
+            write_length_prefixed(&mut writer, &bin_data.abs_name)?;
+            write_length_prefixed(&mut writer, &bin_data.rel_name)?;
+        }
+        BinDataType::Embedding => {
+            writer.write_u16::<LittleEndian>(bin_data.bin_id)?;
+            write_length_prefixed(&mut writer, &bin_data.extension)?;
+        }
+        BinDataType::Storage => writer.write_u16::<LittleEndian>(bin_data.bin_id)?,
+    }
 
     Ok(data)
 }
 
+/// Serialize face name
+fn serialize_face_name(face_name: &crate::model::char_shape::FaceName) -> Result<Vec<u8>> {
+    Ok(face_name.to_bytes())
+}
+
 /// Serialize character shape
 fn serialize_char_shape(char_shape: &crate::model::char_shape::CharShape) -> Result<Vec<u8>> {
     let mut data = Vec::new();
@@ -904,11 +1507,11 @@ fn serialize_border_fill(border_fill: &crate::model::border_fill::BorderFill) ->
     serialize_border_line(&mut writer, &border_fill.bottom)?;
     serialize_border_line(&mut writer, &border_fill.diagonal)?;
 
-    // Write fill info (simplified)
-    writer.write_u8(border_fill.fill_info.fill_type as u8)?;
+    // Write fill info (solid fill only)
+    writer.write_u32::<LittleEndian>(border_fill.fill_info.fill_type)?;
     writer.write_u32::<LittleEndian>(border_fill.fill_info.back_color)?;
     writer.write_u32::<LittleEndian>(border_fill.fill_info.pattern_color)?;
-    writer.write_u8(border_fill.fill_info.pattern_type as u8)?;
+    writer.write_u32::<LittleEndian>(border_fill.fill_info.pattern_type)?;
 
     Ok(data)
 }
@@ -1,4 +1,8 @@
-use crate::model::char_shape::CharShape;
+use crate::model::border_fill::BorderFill;
+use crate::model::char_shape::{CharShape, UnderlineStyle};
+use crate::model::page_layout::mm_to_hwp_units;
+use crate::model::para_shape::ParaShape;
+use crate::model::Color;
 
 /// Text style configuration for paragraphs
 #[derive(Debug, Clone)]
@@ -8,6 +12,10 @@ pub struct TextStyle {
     pub bold: bool,
     pub italic: bool,
     pub underline: bool,
+    /// Underline line shape, solid when `None`
+    pub underline_style: Option<UnderlineStyle>,
+    /// Underline color (RGB format: 0xRRGGBB), the text color when `None`
+    pub underline_color: Option<u32>,
     pub strikethrough: bool,
     pub color: u32,
     pub background_color: Option<u32>,
@@ -22,6 +30,8 @@ impl Default for TextStyle {
             bold: false,
             italic: false,
             underline: false,
+            underline_style: None,
+            underline_color: None,
             strikethrough: false,
             color: 0x000000, // Black color by default
             background_color: None,
@@ -64,6 +74,14 @@ impl TextStyle {
         self
     }
 
+    /// Set an underline with the given line shape and color (RGB format: 0xRRGGBB)
+    pub fn underline_style(mut self, style: UnderlineStyle, color: u32) -> Self {
+        self.underline = true;
+        self.underline_style = Some(style);
+        self.underline_color = Some(color);
+        self
+    }
+
     /// Set strikethrough
     pub fn strikethrough(mut self) -> Self {
         self.strikethrough = true;
@@ -92,16 +110,10 @@ impl TextStyle {
         if self.italic {
             properties |= 1 << 1; // Bit 1: Italic
         }
-        if self.underline {
-            properties |= 1 << 2; // Bit 2: Underline
-        }
-        if self.strikethrough {
-            properties |= 1 << 3; // Bit 3: Strikethrough
-        }
 
         let base_size = self.font_size.unwrap_or(12) as i32 * 100; // Convert pt to hwp units
 
-        CharShape {
+        let mut char_shape = CharShape {
             face_name_ids: [face_name_id; 7], // Use the same font for all languages
             ratios: [100; 7],
             char_spaces: [0; 7],
@@ -112,11 +124,18 @@ impl TextStyle {
             shadow_gap_x: 0,
             shadow_gap_y: 0,
             text_color: self.color,
-            underline_color: self.color,
+            underline_color: self.underline_color.unwrap_or(self.color),
             shade_color: self.background_color.unwrap_or(0xFFFFFF),
             shadow_color: 0x808080,
             border_fill_id: 0,
+        };
+        if self.underline {
+            char_shape.set_underline(self.underline_style.unwrap_or_default());
         }
+        if self.strikethrough {
+            char_shape.set_strikethrough();
+        }
+        char_shape
     }
 }
 
@@ -196,6 +215,106 @@ pub enum ParagraphAlignment {
     Distribute = 4,
 }
 
+/// Paragraph-level formatting, used both for writing and for reading back via
+/// [`HwpDocument::paragraph_styles`](crate::HwpDocument::paragraph_styles)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParagraphStyle {
+    /// Background shading of the paragraph
+    pub shading: Option<Color>,
+    /// Space above the paragraph in HWP units
+    pub space_before: i32,
+    /// Space below the paragraph in HWP units
+    pub space_after: i32,
+    /// Keep the paragraph on the same page as the next one
+    pub keep_with_next: bool,
+    /// Keep all lines of the paragraph on one page
+    pub keep_lines_together: bool,
+    /// Spread the characters of each line evenly across its width (배분 정렬)
+    pub distribute_justify: bool,
+}
+
+impl ParagraphStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set background shading (RGB format: 0xRRGGBB)
+    pub fn shading(mut self, color: u32) -> Self {
+        self.shading = Some(Color::from_rgb(color));
+        self
+    }
+
+    /// Set space above the paragraph in millimeters
+    pub fn space_before(mut self, mm: f32) -> Self {
+        self.space_before = mm_to_hwp_units(mm) as i32;
+        self
+    }
+
+    /// Set space below the paragraph in millimeters
+    pub fn space_after(mut self, mm: f32) -> Self {
+        self.space_after = mm_to_hwp_units(mm) as i32;
+        self
+    }
+
+    /// Keep the paragraph on the same page as the next one, e.g. for headings
+    pub fn keep_with_next(mut self, keep: bool) -> Self {
+        self.keep_with_next = keep;
+        self
+    }
+
+    /// Keep all lines of the paragraph on one page
+    pub fn keep_lines_together(mut self, keep: bool) -> Self {
+        self.keep_lines_together = keep;
+        self
+    }
+
+    /// Use distributed justification, spacing Hangul, Latin and digits evenly
+    /// across every line including the last
+    pub fn distribute_justify(mut self, distribute: bool) -> Self {
+        self.distribute_justify = distribute;
+        self
+    }
+
+    /// Build the BorderFill carrying the shading, if any
+    pub(crate) fn to_border_fill(&self) -> Option<BorderFill> {
+        let color = self.shading?;
+        let mut border_fill = BorderFill::new_default();
+        border_fill.fill_info.fill_type = 1; // Solid fill
+        border_fill.fill_info.back_color = color.to_rgb();
+        Some(border_fill)
+    }
+
+    /// Convert to ParaShape for internal use
+    pub(crate) fn to_para_shape(&self, border_fill_id: u16) -> ParaShape {
+        let mut para_shape = ParaShape::new_default();
+        para_shape.border_fill_id = border_fill_id;
+        para_shape.top_para_space = self.space_before;
+        para_shape.bottom_para_space = self.space_after;
+        para_shape.set_keep_with_next(self.keep_with_next);
+        para_shape.set_keep_lines_together(self.keep_lines_together);
+        if self.distribute_justify {
+            para_shape.set_alignment(ParagraphAlignment::Distribute as u8);
+        }
+        para_shape
+    }
+
+    /// Resolve the style of a paragraph shape against the document's border fills
+    pub(crate) fn from_para_shape(para_shape: &ParaShape, border_fills: &[BorderFill]) -> Self {
+        let shading = BorderFill::from_reference(border_fills, para_shape.border_fill_id)
+            .filter(|border_fill| border_fill.fill_info.fill_type & 0x01 != 0)
+            .map(|border_fill| Color::from_rgb(border_fill.fill_info.back_color));
+
+        Self {
+            shading,
+            space_before: para_shape.top_para_space,
+            space_after: para_shape.bottom_para_space,
+            keep_with_next: para_shape.keeps_with_next(),
+            keep_lines_together: para_shape.keeps_lines_together(),
+            distribute_justify: para_shape.get_alignment() == ParagraphAlignment::Distribute as u8,
+        }
+    }
+}
+
 /// List type options
 #[derive(Debug, Clone, PartialEq)]
 pub enum ListType {
@@ -462,6 +581,17 @@ pub struct TableBuilder<'a> {
     merged_cells: std::collections::HashMap<(u32, u32), (u16, u16)>,
     /// Cell border styles: (row, col) -> BorderStyle
     cell_borders: std::collections::HashMap<(u32, u32), CellBorderStyle>,
+    /// First invalid merge request, reported by `finish`
+    merge_error: Option<String>,
+    /// Explicit column widths in HWP units: col -> width
+    column_widths: std::collections::HashMap<u32, u32>,
+    /// Total table width in HWP units, shared by columns without an explicit width
+    total_width: Option<u32>,
+    /// Cell vertical alignments: (row, col) -> alignment
+    cell_valigns: std::collections::HashMap<(u32, u32), crate::model::VAlign>,
+    /// Cell text styles: (row, col) -> style, taking precedence over the header style
+    cell_styles: std::collections::HashMap<(u32, u32), TextStyle>,
+    repeat_header: bool,
 }
 
 impl<'a> TableBuilder<'a> {
@@ -475,15 +605,68 @@ impl<'a> TableBuilder<'a> {
             style: TableStyle::default(),
             merged_cells: std::collections::HashMap::new(),
             cell_borders: std::collections::HashMap::new(),
+            merge_error: None,
+            column_widths: std::collections::HashMap::new(),
+            total_width: None,
+            cell_valigns: std::collections::HashMap::new(),
+            cell_styles: std::collections::HashMap::new(),
+            repeat_header: false,
         }
     }
 
+    /// Set the width of a column in millimeters
+    pub fn column_width(mut self, col: u32, mm: f32) -> Self {
+        if col < self.cols {
+            self.column_widths.insert(col, mm_to_hwp_units(mm));
+        }
+        self
+    }
+
+    /// Set the total table width in millimeters
+    ///
+    /// Columns without an explicit [`column_width`](Self::column_width) share
+    /// whatever width remains.
+    pub fn total_width(mut self, mm: f32) -> Self {
+        self.total_width = Some(mm_to_hwp_units(mm));
+        self
+    }
+
+    /// Width of each column in HWP units
+    fn resolved_column_widths(&self) -> Vec<u32> {
+        let explicit: u32 = self.column_widths.values().sum();
+        let open_cols = self.cols.saturating_sub(self.column_widths.len() as u32);
+        let (shared_width, mut remainder) = match self.total_width {
+            Some(total) if open_cols > 0 => {
+                let remaining = total.saturating_sub(explicit);
+                (remaining / open_cols, remaining % open_cols)
+            }
+            _ => (5000, 0),
+        };
+
+        // Spread any rounding remainder so the columns add up to the total
+        (0..self.cols)
+            .map(|col| {
+                self.column_widths.get(&col).copied().unwrap_or_else(|| {
+                    let extra = u32::from(remainder > 0);
+                    remainder -= extra;
+                    shared_width + extra
+                })
+            })
+            .collect()
+    }
+
     /// Set whether the first row is a header
     pub fn set_header_row(mut self, has_header: bool) -> Self {
         self.has_header = has_header;
         self
     }
 
+    /// Mark the first row as a header repeated on every page the table spans
+    pub fn repeat_header_row(mut self, repeat: bool) -> Self {
+        self.repeat_header = repeat;
+        self
+    }
+
     /// Set a cell's content
     pub fn set_cell(mut self, row: u32, col: u32, text: &str) -> Self {
         if (row as usize) < self.cells.len() && (col as usize) < self.cells[0].len() {
@@ -492,6 +675,14 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Set a cell's content, formatted with `style`
+    pub fn set_cell_styled(mut self, row: u32, col: u32, text: &str, style: &TextStyle) -> Self {
+        if row < self.rows && col < self.cols {
+            self.cell_styles.insert((row, col), style.clone());
+        }
+        self.set_cell(row, col, text)
+    }
+
     /// Set table style
     pub fn set_style(mut self, style: TableStyle) -> Self {
         self.style = style;
@@ -499,6 +690,8 @@ impl<'a> TableBuilder<'a> {
     }
 
     /// Merge cells horizontally or vertically
+    ///
+    /// Out-of-range or overlapping merges are rejected when `finish` is called.
     pub fn merge_cells(
         mut self,
         start_row: u32,
@@ -506,11 +699,49 @@ impl<'a> TableBuilder<'a> {
         row_span: u16,
         col_span: u16,
     ) -> Self {
+        if self.merge_error.is_some() {
+            return self;
+        }
+
+        let end_row = start_row
+            .checked_add(row_span as u32)
+            .filter(|&end| row_span > 0 && end <= self.rows);
+        let end_col = start_col
+            .checked_add(col_span as u32)
+            .filter(|&end| col_span > 0 && end <= self.cols);
+        let (Some(end_row), Some(end_col)) = (end_row, end_col) else {
+            self.merge_error = Some(format!(
+                "Merge at ({start_row}, {start_col}) spanning {row_span}x{col_span} \
+                 does not fit a {}x{} table",
+                self.rows, self.cols
+            ));
+            return self;
+        };
+
+        let overlapping = self.merged_cells.iter().find(|(&(row, col), &(rs, cs))| {
+            start_row < row + rs as u32
+                && row < end_row
+                && start_col < col + cs as u32
+                && col < end_col
+        });
+        if let Some((&(row, col), _)) = overlapping {
+            self.merge_error = Some(format!(
+                "Merge at ({start_row}, {start_col}) overlaps the merge at ({row}, {col})"
+            ));
+            return self;
+        }
+
         self.merged_cells
             .insert((start_row, start_col), (row_span, col_span));
         self
     }
 
+    /// Set the vertical alignment of a cell's content (top by default)
+    pub fn cell_valign(mut self, row: u32, col: u32, valign: crate::model::VAlign) -> Self {
+        self.cell_valigns.insert((row, col), valign);
+        self
+    }
+
     /// Set border style for a specific cell
     pub fn set_cell_border(mut self, row: u32, col: u32, border_style: CellBorderStyle) -> Self {
         self.cell_borders.insert((row, col), border_style);
@@ -600,6 +831,18 @@ impl<'a> TableBuilder<'a> {
             .set_inner_borders(border_style)
     }
 
+    /// Give every cell border the same line type, `(r, g, b)` color and width in millimeters
+    ///
+    /// The width is rounded to the nearest line width HWP supports.
+    pub fn border(self, style: BorderLineType, color: (u8, u8, u8), width: f32) -> Self {
+        let (r, g, b) = color;
+        self.set_all_borders(BorderLineStyle::new(
+            style,
+            crate::model::border_fill::BorderLine::thickness_for_mm(width),
+            crate::model::Color::Rgb(r, g, b).to_rgb(),
+        ))
+    }
+
     /// Remove all borders from the table
     pub fn no_borders(mut self) -> Self {
         let no_border = CellBorderStyle::no_borders();
@@ -620,16 +863,21 @@ impl<'a> TableBuilder<'a> {
             paragraph::{ParaText, Paragraph},
         };
 
-        // Calculate dimensions (using HWP units: 1mm = ~100 units)
-        let col_width = 5000u32; // 50mm per column
-        let row_height = 1000u32; // 10mm per row
+        if let Some(message) = self.merge_error {
+            return Err(crate::error::HwpError::InvalidInput(message));
+        }
+
+        let col_widths = self.resolved_column_widths();
+        let row_height = 1000u32;
 
         // Create the table structure first
         let mut table = Table::new_default(self.rows as u16, self.cols as u16);
+        if self.repeat_header {
+            table.properties |= crate::model::control::TABLE_REPEAT_HEADER;
+        }
 
         // Create border fills for each unique cell border style
         let mut border_fill_map = std::collections::HashMap::new();
-        let mut next_border_fill_id = 1u16;
 
         // Create cell paragraphs and link them to table cells
         let mut cell_paragraphs = Vec::new();
@@ -675,11 +923,7 @@ impl<'a> TableBuilder<'a> {
                     if let Some(&existing_id) = border_fill_map.get(&border_key) {
                         existing_id
                     } else {
-                        let new_id = next_border_fill_id;
-                        next_border_fill_id += 1;
-
-                        // Add the border fill to the document
-                        self.writer.document.doc_info.border_fills.push(border_fill);
+                        let new_id = self.writer.add_border_fill(border_fill);
                         border_fill_map.insert(border_key, new_id);
                         new_id
                     }
@@ -688,17 +932,23 @@ impl<'a> TableBuilder<'a> {
                 };
 
                 // Create and add table cell with proper addressing and merge info
-                let cell = table.create_cell(
-                    row,
-                    col,
-                    col_width * col_span as u32,
-                    row_height * row_span as u32,
-                );
+                let cell_width = col_widths
+                    .iter()
+                    .skip(col_idx)
+                    .take(col_span as usize)
+                    .sum();
+                let cell = table.create_cell(row, col, cell_width, row_height * row_span as u32);
                 cell.list_header_id = paragraph_list_counter;
                 cell.paragraph_list_id = Some(paragraph_list_counter);
                 cell.row_span = row_span;
                 cell.col_span = col_span;
                 cell.border_fill_id = border_fill_id;
+                cell.vertical_align = self
+                    .cell_valigns
+                    .get(&cell_key)
+                    .copied()
+                    .unwrap_or_default();
+                cell.is_header = self.repeat_header && row_idx == 0;
                 paragraph_list_counter += 1;
 
                 // Create paragraph for cell content
@@ -706,22 +956,21 @@ impl<'a> TableBuilder<'a> {
                     content: cell_text.clone(),
                 };
 
-                // Use header style for first row if header is enabled
-                let char_shape_id = if self.has_header && row_idx == 0 {
-                    // Get or create bold char shape for header
-                    if let Some(header_style) = &self.style.header_style {
-                        let face_name_id = if let Some(font_name) = &header_style.font_name {
+                // Use the cell's own style, else the header style for the first row
+                let header_style = (self.has_header && row_idx == 0)
+                    .then_some(self.style.header_style.as_ref())
+                    .flatten();
+                let char_shape_id = match self.cell_styles.get(&cell_key).or(header_style) {
+                    Some(text_style) => {
+                        let face_name_id = if let Some(font_name) = &text_style.font_name {
                             self.writer.ensure_font(font_name)?
                         } else {
                             0
                         };
-                        let char_shape = header_style.to_char_shape(face_name_id);
-                        self.writer.add_char_shape(char_shape)?
-                    } else {
-                        0
+                        let char_shape = text_style.to_char_shape(face_name_id);
+                        self.writer.intern_char_shape(char_shape)?
                     }
-                } else {
-                    0 // Default char shape
+                    None => 0, // Default char shape
                 };
 
                 let paragraph = Paragraph {
@@ -751,6 +1000,11 @@ impl<'a> TableBuilder<'a> {
                     picture_data: None,
                     text_box_data: None,
                     hyperlinks: Vec::new(),
+                    footnotes: Vec::new(),
+                    shapes: Vec::new(),
+                    fields: Vec::new(),
+                    form_fields: Vec::new(),
+                    hidden_comments: Vec::new(),
                 };
                 cell_paragraphs.push(paragraph);
             }
@@ -782,6 +1036,11 @@ impl<'a> TableBuilder<'a> {
             picture_data: None,
             text_box_data: None,
             hyperlinks: Vec::new(),
+            footnotes: Vec::new(),
+            shapes: Vec::new(),
+            fields: Vec::new(),
+            form_fields: Vec::new(),
+            hidden_comments: Vec::new(),
         };
 
         // Add the table paragraph to the document
@@ -1,6 +1,6 @@
 use hwpers::{
-    writer::style::{BorderLineStyle, CellBorderStyle},
-    HwpWriter,
+    writer::style::{BorderLineStyle, BorderLineType, CellBorderStyle},
+    HwpReader, HwpWriter,
 };
 
 #[test]
@@ -236,3 +236,104 @@ fn test_vertical_cell_merge() {
     assert_eq!(merged_cell.row_span, 3);
     assert_eq!(merged_cell.col_span, 1);
 }
+
+#[test]
+fn test_merged_header_reads_back_through_tables() {
+    let mut writer = HwpWriter::new();
+
+    writer
+        .add_table(3, 2)
+        .unwrap()
+        .set_cell(0, 0, "Quarterly Report")
+        .set_cell(1, 0, "Q1")
+        .set_cell(1, 1, "100")
+        .set_cell(2, 0, "Q2")
+        .set_cell(2, 1, "120")
+        .merge_cells(0, 0, 1, 2)
+        .finish()
+        .unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let tables = document.tables();
+    assert_eq!(tables.len(), 1);
+
+    let header = tables[0].get_cell(0, 0).unwrap();
+    assert_eq!(header.col_span, 2);
+    assert_eq!(header.row_span, 1);
+    // The covered cell is removed
+    assert!(tables[0].get_cell(0, 1).is_none());
+    assert_eq!(tables[0].cells.len(), 5);
+    let cell_text = |row, col| -> String {
+        document
+            .table_cell_runs(0, row, col)
+            .iter()
+            .map(|run| run.text.as_str())
+            .collect()
+    };
+    assert_eq!(cell_text(0, 0), "Quarterly Report");
+    assert_eq!(cell_text(2, 1), "120");
+}
+
+#[test]
+fn test_overlapping_merges_are_rejected() {
+    let mut writer = HwpWriter::new();
+
+    let result = writer
+        .add_table(3, 3)
+        .unwrap()
+        .merge_cells(0, 0, 2, 2)
+        .merge_cells(1, 1, 2, 2)
+        .finish();
+    assert!(result.is_err());
+
+    let result = writer
+        .add_table(2, 2)
+        .unwrap()
+        .merge_cells(1, 1, 2, 1)
+        .finish();
+    assert!(result.is_err());
+
+    // A span past u32::MAX is reported instead of overflowing
+    let result = writer
+        .add_table(2, 2)
+        .unwrap()
+        .merge_cells(u32::MAX, 0, 1, 1)
+        .finish();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_table_dashed_red_border() {
+    let mut writer = HwpWriter::new();
+    writer
+        .add_table(2, 2)
+        .unwrap()
+        .set_cell(0, 0, "A")
+        .border(BorderLineType::Dashed, (255, 0, 0), 0.4)
+        .finish()
+        .unwrap();
+
+    // Cells keep their border fill reference through the binary round trip
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let border_fill_ids: Vec<u16> = document.tables()[0]
+        .cells
+        .iter()
+        .map(|cell| cell.border_fill_id)
+        .collect();
+    assert_eq!(border_fill_ids.len(), 4);
+    assert!(border_fill_ids.iter().all(|&id| id == border_fill_ids[0]));
+
+    let border_fill = document
+        .get_border_fill(border_fill_ids[0] as usize - 1)
+        .unwrap();
+    for line in [
+        &border_fill.left,
+        &border_fill.right,
+        &border_fill.top,
+        &border_fill.bottom,
+    ] {
+        assert_eq!(line.line_type, BorderLineType::Dashed as u8);
+        assert_eq!(line.color, 0xFF0000);
+        assert_eq!(line.width_mm(), Some(0.4));
+    }
+}
@@ -0,0 +1,34 @@
+//! Builders for the HWP streams synthesized by the integration tests
+#![allow(dead_code)]
+
+/// A record of `tag` at `level` holding `data`
+pub fn record(tag: u32, level: u32, data: &[u8]) -> Vec<u8> {
+    let header = tag | (level << 10) | ((data.len() as u32) << 20);
+    let mut bytes = header.to_le_bytes().to_vec();
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// The empty paragraph header a section stream starts with, read as the
+/// section definition
+pub fn section_definition() -> Vec<u8> {
+    record(0x42, 0, &[0; 24])
+}
+
+/// `text` as UTF-16LE bytes
+pub fn utf16(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+/// The records of the uncompressed stream at `path` of an HWP file
+pub fn stream_records(bytes: Vec<u8>, path: &str) -> Vec<hwpers::parser::record::Record> {
+    use std::io::Read;
+
+    let mut file = cfb::CompoundFile::open(std::io::Cursor::new(bytes)).unwrap();
+    let mut data = Vec::new();
+    file.open_stream(path)
+        .unwrap()
+        .read_to_end(&mut data)
+        .unwrap();
+    hwpers::parser::record::RecordReader::new(data).collect()
+}
@@ -0,0 +1,204 @@
+mod common;
+
+use hwpers::model::{CaptionKind, FieldKind};
+use hwpers::{HwpReader, HwpWriter};
+
+#[test]
+fn test_page_number_and_date_fields_round_trip() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Report").unwrap();
+    writer.add_page_number_field().unwrap();
+    writer.add_date_field("%Y-%m-%d").unwrap();
+
+    let cached_date = writer.document().fields()[1].cached_value.clone();
+    assert_eq!(cached_date.len(), 10);
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let fields = document.fields();
+    assert_eq!(fields.len(), 2);
+
+    assert_eq!(fields[0].kind, FieldKind::PageNumber);
+    assert_eq!(fields[0].cached_value, "1");
+
+    assert_eq!(fields[1].kind, FieldKind::Date);
+    assert_eq!(fields[1].command, "%Y-%m-%d");
+    assert_eq!(fields[1].cached_value, cached_date);
+
+    // Control data does not leak into the text; the cached date does show
+    let text = document.extract_text();
+    assert!(text.contains("Report"));
+    assert!(text.contains(&cached_date));
+    assert_eq!(document.extraction_report().unhandled_controls, 0);
+}
+
+#[test]
+fn test_invalid_date_format_rejected() {
+    let mut writer = HwpWriter::new();
+    assert!(writer.add_date_field("%Q").is_err());
+}
+
+#[test]
+fn test_bookmarks_round_trip() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("수신: ").unwrap();
+    writer.add_bookmark("recipient").unwrap();
+    writer.add_paragraph("본문").unwrap();
+    writer.add_bookmark("signature").unwrap();
+
+    assert!(writer.add_bookmark("recipient").is_err());
+    assert!(writer.add_bookmark("").is_err());
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert_eq!(document.bookmarks(), vec!["recipient", "signature"]);
+    assert_eq!(document.fields()[0].kind, FieldKind::Bookmark);
+    assert_eq!(document.extraction_report().unhandled_controls, 0);
+    assert!(document.extract_text().contains("본문"));
+}
+
+#[test]
+fn test_checkbox_round_trip() {
+    let mut writer = HwpWriter::new();
+    writer.add_checkbox(true, "I agree to the terms").unwrap();
+    writer.add_checkbox(false, "Send me updates").unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let form_fields = document.form_fields();
+    assert_eq!(form_fields.len(), 2);
+
+    assert_eq!(form_fields[0].kind, hwpers::model::FormFieldKind::CheckBox);
+    assert_eq!(form_fields[0].name, "CheckBox1");
+    assert_eq!(form_fields[0].caption, "I agree to the terms");
+    assert!(form_fields[0].is_checked());
+
+    assert_eq!(form_fields[1].name, "CheckBox2");
+    assert!(!form_fields[1].is_checked());
+
+    let text = document.extract_text();
+    assert!(text.contains("I agree to the terms"));
+    assert!(text.contains("Send me updates"));
+    assert_eq!(document.extraction_report().unhandled_controls, 0);
+}
+
+#[test]
+fn test_checkbox_form_object_layout() {
+    let mut writer = HwpWriter::new();
+    writer.add_checkbox(true, "Member").unwrap();
+
+    let records = common::stream_records(writer.to_bytes().unwrap(), "BodyText/Section0");
+    let form = records
+        .iter()
+        .find(|record| record.tag_id() == 0x5B)
+        .unwrap();
+    // The form object type id, twice, then the property string
+    let type_id = u32::from_be_bytes(*b"+cbt").to_le_bytes();
+    assert_eq!(form.data[..4], type_id);
+    assert_eq!(form.data[4..8], type_id);
+    let len = u16::from_le_bytes([form.data[8], form.data[9]]) as usize;
+    assert_eq!(form.data.len(), 10 + len * 2);
+    let units: Vec<u16> = form.data[10..]
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    let properties = String::from_utf16(&units).unwrap();
+    assert!(properties.starts_with("CheckBox Name:wstring:9:CheckBox1"));
+    assert!(properties.contains(" CharShape:set:"));
+
+    // The label paragraph carries its own character shape
+    let char_shape = records
+        .iter()
+        .rev()
+        .find(|record| record.tag_id() == 0x44)
+        .unwrap();
+    assert_eq!(char_shape.data, [0u8; 8]);
+}
+
+#[test]
+fn test_filled_in_form_fields() {
+    use hwpers::model::{FormField, FormFieldKind};
+
+    // Fixture: a form with an input box and a combo box, as filled in by a user
+    let mut writer = HwpWriter::new();
+    writer.add_checkbox(true, "Member").unwrap();
+    writer.add_paragraph("Applicant").unwrap();
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let paragraph = &mut document.body_texts[0].sections[0].paragraphs[1];
+    paragraph.form_fields.push(FormField {
+        kind: FormFieldKind::Edit,
+        name: "Applicant".to_string(),
+        caption: String::new(),
+        value: "Hong Gildong".to_string(),
+    });
+    paragraph.form_fields.push(FormField {
+        kind: FormFieldKind::ComboBox,
+        name: "Department".to_string(),
+        caption: String::new(),
+        value: "Sales".to_string(),
+    });
+
+    let bytes = HwpWriter::from_document(document).to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let kinds: Vec<_> = document.form_fields().iter().map(|f| f.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            FormFieldKind::CheckBox,
+            FormFieldKind::Edit,
+            FormFieldKind::ComboBox
+        ]
+    );
+    assert_eq!(
+        document.form_field("Applicant").unwrap().value,
+        "Hong Gildong"
+    );
+    assert_eq!(document.form_field("Department").unwrap().value, "Sales");
+    assert!(document.form_field("Missing").is_none());
+    assert_eq!(document.extraction_report().unhandled_controls, 0);
+}
+
+#[test]
+fn test_form_properties_skip_nested_sets() {
+    use hwpers::model::{FormField, FormFieldKind};
+
+    let field = FormField::parse_properties(
+        "Edit Name:wstring:5:Email CharShape:set:26:Height:int:1000 Bold:int:0 \
+         Text:wstring:11:a b@test.kr",
+    )
+    .unwrap();
+    assert_eq!(field.kind, FormFieldKind::Edit);
+    assert_eq!(field.name, "Email");
+    assert_eq!(field.value, "a b@test.kr");
+
+    assert!(FormField::parse_properties("Slider Name:wstring:1:x").is_none());
+}
+
+#[test]
+fn test_captions_numbered_in_sequence() {
+    let mut writer = HwpWriter::new();
+    writer
+        .add_caption(CaptionKind::Figure, "시스템 구성")
+        .unwrap();
+    writer.add_caption(CaptionKind::Table, "실험 결과").unwrap();
+    writer
+        .add_caption(CaptionKind::Figure, "처리 흐름")
+        .unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let figures: Vec<_> = document
+        .fields()
+        .into_iter()
+        .filter(|field| field.kind == FieldKind::CaptionNumber(CaptionKind::Figure))
+        .map(|field| field.cached_value.as_str())
+        .collect();
+    assert_eq!(figures, ["1", "2"]);
+    assert_eq!(
+        document.fields()[1].kind,
+        FieldKind::CaptionNumber(CaptionKind::Table)
+    );
+    assert_eq!(document.fields()[1].cached_value, "1");
+
+    let text = document.extract_text();
+    assert!(text.contains("그림 시스템 구성"));
+    assert!(text.contains("표 실험 결과"));
+    assert_eq!(document.extraction_report().unhandled_controls, 0);
+}
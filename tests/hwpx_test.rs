@@ -114,7 +114,7 @@ fn test_hwpx_from_document() {
     }
 
     let original = HwpxReader::from_file(&path).expect("Failed to read original");
-    let original_text = original.extract_text();
+    let original_text = original.extract_text().into_owned();
 
     let writer = HwpxWriter::from_document(original);
     let bytes = writer.to_bytes().unwrap();
@@ -319,6 +319,41 @@ fn test_hwpx_table_save_and_read() {
     assert!(text.contains("테이블 문서"));
 }
 
+#[test]
+fn test_hwpx_tables_extracted() {
+    let mut writer = HwpxWriter::new();
+    writer.add_paragraph("Before").unwrap();
+    writer
+        .add_table(HwpxTable::from_data(vec![
+            vec!["이름", "나이"],
+            vec!["홍길동", "30"],
+            vec!["김철수", "25"],
+        ]))
+        .unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpxReader::from_bytes(&bytes).expect("Failed to read");
+
+    let tables = document.tables();
+    assert_eq!(tables.len(), 1);
+    assert_eq!((tables[0].rows, tables[0].cols), (3, 2));
+    assert_eq!(tables[0].cells.len(), 6);
+    assert!(tables[0].get_cell(2, 1).is_some());
+
+    let text = document.extract_text();
+    assert!(text.contains("홍길동"));
+    assert!(text.contains("25"));
+
+    let segments = document.text_with_table_context();
+    assert_eq!(segments[0].cell, None);
+    let cell = segments
+        .iter()
+        .find(|segment| segment.text == "김철수")
+        .and_then(|segment| segment.cell)
+        .unwrap();
+    assert_eq!((cell.table_index, cell.row, cell.col), (0, 2, 0));
+}
+
 #[test]
 fn test_hwpx_image_basic() {
     let mut writer = HwpxWriter::new();
@@ -428,6 +463,29 @@ fn test_hwpx_hyperlink_save_and_read() {
     assert!(text.contains("네이버"));
 }
 
+#[test]
+fn test_hwpx_hyperlinks_extracted() {
+    let mut writer = HwpxWriter::new();
+    let links = vec![
+        HwpxHyperlink::new("Google", "https://google.com"),
+        HwpxHyperlink::new("GitHub", "https://github.com"),
+    ];
+    writer
+        .add_paragraph_with_hyperlinks("Visit Google or GitHub for more.", links)
+        .unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpxReader::from_bytes(&bytes).expect("Failed to read");
+
+    let hyperlinks = document.hyperlinks();
+    assert_eq!(hyperlinks.len(), 2);
+    assert_eq!(hyperlinks[0].display_text, "Google");
+    assert_eq!(hyperlinks[0].target_url, "https://google.com");
+    assert_eq!(hyperlinks[0].length, 6);
+    assert_eq!(hyperlinks[1].target_url, "https://github.com");
+    assert!(document.footnotes().is_empty());
+}
+
 #[test]
 fn test_hwpx_header_basic() {
     let mut writer = HwpxWriter::new();
@@ -514,3 +572,239 @@ fn test_hwpx_odd_even_headers() {
     let bytes = writer.to_bytes().unwrap();
     assert!(!bytes.is_empty());
 }
+
+/// Rewrite `Contents/section0.xml` of an HWPX package with `transform`
+fn rewrite_section(bytes: &[u8], transform: impl Fn(&str) -> Vec<u8>) -> Vec<u8> {
+    use std::io::{Cursor, Read, Write};
+
+    let mut source = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut target = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for i in 0..source.len() {
+        let mut file = source.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut data = Vec::new();
+        file.read_to_end(&mut data).unwrap();
+        if name == "Contents/section0.xml" {
+            data = transform(std::str::from_utf8(&data).unwrap());
+        }
+        target
+            .start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        target.write_all(&data).unwrap();
+    }
+    target.finish().unwrap().into_inner()
+}
+
+#[test]
+fn test_hwpx_section_with_bom() {
+    let mut writer = HwpxWriter::new();
+    writer.add_paragraph("BOM 앞의 본문").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    let with_bom = rewrite_section(&bytes, |xml| {
+        let mut data = b"\xEF\xBB\xBF".to_vec();
+        data.extend_from_slice(xml.as_bytes());
+        data
+    });
+
+    let document = HwpxReader::from_bytes(&with_bom).unwrap();
+    assert!(document.extract_text().contains("BOM 앞의 본문"));
+}
+
+#[test]
+fn test_hwpx_section_with_declared_encoding() {
+    let mut writer = HwpxWriter::new();
+    writer.add_paragraph("EUC-KR 본문").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    let euc_kr = rewrite_section(&bytes, |xml| {
+        assert!(xml.contains("encoding=\"UTF-8\""));
+        let xml = xml.replacen("encoding=\"UTF-8\"", "encoding=\"EUC-KR\"", 1);
+        encoding_rs::EUC_KR.encode(&xml).0.into_owned()
+    });
+
+    let document = HwpxReader::from_bytes(&euc_kr).unwrap();
+    assert!(document.extract_text().contains("EUC-KR 본문"));
+}
+
+fn read_section_xml(bytes: &[u8]) -> String {
+    use std::io::{Cursor, Read};
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name("Contents/section0.xml")
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    xml
+}
+
+#[test]
+fn test_hwpx_pretty_and_minified_output() {
+    let build = |pretty: bool| {
+        let mut writer = HwpxWriter::new();
+        writer.set_pretty(pretty);
+        writer.add_paragraph("첫 문단").unwrap();
+        writer.add_paragraph("").unwrap();
+        writer
+            .add_styled_paragraph("굵은 글씨", HwpxTextStyle::new().bold())
+            .unwrap();
+        writer
+            .add_table(HwpxTable::from_data(vec![vec!["A", "B"], vec!["C", "D"]]))
+            .unwrap();
+        writer.to_bytes().unwrap()
+    };
+    let pretty = build(true);
+    let minified = build(false);
+
+    assert!(read_section_xml(&pretty).contains('\n'));
+    assert!(!read_section_xml(&minified).contains('\n'));
+
+    let pretty_doc = HwpxReader::from_bytes(&pretty).unwrap();
+    let minified_doc = HwpxReader::from_bytes(&minified).unwrap();
+    assert_eq!(pretty_doc.extract_text(), minified_doc.extract_text());
+    assert_eq!(
+        pretty_doc.paragraphs().count(),
+        minified_doc.paragraphs().count()
+    );
+    assert!(pretty_doc.extract_text().contains("굵은 글씨"));
+}
+
+/// Copy an HWPX package, leaving out the part named `skip`
+fn without_part(bytes: &[u8], skip: &str) -> Vec<u8> {
+    use std::io::{Cursor, Read, Write};
+
+    let mut source = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut target = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for i in 0..source.len() {
+        let mut file = source.by_index(i).unwrap();
+        let name = file.name().to_string();
+        if name == skip || file.is_dir() {
+            continue;
+        }
+        let mut data = Vec::new();
+        file.read_to_end(&mut data).unwrap();
+        target
+            .start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        target.write_all(&data).unwrap();
+    }
+    target.finish().unwrap().into_inner()
+}
+
+#[test]
+fn test_hwpx_validate_missing_parts() {
+    use hwpers::HwpError;
+
+    let mut writer = HwpxWriter::new();
+    writer.add_paragraph("Valid package").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+    HwpxReader::validate(&bytes).unwrap();
+
+    let no_mimetype = without_part(&bytes, "mimetype");
+    match HwpxReader::validate(&no_mimetype) {
+        Err(HwpError::MissingPart(part)) => assert_eq!(part, "mimetype"),
+        other => panic!("expected MissingPart, got {other:?}"),
+    }
+    assert!(matches!(
+        HwpxReader::from_bytes(&no_mimetype),
+        Err(HwpError::MissingPart(_))
+    ));
+
+    let no_manifest = without_part(&bytes, "Contents/content.hpf");
+    match HwpxReader::from_bytes(&no_manifest) {
+        Err(HwpError::MissingPart(part)) => assert_eq!(part, "Contents/content.hpf"),
+        other => panic!("expected MissingPart, got {other:?}"),
+    }
+
+    assert!(matches!(
+        HwpxReader::validate(b"not a zip"),
+        Err(HwpError::InvalidFormat(_))
+    ));
+}
+
+#[test]
+fn test_hwpx_mimetype_first_and_version() {
+    use std::io::{Cursor, Read};
+
+    let mut writer = HwpxWriter::new();
+    writer.set_version(5, 2);
+    writer.add_paragraph("Versioned").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes.clone())).unwrap();
+    {
+        let mut first = archive.by_index(0).unwrap();
+        assert_eq!(first.name(), "mimetype");
+        assert_eq!(first.compression(), zip::CompressionMethod::Stored);
+        let mut mimetype = String::new();
+        first.read_to_string(&mut mimetype).unwrap();
+        assert_eq!(mimetype, "application/hwp+zip");
+    }
+
+    let mut version_xml = String::new();
+    archive
+        .by_name("version.xml")
+        .unwrap()
+        .read_to_string(&mut version_xml)
+        .unwrap();
+    assert!(version_xml.contains(r#"major="5" minor="2""#));
+
+    let document = HwpxReader::from_bytes(&bytes).unwrap();
+    assert_eq!(document.header.version_string(), "5.2.0.0");
+}
+
+#[test]
+fn test_hwpx_table_header_rows() {
+    let mut writer = HwpxWriter::new();
+    writer
+        .add_table(HwpxTable::from_data(vec![
+            vec!["Name", "Value"],
+            vec!["a", "1"],
+        ]))
+        .unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    // Mark the two cells of the first row as header cells
+    let marked = rewrite_section(&bytes, |xml| {
+        xml.replacen(r#"header="0""#, r#"header="1""#, 2)
+            .into_bytes()
+    });
+
+    let document = HwpxReader::from_bytes(&marked).unwrap();
+    let tables = document.tables();
+    assert!(tables[0].repeats_header());
+    assert_eq!(tables[0].header_rows(), 1);
+}
+
+#[test]
+fn test_hwpx_section_xml() {
+    let mut writer = HwpxWriter::new();
+    writer.add_paragraph("원본 XML").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    let document = HwpxReader::from_bytes(&bytes).unwrap();
+    let xml = document.section_xml(0).unwrap();
+    assert!(xml.contains("<hs:sec"));
+    assert!(xml.contains("원본 XML"));
+    assert!(document.section_xml(1).is_none());
+
+    let mut hwp = hwpers::HwpWriter::new();
+    hwp.add_paragraph("binary").unwrap();
+    assert!(hwp.document().section_xml(0).is_none());
+}
+
+#[test]
+fn test_hwpx_table_dimensions() {
+    let mut writer = HwpxWriter::new();
+    writer
+        .add_table(HwpxTable::from_data(vec![
+            vec!["a", "b", "c"],
+            vec!["d", "e", "f"],
+        ]))
+        .unwrap();
+
+    let document = HwpxReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.table_dimensions(), [(2, 3)]);
+}
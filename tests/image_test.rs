@@ -1,6 +1,9 @@
+mod common;
+
+use common::{record, section_definition};
 use hwpers::{
     writer::style::{ImageAlign, ImageFormat, ImageOptions},
-    HwpWriter,
+    HwpReader, HwpWriter,
 };
 use std::fs::File;
 use std::io::Write;
@@ -146,7 +149,10 @@ fn test_multiple_images() {
     assert_eq!(document.doc_info.bin_data.len(), 3);
     for (i, bin_data) in document.doc_info.bin_data.iter().enumerate() {
         let expected_id = (i + 1) as u16; // IDs start from 1
-        assert_eq!(bin_data.abs_name, format!("image{}.png", expected_id));
+        assert_eq!(
+            bin_data.stream_name(),
+            format!("BIN{:04X}.png", expected_id)
+        );
         assert_eq!(bin_data.bin_id, expected_id);
     }
 
@@ -244,3 +250,150 @@ fn test_mixed_content_with_images() {
         .content
         .contains("Text after the image"));
 }
+
+fn section_with_inline_image() -> hwpers::parser::body_text::BodyText {
+    let mut text: Vec<u16> = "word".encode_utf16().collect();
+    // Inline drawing object anchor: code, ctrl id 'gso ', 4 reserved units, code
+    text.extend_from_slice(&[0x000B, 0x6F73, 0x2067, 0, 0, 0, 0, 0x000B]);
+    text.extend("word".encode_utf16());
+    text.push(0x000D);
+    let text_bytes: Vec<u8> = text.iter().flat_map(|u| u.to_le_bytes()).collect();
+
+    let mut stream = section_definition();
+    stream.extend(record(0x42, 0, &[0; 24]));
+    stream.extend(record(0x43, 0, &text_bytes));
+    hwpers::parser::body_text::BodyTextParser::parse(stream, false).unwrap()
+}
+
+#[test]
+fn test_inline_image_keeps_words_separated() {
+    use hwpers::model::ExtractionOptions;
+
+    let body = section_with_inline_image();
+
+    let text = body.extract_text();
+    assert!(text.starts_with("word word"), "got {text:?}");
+
+    let tagged =
+        body.extract_text_with(&ExtractionOptions::new().object_placeholder(Some("[image]")));
+    assert!(tagged.starts_with("word[image]word"), "got {tagged:?}");
+
+    let glued = body.extract_text_with(&ExtractionOptions::new().object_placeholder(None));
+    assert!(glued.starts_with("wordword"), "got {glued:?}");
+}
+
+#[test]
+fn test_bin_data_bytes_by_id() {
+    let test_png = create_test_png();
+    let test_jpeg = create_test_jpeg();
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_image_from_bytes(&test_png, ImageFormat::Png)
+        .unwrap();
+    writer
+        .add_image_from_bytes(&test_jpeg, ImageFormat::Jpeg)
+        .unwrap();
+
+    // Uncompressed storage
+    let bytes = writer.to_bytes().unwrap();
+    let mut document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(!document.header.is_compressed());
+    assert_eq!(document.bin_data(1), Some(test_png.as_slice()));
+    assert_eq!(document.bin_data(2), Some(test_jpeg.as_slice()));
+    assert_eq!(document.bin_data(3), None);
+
+    // Compressed storage
+    document.header.set_compressed(true);
+    let bytes = HwpWriter::from_document(document).to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.header.is_compressed());
+    assert_eq!(document.bin_data(2), Some(test_jpeg.as_slice()));
+}
+
+#[test]
+fn test_bin_data_record_layout() {
+    use hwpers::model::bin_data::BinData;
+    use hwpers::parser::record::RecordReader;
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_image_from_bytes(&create_test_png(), ImageFormat::Png)
+        .unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    // EMBEDDING: properties, the BinData id and the extension, no file names
+    let doc_info = common::stream_records(bytes, "DocInfo");
+    let entry = doc_info.iter().find(|r| r.tag_id() == 0x12).unwrap();
+    let mut expected = vec![0x01, 0x00, 0x01, 0x00, 0x03, 0x00];
+    expected.extend(common::utf16("png"));
+    assert_eq!(entry.data, expected);
+
+    // LINK: properties and the absolute and relative paths only
+    let mut link = vec![0x00, 0x00, 0x05, 0x00];
+    link.extend(common::utf16("C:\\a\\"));
+    link.extend([0x01, 0x00]);
+    link.extend(common::utf16("b"));
+    let record = RecordReader::new(common::record(0x12, 1, &link))
+        .next()
+        .unwrap();
+    let linked = BinData::from_record(&record).unwrap();
+    assert_eq!(linked.abs_name, "C:\\a\\");
+    assert_eq!(linked.rel_name, "b");
+    assert!(!linked.is_stored());
+    assert!(linked.data.is_empty());
+}
+
+#[test]
+fn test_embedded_image_dimensions() {
+    let mut writer = HwpWriter::new();
+    let options = ImageOptions::new().width(80).height(40);
+    writer
+        .add_image_with_options(&create_test_png(), ImageFormat::Png, &options)
+        .unwrap();
+    writer
+        .add_image_from_bytes(&create_test_jpeg(), ImageFormat::Jpeg)
+        .unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let images = document.embedded_images();
+    assert_eq!(images.len(), 2);
+
+    let png = &images[0];
+    assert_eq!(png.extension, "png");
+    assert!((png.display_width_mm.unwrap() - 80.0).abs() < 0.1);
+    assert!((png.display_height_mm.unwrap() - 40.0).abs() < 0.1);
+    assert_eq!((png.pixel_width, png.pixel_height), (Some(1), Some(1)));
+
+    // The truncated JPEG has no frame header
+    let jpeg = &images[1];
+    assert!(jpeg.display_width_mm.is_some());
+    assert_eq!((jpeg.pixel_width, jpeg.pixel_height), (None, None));
+}
+
+#[test]
+fn test_image_caption_round_trip() {
+    let mut writer = HwpWriter::new();
+    writer
+        .add_image_with_caption(
+            &create_test_png(),
+            ImageFormat::Png,
+            60,
+            30,
+            "그림 1. 조직도",
+        )
+        .unwrap();
+    writer.add_paragraph("본문").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let images = document.embedded_images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].caption.as_deref(), Some("그림 1. 조직도"));
+    assert!((images[0].display_width_mm.unwrap() - 60.0).abs() < 0.1);
+
+    // The caption stays inside the picture control
+    assert_eq!(document.paragraphs().count(), 2);
+    assert!(document.extract_text().contains("본문"));
+}
@@ -1,4 +1,7 @@
-use hwpers::HwpReader;
+mod common;
+
+use common::{record, section_definition, utf16};
+use hwpers::{HwpReader, HwpWriter, ReaderOptions};
 use std::path::PathBuf;
 
 fn test_file_path(name: &str) -> PathBuf {
@@ -54,3 +57,845 @@ fn test_from_bytes() {
     // Verify we can parse from bytes
     assert!(doc.sections().count() > 0);
 }
+
+#[test]
+fn test_stream_info_of_compressed_document() {
+    let mut writer = HwpWriter::new();
+    for i in 0..50 {
+        writer
+            .add_paragraph(&format!(
+                "Paragraph {i} repeats the same words again and again"
+            ))
+            .unwrap();
+    }
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    document.header.set_compressed(true);
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("compressed.hwp");
+    std::fs::write(
+        &path,
+        HwpWriter::from_document(document).to_bytes().unwrap(),
+    )
+    .unwrap();
+
+    let section = HwpReader::stream_info(&path, "BodyText/Section0").unwrap();
+    assert!(section.is_compressed);
+    assert!(!section.is_encrypted);
+    assert!(section.decompressed_size > section.compressed_size);
+
+    let header = HwpReader::stream_info(&path, "/FileHeader").unwrap();
+    assert_eq!(header.name, "FileHeader");
+    assert!(!header.is_compressed);
+    assert_eq!(
+        (header.compressed_size, header.decompressed_size),
+        (256, 256)
+    );
+
+    assert!(HwpReader::stream_info(&path, "BodyText/Section9").is_err());
+}
+
+#[test]
+fn test_plain_document_has_no_scripts() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("No macros here").unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    // The writer stores Hangul's blank script template, which is not a macro
+    assert!(!document.scripts.is_empty());
+    assert!(!document.has_scripts());
+    assert!(document.script_sources().is_empty());
+}
+
+#[test]
+fn test_script_languages_of_macro_document() {
+    use std::io::{Cursor, Write};
+
+    fn script_stream(source: &str) -> Vec<u8> {
+        let mut data = Vec::new();
+        for block in ["", source, "", ""] {
+            let units = utf16(block);
+            data.extend_from_slice(&((units.len() / 2) as u32).to_le_bytes());
+            data.extend(units);
+        }
+        data.extend_from_slice(&u32::MAX.to_le_bytes());
+        data
+    }
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Macro bearing").unwrap();
+    assert!(HwpReader::from_bytes(&writer.to_bytes().unwrap())
+        .unwrap()
+        .script_languages()
+        .is_empty());
+
+    // Fixture: a JScript open handler plus a VBScript module
+    let bytes = patch_stream(
+        writer.to_bytes().unwrap(),
+        "/Scripts/DefaultJScript",
+        |data| *data = script_stream("function OnDocument_Open() { Run(\"cmd\"); }"),
+    );
+    let mut file = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+    let mut stream = file.create_stream("/Scripts/DefaultVBScript").unwrap();
+    stream
+        .write_all(&script_stream("Sub Document_Open()\r\nEnd Sub"))
+        .unwrap();
+    drop(stream);
+    file.flush().unwrap();
+    let bytes = file.into_inner().into_inner();
+
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.has_scripts());
+    assert_eq!(document.script_languages(), ["JScript", "VBScript"]);
+    let mut names = document.script_stream_names();
+    names.sort();
+    assert_eq!(names, ["Scripts/DefaultJScript", "Scripts/DefaultVBScript"]);
+}
+
+#[test]
+fn test_parse_error_reports_stream_and_offset() {
+    use hwpers::parser::doc_info::DocInfoParser;
+    use hwpers::HwpError;
+
+    // An ignored record followed by a FaceName record with no payload
+    let mut stream = record(0x1E, 0, &[0; 4]);
+    stream.extend(record(0x13, 0, &[]));
+
+    let err = DocInfoParser::parse(stream, false).unwrap_err();
+    assert!(err.to_string().contains("'DocInfo'"), "got: {err}");
+    match err {
+        HwpError::StreamParse { stream, offset, .. } => {
+            assert_eq!(stream, "DocInfo");
+            assert_eq!(offset, 8);
+        }
+        other => panic!("expected StreamParse, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_paragraph_text_has_no_control_characters() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("First paragraph").unwrap();
+    writer.add_paragraph("Tab\tseparated").unwrap();
+    writer
+        .add_simple_table(&[vec!["A", "B"], vec!["C", "D"]])
+        .unwrap();
+    writer.add_paragraph("Last paragraph").unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let texts: Vec<String> = document.paragraphs().map(|p| p.text()).collect();
+
+    assert!(texts.contains(&"First paragraph".to_string()));
+    assert!(texts.contains(&"Tab\tseparated".to_string()));
+    for text in &texts {
+        assert!(
+            text.chars().all(|c| c >= '\u{20}' || c == '\t'),
+            "control character in {text:?}"
+        );
+    }
+}
+
+/// Copy every stream of an HWP file into a fresh compound file of `version`,
+/// renaming stream paths with `rename`
+fn rebuild_cfb(bytes: Vec<u8>, version: cfb::Version, rename: impl Fn(&str) -> String) -> Vec<u8> {
+    use std::io::{Cursor, Read, Write};
+
+    let mut source = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+    let streams: Vec<_> = source
+        .walk()
+        .filter(|entry| entry.is_stream())
+        .map(|entry| entry.path().to_path_buf())
+        .collect();
+
+    let mut target =
+        cfb::CompoundFile::create_with_version(version, Cursor::new(Vec::new())).unwrap();
+    for path in streams {
+        let mut data = Vec::new();
+        source
+            .open_stream(&path)
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+
+        let name = rename(&path.to_string_lossy());
+        if let Some((storage, _)) = name.rsplit_once('/') {
+            if !storage.is_empty() && !target.exists(storage) {
+                target.create_storage_all(storage).unwrap();
+            }
+        }
+        target
+            .create_stream(&name)
+            .unwrap()
+            .write_all(&data)
+            .unwrap();
+    }
+    target.into_inner().into_inner()
+}
+
+#[test]
+fn test_sections_found_under_alternate_storage() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Stored under ViewText").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    // Rebuild the file with the section streams moved from BodyText to ViewText
+    let renamed = rebuild_cfb(bytes, cfb::Version::V3, |name| {
+        name.replace("BodyText", "ViewText")
+    });
+
+    let document = HwpReader::from_bytes(&renamed).unwrap();
+    assert!(document.extract_text().contains("Stored under ViewText"));
+}
+
+#[test]
+fn test_4096_byte_sectors() {
+    use hwpers::reader::CfbReader;
+    use std::io::Cursor;
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Version 4 compound file").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+    assert_eq!(
+        CfbReader::new(Cursor::new(bytes.clone()))
+            .unwrap()
+            .sector_size(),
+        512
+    );
+
+    let v4 = rebuild_cfb(bytes, cfb::Version::V4, str::to_string);
+    assert_eq!(
+        CfbReader::new(Cursor::new(v4.clone()))
+            .unwrap()
+            .sector_size(),
+        4096
+    );
+
+    let document = HwpReader::from_bytes(&v4).unwrap();
+    assert!(document.extract_text().contains("Version 4 compound file"));
+}
+
+#[test]
+fn test_unsupported_sector_shift_rejected() {
+    use hwpers::reader::CfbReader;
+    use std::io::Cursor;
+
+    let mut bytes = HwpWriter::new().to_bytes().unwrap();
+    bytes[30] = 10;
+    let err = CfbReader::new(Cursor::new(bytes)).err().unwrap();
+    assert!(err.to_string().contains("sector shift 10"));
+}
+
+#[test]
+fn test_extract_text_matches_per_paragraph_text() {
+    let mut writer = HwpWriter::new();
+    for i in 0..200 {
+        writer
+            .add_paragraph(&format!("문단 {i}: 반복되는 본문 텍스트\t끝"))
+            .unwrap();
+    }
+    writer.add_paragraph("").unwrap();
+    writer.add_paragraph("Last line").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    // Output of the extraction before the buffer was reserved up front
+    let expected = include_str!("fixtures/extract_text_paragraphs.txt");
+    assert_eq!(document.extract_text(), expected);
+    let per_paragraph: String = document
+        .paragraphs()
+        .filter(|para| para.text.is_some())
+        .map(|para| para.text() + "\n")
+        .collect();
+    assert_eq!(per_paragraph, expected);
+
+    let mut written = Vec::new();
+    document.write_text_to(&mut written).unwrap();
+    assert_eq!(String::from_utf8(written).unwrap(), expected);
+}
+
+#[test]
+fn test_extract_text_borrows_a_single_plain_paragraph() {
+    use std::borrow::Cow;
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Only line\n").unwrap();
+    let text = writer.document().extract_text();
+    assert!(matches!(text, Cow::Borrowed("Only line\n")));
+
+    writer.add_paragraph("Second line").unwrap();
+    let text = writer.document().extract_text();
+    assert!(matches!(text, Cow::Owned(_)));
+    assert_eq!(text, "Only line\nSecond line\n");
+}
+
+#[test]
+fn test_extraction_options_toggle_output() {
+    use hwpers::model::{ExtractionOptions, Footnote};
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("본문 첫 줄").unwrap();
+    writer.add_paragraph("본문 끝").unwrap();
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    document.body_texts[0].sections[0].paragraphs[0]
+        .footnotes
+        .push(Footnote {
+            number: Some(1),
+            text: "각주 내용".to_string(),
+        });
+
+    let defaults = document.extract_text_with(&ExtractionOptions::default());
+    assert_eq!(defaults, document.extract_text());
+    assert_eq!(defaults, "본문 첫 줄\n본문 끝\n");
+
+    let options = ExtractionOptions::new()
+        .include_footnotes(true)
+        .paragraph_separator(" | ");
+    assert_eq!(
+        document.extract_text_with(&options),
+        "본문 첫 줄 | 각주 내용 | 본문 끝 | "
+    );
+}
+
+#[test]
+fn test_extraction_report_plain_document() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("First").unwrap();
+    writer.add_paragraph("Second").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let report = HwpReader::from_bytes(&bytes).unwrap().extraction_report();
+    assert_eq!(report.paragraphs, 2);
+    assert_eq!(report.tables, 0);
+    assert_eq!(report.images, 0);
+    assert_eq!(report.unhandled_controls, 0);
+    assert!(!report.encrypted_content);
+}
+
+#[test]
+fn test_compressed_plain_document_has_no_encrypted_content() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Nothing hidden").unwrap();
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    document.header.set_compressed(true);
+
+    let bytes = HwpWriter::from_document(document).to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.undecrypted_streams.is_empty());
+    assert!(!document.extraction_report().encrypted_content);
+}
+
+#[test]
+fn test_extraction_report_counts_skipped_controls() {
+    use hwpers::parser::body_text::BodyTextParser;
+
+    let mut stream = section_definition();
+    stream.extend(record(0x42, 0, &[0; 24]));
+    for ctrl_id in [b"cold", b"%clk", b"eqed"] {
+        stream.extend(record(0x47, 1, &u32::from_be_bytes(*ctrl_id).to_le_bytes()));
+    }
+
+    let body_text = BodyTextParser::parse(stream, false).unwrap();
+    assert_eq!(body_text.unhandled_controls(), 2);
+}
+
+#[test]
+fn test_reader_options_skip_previews_and_summary() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Preview text source").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    let full = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(full.preview_text().is_some());
+
+    let options = ReaderOptions::new()
+        .load_previews(false)
+        .load_summary(false);
+    let document = HwpReader::from_bytes_with_options(&bytes, &options).unwrap();
+    assert!(document.preview_text().is_none());
+    assert!(document.preview_image().is_none());
+    assert!(document.summary_info().is_none());
+    assert_eq!(document.extract_text(), full.extract_text());
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let path = temp_dir.path().join("no_previews.hwp");
+    std::fs::write(&path, &bytes).unwrap();
+    let from_file = HwpReader::from_file_with_options(&path, &options).unwrap();
+    assert!(from_file.preview_text().is_none());
+}
+
+/// Rewrite the content of one stream in place
+fn patch_stream(bytes: Vec<u8>, path: &str, patch: impl FnOnce(&mut Vec<u8>)) -> Vec<u8> {
+    use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+    let mut file = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+    let mut data = Vec::new();
+    let mut stream = file.open_stream(path).unwrap();
+    stream.read_to_end(&mut data).unwrap();
+    patch(&mut data);
+    stream.set_len(0).unwrap();
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    stream.write_all(&data).unwrap();
+    drop(stream);
+    file.flush().unwrap();
+    file.into_inner().into_inner()
+}
+
+fn flag_compressed(bytes: Vec<u8>) -> Vec<u8> {
+    patch_stream(bytes, "FileHeader", |header| header[36] |= 0x01)
+}
+
+#[test]
+fn test_raw_streams_flagged_compressed_fall_back() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Stored raw despite the flag").unwrap();
+    let bytes = flag_compressed(writer.to_bytes().unwrap());
+
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.header.is_compressed());
+    assert!(document
+        .extract_text()
+        .contains("Stored raw despite the flag"));
+}
+
+#[test]
+fn test_unpaired_surrogate_replaced() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("앞X뒤 이어지는 글 😀").unwrap();
+    let bytes = patch_stream(writer.to_bytes().unwrap(), "/BodyText/Section0", |data| {
+        let marker = utf16("앞X뒤");
+        let at = data
+            .windows(marker.len())
+            .position(|window| window == marker.as_slice())
+            .unwrap();
+        data[at + 2..at + 4].copy_from_slice(&0xD800u16.to_le_bytes());
+    });
+
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert_eq!(document.extract_text(), "앞\u{FFFD}뒤 이어지는 글 😀\n");
+}
+
+#[test]
+fn test_corrupt_compressed_stream_reported() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Corrupt").unwrap();
+    let bytes = flag_compressed(writer.to_bytes().unwrap());
+
+    let corrupt_doc_info = patch_stream(bytes.clone(), "DocInfo", |data| *data = vec![0xFF; 64]);
+    let message = HwpReader::from_bytes(&corrupt_doc_info)
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("'DocInfo'"), "{message}");
+    assert!(
+        message.contains("compressed stream is corrupt or not deflate-encoded"),
+        "{message}"
+    );
+
+    let corrupt_section = patch_stream(bytes, "BodyText/Section0", |data| *data = vec![0xFF; 64]);
+    let message = HwpReader::from_bytes(&corrupt_section)
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("'BodyText/Section0'"), "{message}");
+}
+
+#[test]
+fn test_document_history() {
+    use std::io::{Cursor, Write};
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Revised").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+    assert!(HwpReader::from_bytes(&bytes).unwrap().history().is_empty());
+
+    // 2024-03-05 14:30:00.000 as SYSTEMTIME
+    let date: Vec<u8> = [2024u16, 3, 2, 5, 14, 30, 0, 0]
+        .iter()
+        .flat_map(|field| field.to_le_bytes())
+        .collect();
+    let mut log = Vec::new();
+    log.extend(record(0x10, 0, &[]));
+    log.extend(record(0x12, 0, &date));
+    log.extend(record(0x13, 0, &utf16("홍길동")));
+    log.extend(record(0x14, 0, &utf16("First draft")));
+    log.extend(record(0x17, 0, &[]));
+    log.extend(record(0x10, 0, &[]));
+    log.extend(record(0x13, 0, &utf16("Reviewer")));
+    log.extend(record(0x17, 0, &[]));
+
+    let mut file = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+    file.create_storage("DocHistory").unwrap();
+    file.create_stream("DocHistory/VersionLog0")
+        .unwrap()
+        .write_all(&log)
+        .unwrap();
+    file.flush().unwrap();
+    let bytes = file.into_inner().into_inner();
+
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let history = document.history();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].author, "홍길동");
+    assert_eq!(history[0].comment, "First draft");
+    assert_eq!(
+        history[0].timestamp.unwrap().to_rfc3339(),
+        "2024-03-05T14:30:00+00:00"
+    );
+    assert_eq!(history[1].author, "Reviewer");
+    assert_eq!(history[1].timestamp, None);
+}
+
+#[test]
+fn test_record_reader_walks_doc_info() {
+    use hwpers::parser::RecordReader;
+    use std::io::{Cursor, Read};
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Records").unwrap();
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let mut file = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+    let mut data = Vec::new();
+    file.open_stream("DocInfo")
+        .unwrap()
+        .read_to_end(&mut data)
+        .unwrap();
+    let stream_len = data.len();
+
+    let records: Vec<_> = RecordReader::new(data).collect();
+    // DOCUMENT_PROPERTIES comes first, at the top level
+    assert_eq!(records[0].tag_id(), 0x10);
+    assert_eq!(records[0].level(), 0);
+    assert_eq!(
+        records.iter().filter(|r| r.tag_id() == 0x15).count(),
+        document.doc_info.char_shapes.len()
+    );
+
+    // Every byte of the stream belongs to a record
+    let consumed: usize = records
+        .iter()
+        .map(|r| if r.size() >= 0xFFF { 8 } else { 4 } + r.size() as usize)
+        .sum();
+    assert_eq!(consumed, stream_len);
+}
+
+#[test]
+fn test_record_larger_than_stream_rejected() {
+    use hwpers::parser::doc_info::DocInfoParser;
+    use hwpers::parser::RecordReader;
+    use hwpers::HwpError;
+
+    // A valid empty record, then one claiming 1 MiB with only 4 bytes of data
+    let mut stream = 0x1Eu32.to_le_bytes().to_vec();
+    stream.extend((0x1Eu32 | (0xFFF << 20)).to_le_bytes());
+    stream.extend((1024u32 * 1024).to_le_bytes());
+    stream.extend([0; 4]);
+
+    let mut records = RecordReader::new(stream.clone());
+    assert_eq!(records.by_ref().count(), 1);
+    assert_eq!(records.position(), 4);
+    let err = records.take_error().unwrap();
+    assert!(
+        err.to_string().contains("exceeds remaining data"),
+        "got: {err}"
+    );
+
+    match DocInfoParser::parse(stream, false).unwrap_err() {
+        HwpError::StreamParse { stream, offset, .. } => {
+            assert_eq!(stream, "DocInfo");
+            assert_eq!(offset, 4);
+        }
+        other => panic!("expected StreamParse, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_max_record_size_limit() {
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph("A paragraph long enough to exceed a tiny record cap")
+        .unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    let options = ReaderOptions::new().max_record_size(16);
+    let err = HwpReader::from_bytes_with_options(&bytes, &options).unwrap_err();
+    assert!(
+        err.to_string().contains("exceeds the limit of 16 bytes"),
+        "got: {err}"
+    );
+
+    let options = ReaderOptions::new().max_record_size(1024 * 1024);
+    assert!(HwpReader::from_bytes_with_options(&bytes, &options).is_ok());
+}
+
+#[test]
+fn test_from_file_timed_reports_metrics() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let path = temp_dir.path().join("timed.hwp");
+
+    let mut writer = HwpWriter::new();
+    for i in 0..200 {
+        writer
+            .add_paragraph(&format!("Timed paragraph {i}"))
+            .unwrap();
+    }
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    document.header.set_compressed(true);
+    HwpWriter::from_document(document)
+        .save_to_file(&path)
+        .unwrap();
+
+    let (document, metrics) = HwpReader::from_file_timed(&path).unwrap();
+    assert!(document.extract_text().contains("Timed paragraph 199"));
+
+    let streams: Vec<_> = metrics.streams.iter().map(|s| s.stream.as_str()).collect();
+    assert_eq!(streams, ["DocInfo", "BodyText/Section0"]);
+    assert!(metrics.decompression() > std::time::Duration::ZERO);
+    assert!(metrics.parsing() > std::time::Duration::ZERO);
+    assert!(metrics.total >= metrics.decompression() + metrics.parsing());
+}
+
+/// Build a 260-byte distribution record whose key is `key`
+fn distribution_record(key: [u8; 16]) -> Vec<u8> {
+    // The body is XORed with values from the MSVC rand() sequence seeded by
+    // its first 4 bytes; a seed starting with 0 puts the key at offset 4
+    let mut body = [0u8; 256];
+    body[0..4].copy_from_slice(&0x1234_5600u32.to_le_bytes());
+    body[4..20].copy_from_slice(&key);
+
+    let mut seed = 0x1234_5600u32;
+    let mut rand = || {
+        seed = seed.wrapping_mul(214013).wrapping_add(2531011);
+        (seed >> 16) & 0x7FFF
+    };
+    let (mut value, mut count) = (0u8, 0u32);
+    for (i, byte) in body.iter_mut().enumerate() {
+        if count == 0 {
+            value = rand() as u8;
+            count = (rand() & 0x0F) + 1;
+        }
+        if i >= 4 {
+            *byte ^= value;
+        }
+        count -= 1;
+    }
+
+    let mut record = (0x1Cu32 | (256 << 20)).to_le_bytes().to_vec();
+    record.extend_from_slice(&body);
+    record
+}
+
+/// Encrypt a record stream as a distribution document stores it
+fn encrypt_stream(data: &mut Vec<u8>, record: &[u8], key: [u8; 16]) {
+    use aes::cipher::{BlockEncrypt, KeyInit};
+
+    // Zero-length records of tag 0 pad the stream to whole AES blocks
+    data.resize(data.len().div_ceil(16) * 16, 0);
+    let cipher = aes::Aes128::new_from_slice(&key).unwrap();
+    for block in data.chunks_exact_mut(16) {
+        cipher.encrypt_block(aes::Block::from_mut_slice(block));
+    }
+    data.splice(0..0, record.iter().copied());
+}
+
+#[test]
+fn test_read_only_document_round_trip() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Do not edit").unwrap();
+    let plain = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert!(!plain.is_read_only());
+
+    writer.set_read_only(true);
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.is_read_only());
+    assert!(!document.is_distribution_document());
+    assert_eq!(document.extract_text(), "Do not edit\n");
+
+    // The flags word follows the 26 documented bytes of DOCUMENT_PROPERTIES
+    let doc_info = common::stream_records(bytes, "DocInfo");
+    assert_eq!(doc_info[0].tag_id(), 0x10);
+    assert_eq!(doc_info[0].data[26..], 0x02u32.to_le_bytes());
+
+    writer.set_read_only(false);
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert!(!document.is_read_only());
+}
+
+#[test]
+fn test_distribution_sections_report_view_text() {
+    use hwpers::parser::body_text::SectionSource;
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Distributed text").unwrap();
+    let plain = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(plain.body_texts[0].source, SectionSource::BodyText);
+
+    let key = *b"0123456789abcdef";
+    let record = distribution_record(key);
+    let bytes = rebuild_cfb(writer.to_bytes().unwrap(), cfb::Version::V3, |name| {
+        name.replace("BodyText", "ViewText")
+    });
+    let bytes = patch_stream(bytes, "FileHeader", |header| header[36] |= 0x04);
+    let bytes = patch_stream(bytes, "DocInfo", |data| encrypt_stream(data, &record, key));
+    let bytes = patch_stream(bytes, "ViewText/Section0", |data| {
+        encrypt_stream(data, &record, key)
+    });
+
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.is_distribution_document());
+    assert!(document.undecrypted_streams.is_empty());
+    assert!(document.extract_text().contains("Distributed text"));
+    assert!(document
+        .body_texts
+        .iter()
+        .all(|body| body.source == SectionSource::ViewText));
+}
+
+#[test]
+fn test_zero_padded_file_header() {
+    use hwpers::parser::FileHeader;
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Padded header").unwrap();
+    let bytes = patch_stream(writer.to_bytes().unwrap(), "FileHeader", |header| {
+        header.resize(512, 0)
+    });
+
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.extract_text().contains("Padded header"));
+
+    let mut padded = document.header.to_bytes();
+    padded.resize(512, 0);
+    let header = FileHeader::parse(padded).unwrap();
+    assert_eq!(header.flags, document.header.flags);
+
+    let err = FileHeader::parse(vec![0; 100]).unwrap_err();
+    assert!(err.to_string().contains("at least 256 bytes"), "got: {err}");
+}
+
+#[test]
+fn test_has_text() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    let empty_path = temp_dir.path().join("empty.hwp");
+    HwpWriter::new().save_to_file(&empty_path).unwrap();
+    assert!(!HwpReader::has_text(&empty_path, 1).unwrap());
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("   ").unwrap();
+    writer.add_paragraph("스캔 문서 본문").unwrap();
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    document.header.set_compressed(true);
+    let text_path = temp_dir.path().join("text.hwp");
+    HwpWriter::from_document(document)
+        .save_to_file(&text_path)
+        .unwrap();
+
+    assert!(HwpReader::has_text(&text_path, 1).unwrap());
+    assert!(HwpReader::has_text(&text_path, 6).unwrap());
+    assert!(!HwpReader::has_text(&text_path, 7).unwrap());
+}
+
+#[test]
+fn test_xml_template() {
+    use std::io::{Cursor, Write};
+
+    let schema = r#"<?xml version="1.0"?><xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"/>"#;
+    let mut stream = (schema.encode_utf16().count() as u32)
+        .to_le_bytes()
+        .to_vec();
+    stream.extend(utf16(schema));
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Form").unwrap();
+    let mut file = cfb::CompoundFile::open(Cursor::new(writer.to_bytes().unwrap())).unwrap();
+    file.create_storage("XMLTemplate").unwrap();
+    file.create_stream("XMLTemplate/Schema")
+        .unwrap()
+        .write_all(&stream)
+        .unwrap();
+    file.flush().unwrap();
+    let unflagged = file.into_inner().into_inner();
+
+    // Without the header flag the storage is ignored
+    let document = HwpReader::from_bytes(&unflagged).unwrap();
+    assert_eq!(document.xml_template(), None);
+
+    let flagged = patch_stream(unflagged, "FileHeader", |header| header[36] |= 0x20);
+    let document = HwpReader::from_bytes(&flagged).unwrap();
+    assert!(document.header.is_xml_template());
+    assert_eq!(document.xml_template().as_deref(), Some(schema));
+}
+
+#[test]
+fn test_set_metadata_roundtrip() {
+    use chrono::TimeZone;
+    use hwpers::DocumentMetadata;
+
+    let created = chrono::Utc.with_ymd_and_hms(2024, 3, 5, 9, 0, 0).unwrap();
+    let metadata = DocumentMetadata {
+        title: Some("사업 계획서".to_string()),
+        author: Some("홍길동".to_string()),
+        created: Some(created),
+        page_count: Some(2),
+        word_count: Some(40),
+        ..Default::default()
+    };
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Metadata").unwrap();
+    writer.set_metadata(&metadata);
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.metadata(), Some(metadata));
+    assert_eq!(
+        document.metadata().and_then(|m| m.statistics()),
+        Some(hwpers::DocStatistics {
+            pages: Some(2),
+            words: Some(40),
+            characters: None,
+        })
+    );
+    assert_eq!(document.title(), Some("사업 계획서"));
+    assert_eq!(document.author(), Some("홍길동"));
+    assert_eq!(document.subject(), None);
+    assert_eq!(document.creation_date(), Some(created));
+
+    let mut plain = HwpWriter::new();
+    plain.add_paragraph("No metadata").unwrap();
+    let document = HwpReader::from_bytes(&plain.to_bytes().unwrap()).unwrap();
+    assert!(document.summary_info().is_none());
+    assert!(document.metadata().is_none());
+}
+
+#[test]
+fn test_section_paragraph_counts() {
+    use std::io::{Cursor, Read, Write};
+
+    let mut writer = HwpWriter::new();
+    for text in ["One", "Two", "Three"] {
+        writer.add_paragraph(text).unwrap();
+    }
+    let bytes = writer.to_bytes().unwrap();
+    let single = HwpReader::from_bytes(&bytes).unwrap();
+    let counts = single.section_paragraph_counts();
+    assert_eq!(counts.len(), 1);
+    assert_eq!(counts.iter().sum::<usize>(), single.paragraph_count());
+    assert_eq!(single.paragraph_count(), single.paragraphs().count());
+
+    // Duplicate the section into a second stream
+    let mut file = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+    let mut section = Vec::new();
+    file.open_stream("BodyText/Section0")
+        .unwrap()
+        .read_to_end(&mut section)
+        .unwrap();
+    file.create_stream("BodyText/Section1")
+        .unwrap()
+        .write_all(&section)
+        .unwrap();
+    file.flush().unwrap();
+
+    let document = HwpReader::from_bytes(&file.into_inner().into_inner()).unwrap();
+    assert_eq!(document.section_paragraph_counts(), [counts[0], counts[0]]);
+    assert_eq!(document.paragraph_count(), 2 * single.paragraph_count());
+}
@@ -1,11 +1,12 @@
-use hwpers::{writer::style::ListType, HwpWriter};
+use hwpers::model::ListItem;
+use hwpers::{writer::style::ListType, HwpReader, HwpWriter};
 
 #[test]
 fn test_simple_bullet_list() {
     let mut writer = HwpWriter::new();
 
     writer
-        .add_list(&["Item 1", "Item 2", "Item 3"], ListType::Bullet)
+        .add_list_of_type(&["Item 1", "Item 2", "Item 3"], ListType::Bullet)
         .unwrap();
 
     let document = writer.document();
@@ -21,7 +22,7 @@ fn test_numbered_list() {
     let mut writer = HwpWriter::new();
 
     writer
-        .add_list(&["First", "Second", "Third"], ListType::Numbered)
+        .add_list_of_type(&["First", "Second", "Third"], ListType::Numbered)
         .unwrap();
 
     let document = writer.document();
@@ -38,7 +39,7 @@ fn test_alphabetic_list() {
     let mut writer = HwpWriter::new();
 
     writer
-        .add_list(&["Apple", "Banana", "Cherry"], ListType::Alphabetic)
+        .add_list_of_type(&["Apple", "Banana", "Cherry"], ListType::Alphabetic)
         .unwrap();
 
     let document = writer.document();
@@ -55,7 +56,7 @@ fn test_korean_list() {
     let mut writer = HwpWriter::new();
 
     writer
-        .add_list(&["첫째", "둘째", "셋째"], ListType::Korean)
+        .add_list_of_type(&["첫째", "둘째", "셋째"], ListType::Korean)
         .unwrap();
 
     let document = writer.document();
@@ -72,7 +73,7 @@ fn test_roman_numerals() {
     let mut writer = HwpWriter::new();
 
     writer
-        .add_list(
+        .add_list_of_type(
             &["Introduction", "Methods", "Results", "Discussion"],
             ListType::Roman,
         )
@@ -172,3 +173,34 @@ fn test_list_indentation() {
     assert_ne!(shape_ids[1], shape_ids[2]);
     assert_ne!(shape_ids[0], shape_ids[2]);
 }
+
+#[test]
+fn test_ordered_list_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Steps:").unwrap();
+    writer.add_list(&["Open", "Edit", "Save"], true, 1).unwrap();
+    writer.add_list(&["Note"], false, 0).unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let item = |text: &str, ordered, level| ListItem {
+        text: text.to_string(),
+        ordered,
+        level,
+    };
+    assert_eq!(
+        document.list_items(),
+        vec![
+            item("Open", true, 1),
+            item("Edit", true, 1),
+            item("Save", true, 1),
+            item("Note", false, 0),
+        ]
+    );
+    assert_eq!(document.doc_info.numberings.len(), 1);
+    assert_eq!(document.doc_info.bullets.len(), 1);
+
+    // Markers come from the numbering definition, not the text
+    assert!(document.extract_text().contains("Open\nEdit\nSave\n"));
+}
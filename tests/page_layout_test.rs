@@ -1,8 +1,10 @@
+mod common;
+
 use hwpers::{
     model::{
         inches_to_hwp_units, mm_to_hwp_units, PageLayout, PageMargins, PageOrientation, PaperSize,
     },
-    HwpWriter,
+    HwpReader, HwpWriter,
 };
 
 #[test]
@@ -379,3 +381,251 @@ fn test_mixed_document_with_page_layout() {
     assert_eq!(page_def.header_footer.headers().len(), 1);
     assert_eq!(page_def.header_footer.footers().len(), 1);
 }
+
+#[test]
+fn test_manuscript_grid_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.set_grid(20, 20).unwrap();
+    writer.add_paragraph("원고지 양식").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let settings = document.page_settings();
+    assert_eq!(settings.len(), 1);
+    assert!(settings[0].has_grid());
+    assert_eq!(settings[0].grid(), Some((20, 20)));
+    assert!(document.extract_text().contains("원고지 양식"));
+}
+
+#[test]
+fn test_grid_rejects_zero_size() {
+    let mut writer = HwpWriter::new();
+    assert!(writer.set_grid(0, 20).is_err());
+    assert!(!writer.document().page_settings()[0].has_grid());
+}
+
+#[test]
+fn test_line_grid_roundtrip() {
+    let mut writer = HwpWriter::new();
+    assert!(!writer.document().page_settings()[0].has_line_grid());
+    writer.set_line_grid(true, 8.0).unwrap();
+    writer.add_paragraph("격자에 맞춘 줄").unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let settings = &document.page_settings()[0];
+    assert!(settings.has_line_grid());
+    assert!(!settings.has_grid());
+    assert!((settings.line_grid_mm().unwrap() - 8.0).abs() < 0.01);
+
+    writer.set_line_grid(false, 0.0).unwrap();
+    assert_eq!(writer.document().page_settings()[0].line_grid_mm(), None);
+    assert!(writer.set_line_grid(true, -1.0).is_err());
+    assert!(writer.set_line_grid(true, 500.0).is_err());
+}
+
+#[test]
+fn test_distinct_page_setups() {
+    let read_back = |landscape: bool| {
+        let mut writer = HwpWriter::new();
+        if landscape {
+            writer.set_a4_landscape().unwrap();
+        }
+        writer.add_paragraph("구역").unwrap();
+        HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap()
+    };
+
+    let mut document = read_back(false);
+    document.body_texts.extend(read_back(false).body_texts);
+    assert_eq!(document.page_settings().len(), 2);
+    assert_eq!(document.page_settings()[0], document.page_settings()[1]);
+    assert_eq!(document.distinct_page_setups().len(), 1);
+
+    document.body_texts.extend(read_back(true).body_texts);
+    let setups = document.distinct_page_setups();
+    assert_eq!(setups.len(), 2);
+    assert!(!setups[0].is_landscape());
+    assert!(setups[1].is_landscape());
+}
+
+#[test]
+fn test_default_tab_width_roundtrip() {
+    let mut writer = HwpWriter::new();
+    assert_eq!(
+        writer.document().page_settings()[0].default_tab_width_mm(),
+        None
+    );
+    writer.set_default_tab_width(10.0).unwrap();
+    writer.add_paragraph("탭\t간격").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let settings = document.page_settings();
+    assert_eq!(settings[0].default_tab_stop, mm_to_hwp_units(10.0));
+    let width = settings[0].default_tab_width_mm().unwrap();
+    assert!((width - 10.0).abs() < 0.01, "got {width}mm");
+
+    assert!(writer.set_default_tab_width(0.0).is_err());
+}
+
+#[test]
+fn test_two_column_section_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.set_columns(2, 8.0);
+    writer.add_paragraph("신문 2단 편집").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let section = document.sections().next().unwrap();
+    assert_eq!(section.column_count(), 2);
+    assert_eq!(
+        section.column_def.as_ref().unwrap().gap as u32,
+        mm_to_hwp_units(8.0)
+    );
+    assert!(document.extract_text().contains("신문 2단 편집"));
+}
+
+#[test]
+fn test_single_column_by_default() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("1단").unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.sections().next().unwrap().column_count(), 1);
+}
+
+#[test]
+fn test_line_numbering_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.set_line_numbering(1, 5).unwrap();
+    writer.add_paragraph("제1조 목적").unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let numbering = document
+        .sections()
+        .next()
+        .unwrap()
+        .line_numbering()
+        .copied()
+        .expect("line numbering should be enabled");
+    assert_eq!(numbering.start, 1);
+    assert_eq!(numbering.interval, 5);
+
+    assert!(writer.set_line_numbering(1, 0).is_err());
+
+    let mut plain = HwpWriter::new();
+    plain.add_paragraph("번호 없음").unwrap();
+    let document = HwpReader::from_bytes(&plain.to_bytes().unwrap()).unwrap();
+    assert!(document
+        .sections()
+        .next()
+        .unwrap()
+        .line_numbering()
+        .is_none());
+}
+
+#[test]
+fn test_line_numbering_section_def_layout() {
+    use hwpers::model::LineNumbering;
+
+    // SECTION_DEF control as Hangul writes it: the 26 documented bytes, then
+    // restartType 1, countBy 5, distance 1417 and startNumber 1
+    let secd: [u8; 38] = [
+        0x64, 0x63, 0x65, 0x73, // 'secd'
+        0x00, 0x00, 0x00, 0x00, // properties
+        0x6E, 0x04, 0x00, 0x00, 0x00, 0x00, // column gap, grids
+        0x40, 0x1F, 0x00, 0x00, // default tab stop
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // numbering ids
+        0x12, 0x04, // language
+        0x01, 0x00, 0x05, 0x00, 0x89, 0x05, 0x01, 0x00, // line numbering
+    ];
+    let mut section = common::section_definition();
+    section.extend(common::record(0x47, 1, &secd));
+
+    let mut writer = HwpWriter::new();
+    writer.add_raw_section(&section).unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(
+        document.body_texts[1].sections[0].line_numbering(),
+        Some(&LineNumbering {
+            start: 1,
+            interval: 5,
+            distance: 1417,
+            restart_type: 1,
+        })
+    );
+
+    // Writing the parsed section back reproduces the control byte for byte
+    let bytes = HwpWriter::from_document(document).to_bytes().unwrap();
+    let written = common::stream_records(bytes, "BodyText/Section1")
+        .into_iter()
+        .find(|record| record.tag_id() == 0x47 && record.data.starts_with(b"dces"))
+        .expect("section definition control");
+    assert_eq!(written.data, secd);
+}
+
+#[test]
+fn test_set_page_landscape_a3_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.set_page(297.0, 420.0, true).unwrap();
+    writer.add_paragraph("A3 가로").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let settings = document.page_settings();
+    assert_eq!(settings[0].width, mm_to_hwp_units(420.0));
+    assert_eq!(settings[0].height, mm_to_hwp_units(297.0));
+    assert!(settings[0].is_landscape());
+
+    let page_def = document
+        .sections()
+        .next()
+        .unwrap()
+        .page_def
+        .as_ref()
+        .unwrap();
+    assert_eq!(page_def.properties & 0x01, 1);
+
+    // Portrait keeps the given dimensions
+    let mut writer = HwpWriter::new();
+    writer.set_page(182.0, 257.0, false).unwrap();
+    let bytes = writer.to_bytes().unwrap();
+    let settings = HwpReader::from_bytes(&bytes).unwrap().page_settings();
+    assert_eq!(settings[0].width, mm_to_hwp_units(182.0));
+    assert!(!settings[0].is_landscape());
+}
+
+#[test]
+fn test_page_background_color_is_detected() {
+    use hwpers::model::border_fill::BorderFill;
+    use hwpers::model::{Color, PageBackground};
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Official notice").unwrap();
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.page_settings()[0].background, None);
+
+    // Fixture: point the page border fill at a solid light yellow fill
+    let mut border_fill = BorderFill::new_default();
+    border_fill.fill_info.fill_type = 1;
+    border_fill.fill_info.back_color = 0xFFF2CC;
+    document.doc_info.border_fills.push(border_fill);
+    let border_fill_id = document.doc_info.border_fills.len() as u16;
+    let section = &mut document.body_texts[0].sections[0];
+    assert_eq!(section.page_border_fills.len(), 3);
+    section.page_border_fills[0].border_fill_id = border_fill_id;
+
+    let bytes = HwpWriter::from_document(document).to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        document.page_settings()[0].background,
+        Some(PageBackground::Color(Color::Rgb(0xFF, 0xF2, 0xCC)))
+    );
+    assert!(
+        hwpers::render::to_html(&document).contains("<body style=\"background-color: #fff2cc\">")
+    );
+    assert_eq!(document.extract_text().trim_end(), "Official notice");
+}
@@ -1,5 +1,9 @@
 use hwpers::writer::style::TextStyle;
-use hwpers::{HwpReader, HwpWriter};
+use hwpers::{
+    chunk_text_with_headings, deduplicate_paragraphs, extract_text_for_rag,
+    extract_text_for_rag_with, extract_under_heading, looks_like_mojibake, normalize_text,
+    normalize_text_with, HwpReader, HwpWriter, NormalizeOptions, RagOptions, ShortPolicy,
+};
 use tempfile::TempDir;
 
 #[test]
@@ -150,3 +154,258 @@ fn test_hwp_styled_text_extraction() {
         "Styled text content not preserved"
     );
 }
+
+#[test]
+fn test_normalize_preserves_line_breaks() {
+    let input = "fn main() {\n\n    let x = 1;   \n\n    println!(\"{}\", x);\n\n}";
+    let options = NormalizeOptions {
+        preserve_line_breaks: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        normalize_text_with(input, &options),
+        "fn main() {\n\n    let x = 1;\n\n    println!(\"{}\", x);\n\n}"
+    );
+    assert_eq!(
+        normalize_text(input),
+        "fn main() {\nlet x = 1;\nprintln!(\"{}\", x);\n}"
+    );
+}
+
+#[test]
+fn test_normalize_folds_fullwidth() {
+    let options = NormalizeOptions {
+        fold_fullwidth: true,
+        ..Default::default()
+    };
+    assert_eq!(normalize_text_with("번호 １２３", &options), "번호 123");
+    assert_eq!(
+        normalize_text_with("ＡＢＣ－ｘｙｚ！", &options),
+        "ABC-xyz!"
+    );
+    assert_eq!(normalize_text("１２３"), "１２３");
+}
+
+#[test]
+fn test_deduplicate_repeated_paragraphs() {
+    let text =
+        "본 문서는 대외비입니다.\n1분기 매출 보고\n본 문서는  대외비입니다.\n2분기 매출 보고";
+
+    assert_eq!(
+        deduplicate_paragraphs(text, 1.0),
+        "본 문서는 대외비입니다.\n1분기 매출 보고\n2분기 매출 보고"
+    );
+    assert_eq!(
+        deduplicate_paragraphs(text, 0.5),
+        "본 문서는 대외비입니다.\n1분기 매출 보고"
+    );
+}
+
+#[test]
+fn test_short_text_policies() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("short.hwp");
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("열 글자 짧은 문서").unwrap();
+    writer.save_to_file(&file_path).unwrap();
+    let path = file_path.to_str().unwrap();
+
+    assert!(extract_text_for_rag(path).is_err());
+
+    let policy = |on_short| extract_text_for_rag_with(path, &RagOptions::new().on_short(on_short));
+    assert!(policy(ShortPolicy::Error).is_err());
+    assert_eq!(
+        policy(ShortPolicy::ReturnAnyway).unwrap(),
+        "열 글자 짧은 문서"
+    );
+    assert_eq!(policy(ShortPolicy::Empty).unwrap(), "");
+}
+
+#[test]
+fn test_looks_like_mojibake() {
+    assert!(!looks_like_mojibake(
+        "대한민국 헌법 제1조 ① 대한민국은 민주공화국이다."
+    ));
+    assert!(!looks_like_mojibake(
+        "Crème brûlée au café, s'il vous plaît"
+    ));
+    assert!(!looks_like_mojibake(""));
+
+    // EUC-KR "한글 문서" read as Latin-1, and text full of replacement characters
+    assert!(looks_like_mojibake("ÇÑ±Û ¹®¼\u{AD}"));
+    assert!(looks_like_mojibake(
+        "보고서 \u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD} 결과"
+    ));
+}
+
+#[test]
+fn test_rag_rejects_mojibake_when_asked() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("garbled.hwp");
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("ÇÑ±Û ¹®¼\u{AD} ÀÔ´Ï´Ù").unwrap();
+    writer.save_to_file(&file_path).unwrap();
+    let path = file_path.to_str().unwrap();
+
+    let lenient = RagOptions::new().min_chars(0);
+    assert!(extract_text_for_rag_with(path, &lenient).is_ok());
+    assert!(extract_text_for_rag_with(path, &lenient.reject_mojibake(true)).is_err());
+}
+
+#[test]
+fn test_chunks_carry_heading_path() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("머리말 문단").unwrap();
+    writer.add_heading("제1장 총칙", 1).unwrap();
+    writer
+        .add_paragraph("이 규정은 문서 관리에 관한 사항을 정한다.")
+        .unwrap();
+    writer.add_heading("1.1 범위", 2).unwrap();
+    writer.add_paragraph("모든 부서에 적용한다.").unwrap();
+    writer.add_heading("제2장 보칙", 1).unwrap();
+    writer.add_paragraph("시행일은 공포한 날로 한다.").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let doc = HwpReader::from_bytes(&bytes).unwrap();
+    assert_eq!(doc.outline().len(), 3);
+
+    let chunks = chunk_text_with_headings(&doc, 200);
+    assert_eq!(
+        chunks,
+        vec![
+            "머리말 문단",
+            "제1장 총칙\n이 규정은 문서 관리에 관한 사항을 정한다.",
+            "제1장 총칙 > 1.1 범위\n모든 부서에 적용한다.",
+            "제2장 보칙\n시행일은 공포한 날로 한다.",
+        ]
+    );
+
+    // Long sections are split, and every piece keeps its heading
+    let chunks = chunk_text_with_headings(&doc, 10);
+    assert!(chunks
+        .iter()
+        .filter(|chunk| chunk.contains("규정") || chunk.contains("관리"))
+        .all(|chunk| chunk.starts_with("제1장 총칙\n")));
+}
+
+#[test]
+fn test_extract_under_heading() {
+    let mut writer = HwpWriter::new();
+    writer.add_heading("제1장 총칙", 1).unwrap();
+    writer
+        .add_paragraph("이 규정은 문서 관리에 관한 사항을 정한다.")
+        .unwrap();
+    writer.add_heading("제2장 문서의 작성", 1).unwrap();
+    writer.add_paragraph("문서는 한글로 작성한다.").unwrap();
+    writer.add_heading("2.1 서식", 2).unwrap();
+    writer.add_paragraph("서식은 별표에 따른다.").unwrap();
+    writer.add_heading("제3장 보칙", 1).unwrap();
+    writer.add_paragraph("시행일은 공포한 날로 한다.").unwrap();
+    let doc = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(
+        extract_under_heading(&doc, "제2장").as_deref(),
+        Some("제2장 문서의 작성\n문서는 한글로 작성한다.\n2.1 서식\n서식은 별표에 따른다.")
+    );
+    // A subsection ends at the next heading of its own level or higher
+    assert_eq!(
+        extract_under_heading(&doc, "2.1").as_deref(),
+        Some("2.1 서식\n서식은 별표에 따른다.")
+    );
+    assert_eq!(extract_under_heading(&doc, "제9장"), None);
+}
+
+#[test]
+fn test_ruby_handling_modes() {
+    use hwpers::model::{Ruby, RubyHandling};
+
+    let ruby = Ruby {
+        base: "漢字".to_string(),
+        ruby: "한자".to_string(),
+    };
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("ruby.hwp");
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph(&format!("{} 교육 자료", ruby.annotation()))
+        .unwrap();
+    writer.save_to_file(&file_path).unwrap();
+    let path = file_path.to_str().unwrap();
+
+    let extract = |handling| {
+        let options = RagOptions::new().min_chars(0).ruby_handling(handling);
+        extract_text_for_rag_with(path, &options).unwrap()
+    };
+    assert_eq!(extract(RubyHandling::Base), "漢字 교육 자료");
+    assert_eq!(extract(RubyHandling::Ruby), "한자 교육 자료");
+    assert_eq!(extract(RubyHandling::Both), "漢字(한자) 교육 자료");
+
+    // The annotation is stored as a `tdut` control, not as literal text
+    let doc = HwpReader::from_file(&file_path).unwrap();
+    assert_eq!(doc.extract_text().trim(), "漢字 교육 자료");
+    let para = doc.paragraphs().next().unwrap();
+    assert_ne!(para.control_mask & (1 << 0x17), 0);
+    assert_eq!(doc.extraction_report().unhandled_controls, 0);
+}
+
+#[test]
+fn test_strip_toc() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("manual.hwp");
+
+    let mut writer = HwpWriter::new();
+    for text in [
+        "제품 사용 설명서",
+        "목 차",
+        "1. 개요 ........ 3",
+        "2. 설치 ·········· 5",
+        "3. 문제 해결 12",
+        "1. 개요",
+        "이 설명서는 제품을 설치하고 사용하는 방법을 설명합니다. 2024년 판입니다.",
+        "부록 A 용어 정리 ..... 41",
+    ] {
+        writer.add_paragraph(text).unwrap();
+    }
+    writer.save_to_file(&file_path).unwrap();
+    let path = file_path.to_str().unwrap();
+
+    let options = RagOptions::new().min_chars(0);
+    let full = extract_text_for_rag_with(path, &options).unwrap();
+    assert!(full.contains("목 차"));
+    assert!(full.contains("2. 설치"));
+
+    let stripped = extract_text_for_rag_with(path, &options.strip_toc(true)).unwrap();
+    assert_eq!(
+        stripped,
+        "제품 사용 설명서\n1. 개요\n이 설명서는 제품을 설치하고 사용하는 방법을 설명합니다. 2024년 판입니다."
+    );
+}
+
+#[test]
+fn test_hwp_rag_without_tables() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("tables.hwp");
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Prose before the table").unwrap();
+    writer
+        .add_simple_table(&[vec!["Cell A1", "Cell B1"], vec!["Cell A2", "Cell B2"]])
+        .unwrap();
+    writer.add_paragraph("Prose after the table").unwrap();
+    writer.save_to_file(&file_path).unwrap();
+    let path = file_path.to_str().unwrap();
+
+    let options = RagOptions::new().min_chars(0);
+    let with_tables = extract_text_for_rag_with(path, &options).unwrap();
+    assert!(with_tables.contains("Cell A1"));
+    assert!(with_tables.contains("Cell B2"));
+
+    let prose = extract_text_for_rag_with(path, &options.include_tables(false)).unwrap();
+    assert!(!prose.contains("Cell"), "got: {prose}");
+    assert!(prose.contains("Prose before the table"));
+    assert!(prose.contains("Prose after the table"));
+}
@@ -107,3 +107,34 @@ fn test_hwpx_styled_text_roundtrip() {
     assert!(text.contains("Large text 큰 글씨"));
     assert!(text.contains("Normal text 일반 글씨"));
 }
+
+#[test]
+fn test_hwpx_rag_without_tables() {
+    use hwpers::hwpx::writer::HwpxTable;
+    use hwpers::{extract_text_for_rag_with, RagOptions};
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("tables.hwpx");
+
+    let mut writer = HwpxWriter::new();
+    writer.add_paragraph("Prose before the table").unwrap();
+    writer
+        .add_table(HwpxTable::from_data(vec![
+            vec!["Cell A1", "Cell B1"],
+            vec!["Cell A2", "Cell B2"],
+        ]))
+        .unwrap();
+    writer.add_paragraph("Prose after the table").unwrap();
+    writer.save_to_file(&file_path).unwrap();
+    let path = file_path.to_str().unwrap();
+
+    let options = RagOptions::new().min_chars(0);
+    let with_tables = extract_text_for_rag_with(path, &options).unwrap();
+    assert!(with_tables.contains("Cell A1"));
+    assert!(with_tables.contains("Cell B2"));
+
+    let prose = extract_text_for_rag_with(path, &options.include_tables(false)).unwrap();
+    assert!(!prose.contains("Cell"), "got: {prose}");
+    assert!(prose.contains("Prose before the table"));
+    assert!(prose.contains("Prose after the table"));
+}
@@ -64,7 +64,7 @@ fn test_read_existing_hwp_and_convert() {
     };
 
     println!("✓ Successfully read existing HWP file");
-    let original_text = document.extract_text();
+    let original_text = document.extract_text().into_owned();
     println!("Original text length: {} characters", original_text.len());
     let safe_preview = original_text.chars().take(30).collect::<String>(); // Use char count instead
     println!("Original text preview: {safe_preview:?}");
@@ -138,3 +138,61 @@ fn test_minimal_document_structure() {
         }
     }
 }
+
+#[test]
+fn test_text_diff() {
+    use hwpers::model::DiffOp;
+
+    let build = |paragraphs: &[&str]| {
+        let mut writer = HwpWriter::new();
+        for text in paragraphs {
+            writer.add_paragraph(text).unwrap();
+        }
+        HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap()
+    };
+    let original = build(&["제목", "첫 문단", "둘째 문단", "맺음말"]);
+
+    let same = original.text_diff(&original);
+    assert_eq!(same.len(), 4);
+    assert!(same.iter().all(|op| matches!(op, DiffOp::Equal(_))));
+
+    let modified = build(&["제목", "둘째 문단", "새 문단", "맺음말"]);
+    assert_eq!(
+        original.text_diff(&modified),
+        vec![
+            DiffOp::Equal("제목".to_string()),
+            DiffOp::Delete("첫 문단".to_string()),
+            DiffOp::Equal("둘째 문단".to_string()),
+            DiffOp::Insert("새 문단".to_string()),
+            DiffOp::Equal("맺음말".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_special_spaces_roundtrip() {
+    use std::io::{Cursor, Read};
+
+    let text = "Name:\u{00A0}Hong\u{2007}Gildong";
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph(text).unwrap();
+    let bytes = writer.to_bytes().unwrap();
+
+    // The spaces are written as HWP space controls, not as Unicode characters
+    let mut file = cfb::CompoundFile::open(Cursor::new(bytes.clone())).unwrap();
+    let mut section = Vec::new();
+    file.open_stream("BodyText/Section0")
+        .unwrap()
+        .read_to_end(&mut section)
+        .unwrap();
+    let units: Vec<u16> = section
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    assert!(!units.contains(&0x00A0));
+    assert!(units.windows(2).any(|w| w == [u16::from(b':'), 0x001E]));
+    assert!(units.windows(2).any(|w| w == [u16::from(b'g'), 0x001F]));
+
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert_eq!(document.extract_text(), format!("{text}\n"));
+}
@@ -1,4 +1,6 @@
-use hwpers::{writer::style::TextStyle, HwpReader, HwpWriter};
+use hwpers::model::Color;
+use hwpers::writer::style::{ParagraphStyle, TextStyle};
+use hwpers::{HwpReader, HwpWriter};
 
 #[test]
 fn test_text_style_creation() {
@@ -40,6 +42,34 @@ fn test_add_paragraph_with_style() {
     assert!(document.doc_info.char_shapes.len() > 1); // Default + our new one
 }
 
+#[test]
+fn test_identical_styles_share_shapes() {
+    let mut writer = HwpWriter::new();
+    let char_shapes_before = writer.document().doc_info.char_shapes.len();
+    let para_shapes_before = writer.document().doc_info.para_shapes.len();
+
+    let bold_style = TextStyle::new().bold();
+    for i in 0..100 {
+        writer
+            .add_paragraph_with_style(&format!("Bold {i}"), &bold_style)
+            .unwrap();
+    }
+    writer.add_heading("Heading", 1).unwrap();
+    writer.add_heading("Another heading", 1).unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.doc_info.char_shapes.len() <= char_shapes_before + 2);
+    assert!(document.doc_info.para_shapes.len() <= para_shapes_before + 1);
+
+    let paragraphs = &document.body_texts[0].sections[0].paragraphs;
+    let first = paragraphs[0].char_shapes.as_ref().unwrap().char_positions[0].char_shape_id;
+    let last = paragraphs[99].char_shapes.as_ref().unwrap().char_positions[0].char_shape_id;
+    assert_eq!(first, last);
+    assert!(document.doc_info.char_shapes[first as usize].is_bold());
+    assert_eq!(paragraphs[100].para_shape_id, paragraphs[101].para_shape_id);
+}
+
 #[test]
 fn test_add_heading() {
     let mut writer = HwpWriter::new();
@@ -147,3 +177,239 @@ fn test_font_size_in_style() {
     let large_style = TextStyle::new().size(24);
     assert_eq!(large_style.font_size, Some(24));
 }
+
+#[test]
+fn test_paragraph_shading_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Plain paragraph").unwrap();
+    writer
+        .add_paragraph_with_paragraph_style(
+            "Notice: shaded paragraph",
+            &ParagraphStyle::new().shading(0xD9D9D9),
+        )
+        .unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let styles = document.paragraph_styles();
+    assert_eq!(styles.len(), 2);
+    assert_eq!(styles[0].shading, None);
+    assert_eq!(styles[1].shading, Some(Color::Rgb(0xD9, 0xD9, 0xD9)));
+}
+
+#[test]
+fn test_paragraph_space_after_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_paragraph_style(
+            "Spaced paragraph",
+            &ParagraphStyle::new().space_after(5.0),
+        )
+        .unwrap();
+    writer.add_paragraph("Next paragraph").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let styles = document.paragraph_styles();
+    assert_eq!(styles[0].space_before, 0);
+    assert_eq!(styles[0].space_after, 1417); // 5mm in HWP units
+    assert_eq!(styles[0], ParagraphStyle::new().space_after(5.0));
+    assert_eq!(styles[1].space_after, 0);
+}
+
+#[test]
+fn test_keep_with_next_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_paragraph_style("제목", &ParagraphStyle::new().keep_with_next(true))
+        .unwrap();
+    writer
+        .add_paragraph_with_paragraph_style(
+            "본문",
+            &ParagraphStyle::new().keep_lines_together(true),
+        )
+        .unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let styles = document.paragraph_styles();
+    assert!(styles[0].keep_with_next);
+    assert!(!styles[0].keep_lines_together);
+    assert!(!styles[1].keep_with_next);
+    assert!(styles[1].keep_lines_together);
+}
+
+#[test]
+fn test_distribute_justify_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_paragraph_style(
+            "2024년 3분기 매출 1,234억 원",
+            &ParagraphStyle::new().distribute_justify(true),
+        )
+        .unwrap();
+    writer.add_paragraph("기본 정렬").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let styles = document.paragraph_styles();
+    assert!(styles[0].distribute_justify);
+    assert_eq!(styles[0], ParagraphStyle::new().distribute_justify(true));
+    assert!(!styles[1].distribute_justify);
+}
+
+#[test]
+fn test_document_language_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.set_language("en-US").unwrap();
+    writer.add_paragraph("Spell-checked in English").unwrap();
+    assert!(writer.set_language("xx-XX").is_err());
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let style = document.get_style(0).unwrap();
+    assert_eq!(style.lang_id, 0x0409);
+    assert_eq!(style.language(), Some("en-US"));
+}
+
+#[test]
+fn test_section_default_style() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Plain first paragraph").unwrap();
+    writer
+        .add_paragraph_with_style("Styled later", &TextStyle::new().bold())
+        .unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let (char_shape, para_shape) = document.section_default_style(0).unwrap();
+    assert!(std::ptr::eq(
+        char_shape,
+        document.get_char_shape(0).unwrap()
+    ));
+    assert!(std::ptr::eq(
+        para_shape,
+        document.get_para_shape(0).unwrap()
+    ));
+
+    assert!(document.section_default_style(1).is_none());
+}
+
+#[test]
+fn test_default_named_styles() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("본문").unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    let styles = document.named_styles();
+    let normal = styles
+        .iter()
+        .find(|style| style.name == "바탕글")
+        .expect("default document should define 바탕글");
+    assert_eq!(normal.english_name, "Normal");
+    assert!(document
+        .get_char_shape(normal.char_shape_id as usize)
+        .is_some());
+    assert!(document
+        .get_para_shape(normal.para_shape_id as usize)
+        .is_some());
+
+    let paragraph = document
+        .paragraphs()
+        .find(|para| para.text().contains("본문"))
+        .unwrap();
+    assert_eq!(
+        document.paragraph_named_style(paragraph).as_ref(),
+        Some(normal)
+    );
+}
+
+#[test]
+fn test_paragraph_with_named_style_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_named_style("기본 문단", "Normal")
+        .unwrap();
+    let quote_id = writer.add_named_style("인용").unwrap();
+    assert_eq!(writer.add_named_style("인용").unwrap(), quote_id);
+    writer
+        .add_paragraph_with_named_style("인용 문단", "인용")
+        .unwrap();
+    assert!(writer
+        .add_paragraph_with_named_style("없음", "Missing")
+        .is_err());
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let styles: Vec<_> = document
+        .paragraphs()
+        .filter(|para| !para.text().trim().is_empty())
+        .map(|para| {
+            let style = document.paragraph_named_style(para).unwrap();
+            (para.text().trim().to_string(), para.style_id, style.name)
+        })
+        .collect();
+    assert_eq!(
+        styles,
+        [
+            ("기본 문단".to_string(), 0, "바탕글".to_string()),
+            ("인용 문단".to_string(), quote_id, "인용".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_default_document_base_font_and_color() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Unstyled").unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(document.base_font(), "맑은 고딕");
+    assert_eq!(document.base_color(), Color::Rgb(0, 0, 0));
+
+    // A font added for styled text does not change the base font
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_style("Red", &TextStyle::new().font("바탕").color(0xFF0000))
+        .unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.base_font(), "맑은 고딕");
+    assert_eq!(document.base_color(), Color::Rgb(0, 0, 0));
+}
+
+#[test]
+fn test_raw_shapes_parse_back_to_shapes() {
+    use hwpers::model::{CharShape, ParaShape};
+    use hwpers::parser::record::{Record, RecordHeader};
+
+    let record = |tag_id: u16, data: &[u8]| Record {
+        header: RecordHeader {
+            tag_id,
+            level: 1,
+            size: data.len() as u32,
+        },
+        data: data.to_vec(),
+    };
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_style("Bold", &TextStyle::new().bold().size(14))
+        .unwrap();
+    assert!(writer.document().raw_char_shape(0).is_none());
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    for id in 0..document.doc_info.char_shapes.len() {
+        let raw = document.raw_char_shape(id).unwrap();
+        let shape = CharShape::from_record(&record(0x15, raw)).unwrap();
+        assert_eq!(&shape, document.get_char_shape(id).unwrap());
+    }
+    let raw = document.raw_para_shape(0).unwrap();
+    let shape = ParaShape::from_record(&record(0x19, raw)).unwrap();
+    assert_eq!(&shape, document.get_para_shape(0).unwrap());
+
+    let last = document.doc_info.char_shapes.len();
+    assert!(document.raw_char_shape(last).is_none());
+}
@@ -1,5 +1,8 @@
+mod common;
+
+use common::stream_records;
 use hwpers::writer::style::{StyledText, TextStyle};
-use hwpers::HwpWriter;
+use hwpers::{HwpReader, HwpWriter};
 
 #[test]
 fn test_styled_paragraph() {
@@ -182,3 +185,409 @@ fn test_styled_text_all_occurrences() {
     assert_eq!(styled_text.ranges[2].start, 20); // Third "test"
     assert_eq!(styled_text.ranges[2].end, 24);
 }
+
+#[test]
+fn test_identically_styled_runs_share_char_shape_id() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Plain one").unwrap();
+    writer
+        .add_paragraph_with_bold("Plain then bold", vec![(11, 15)])
+        .unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let runs = document.styled_runs();
+
+    let texts: Vec<&str> = runs.iter().map(|run| run.text.as_str()).collect();
+    assert_eq!(texts, ["Plain one", "Plain then ", "bold"]);
+    assert_eq!(runs[0].char_shape_id, runs[1].char_shape_id);
+    assert_ne!(runs[1].char_shape_id, runs[2].char_shape_id);
+    assert!(!runs[1].bold);
+    assert!(runs[2].bold);
+
+    let bold_shape = document.get_char_shape(runs[2].char_shape_id as usize);
+    assert!(bold_shape.is_some_and(|shape| shape.is_bold()));
+    assert!(document.paragraphs().all(|para| para.para_shape_id == 0));
+}
+
+#[test]
+fn test_locate_maps_offset_to_paragraph_and_run() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("First paragraph").unwrap();
+    writer
+        .add_paragraph_with_bold("Plain then bold", vec![(11, 15)])
+        .unwrap();
+    writer.add_paragraph("Last one").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let text = document.extract_text();
+    let char_offset = |needle: &str| text[..text.find(needle).unwrap()].chars().count();
+
+    let paragraphs = &document.sections().next().unwrap().paragraphs;
+    let middle = paragraphs
+        .iter()
+        .position(|para| para.text().contains("Plain then bold"))
+        .unwrap();
+
+    let bold = document.locate(char_offset("bold")).unwrap();
+    assert_eq!(bold.section, 0);
+    assert_eq!(bold.paragraph_index, middle);
+    assert_eq!(bold.run_index, 1);
+
+    let plain = document.locate(char_offset("then")).unwrap();
+    assert_eq!(plain.paragraph_index, middle);
+    assert_eq!(plain.run_index, 0);
+
+    let last = document.locate(char_offset("Last")).unwrap();
+    assert_eq!(last.paragraph_index, middle + 1);
+
+    // The line break after a paragraph has no location
+    assert!(document.locate(char_offset("\n")).is_none());
+    assert!(document.locate(text.chars().count()).is_none());
+}
+
+#[test]
+fn test_text_locator_counts_chars_not_bytes() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("첫 문단").unwrap();
+    writer
+        .add_paragraph_with_bold("보통 굵게", vec![(3, 5)])
+        .unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let text = document.extract_text();
+    let char_offset = |needle: &str| text[..text.find(needle).unwrap()].chars().count();
+    let locator = document.text_locator();
+
+    let first = locator.locate(0).unwrap();
+    let plain = locator.locate(char_offset("보통")).unwrap();
+    let bold = locator.locate(char_offset("굵게") + 1).unwrap();
+    assert_eq!(plain.paragraph_index, first.paragraph_index + 1);
+    assert_eq!(plain.run_index, 0);
+    assert_eq!(bold.paragraph_index, plain.paragraph_index);
+    assert_eq!(bold.run_index, 1);
+    assert!(locator.locate(char_offset("\n")).is_none());
+    assert!(locator.locate(text.chars().count()).is_none());
+}
+
+#[test]
+fn test_shape_indices_beyond_a_byte() {
+    use hwpers::model::{CharShape, ParaShape};
+
+    let mut writer = HwpWriter::new();
+    while writer.document().doc_info.char_shapes.len() < 299 {
+        writer.add_char_shape(CharShape::new_default()).unwrap();
+    }
+    while writer.document().doc_info.para_shapes.len() < 300 {
+        writer.add_para_shape(ParaShape::new_default()).unwrap();
+    }
+    writer
+        .add_paragraph_with_style("High index", &TextStyle::new().size(37))
+        .unwrap();
+    writer.add_heading("Heading", 2).unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    assert!(document.doc_info.char_shapes.len() > 300);
+
+    let run = document
+        .styled_runs()
+        .into_iter()
+        .find(|run| run.text == "High index")
+        .unwrap();
+    assert_eq!(run.char_shape_id, 299);
+    assert_eq!(run.font_size, 37.0);
+
+    // The heading's paragraph shape is past index 255 too
+    let heading = document
+        .paragraphs()
+        .find(|para| para.text() == "Heading")
+        .unwrap();
+    assert!(heading.para_shape_id >= 300);
+    assert_eq!(document.heading_level(heading), Some(2));
+}
+
+#[test]
+fn test_run_color_auto_or_rgb() {
+    use hwpers::model::Color;
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_style("Unset text", &TextStyle::new().bold())
+        .unwrap();
+    writer
+        .add_paragraph_with_style("Black text", &TextStyle::new().color(0x000000))
+        .unwrap();
+    writer
+        .add_paragraph_with_style("Red text", &TextStyle::new().color(0xFF0000))
+        .unwrap();
+
+    // Clear the color of the first run's shape
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let unset_id = document.styled_runs()[0].char_shape_id as usize;
+    document.doc_info.char_shapes[unset_id].text_color = hwpers::model::color::COLOR_NONE;
+    let bytes = HwpWriter::from_document(document).to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let runs = document.styled_runs();
+
+    let unset_run = runs.iter().find(|run| run.text == "Unset text").unwrap();
+    assert_eq!(unset_run.color, Color::Auto);
+    assert!(unset_run.color.is_auto());
+
+    let black_run = runs.iter().find(|run| run.text == "Black text").unwrap();
+    assert_eq!(black_run.color, Color::Rgb(0, 0, 0));
+    assert!(!black_run.color.is_auto());
+
+    let red_run = runs.iter().find(|run| run.text == "Red text").unwrap();
+    assert_eq!(red_run.color, Color::Rgb(0xFF, 0, 0));
+    assert_eq!(red_run.color.to_rgb(), 0xFF0000);
+}
+
+#[test]
+fn test_underline_style_roundtrip() {
+    use hwpers::model::{Color, UnderlineStyle};
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_style(
+            "Dotted",
+            &TextStyle::new().underline_style(UnderlineStyle::Dot, 0xFF0000),
+        )
+        .unwrap();
+    writer
+        .add_paragraph_with_style("Plain", &TextStyle::new().underline())
+        .unwrap();
+    writer
+        .add_paragraph_with_style("Struck", &TextStyle::new().strikethrough())
+        .unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let runs = document.styled_runs();
+    let run = |text: &str| runs.iter().find(|run| run.text == text).unwrap();
+
+    let dotted = run("Dotted");
+    assert!(dotted.underline);
+    assert!(!dotted.strikethrough);
+    assert_eq!(dotted.underline_style, Some(UnderlineStyle::Dot));
+    assert_eq!(dotted.underline_color, Color::Rgb(0xFF, 0, 0));
+    assert_eq!(dotted.color, Color::Rgb(0, 0, 0));
+
+    assert_eq!(run("Plain").underline_style, Some(UnderlineStyle::Solid));
+
+    let struck = run("Struck");
+    assert!(struck.strikethrough);
+    assert!(!struck.underline);
+    assert_eq!(struck.underline_style, None);
+}
+
+#[test]
+fn test_runs_with_offsets() {
+    let mut writer = HwpWriter::new();
+    let styled = StyledText::new("Plain 굵게".to_string()).add_range(6, 8, TextStyle::new().bold());
+    writer.add_styled_paragraph(&styled).unwrap();
+    writer.add_paragraph("둘째 문단").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let runs: Vec<_> = document.runs().collect();
+
+    assert_eq!(runs.len(), document.styled_runs().len());
+    assert_eq!(runs.len(), 3);
+    assert_eq!(runs[0].0, 0);
+    for pair in runs.windows(2) {
+        let (start, run) = &pair[0];
+        assert_eq!(pair[1].0, start + run.text.chars().count());
+        assert!(pair[1].0 > *start);
+    }
+
+    let (offset, bold) = runs.iter().find(|(_, run)| run.bold).unwrap();
+    assert_eq!(bold.text, "굵게");
+    assert_eq!(*offset, 6);
+    assert_eq!(runs[2], (8, document.styled_runs()[2].clone()));
+}
+
+#[test]
+fn test_hidden_comments_are_excluded_by_default() {
+    use common::{record, section_definition, utf16};
+
+    // A hidden comment anchor takes 8 UTF-16 units; its text sits in a
+    // paragraph list below the `tcmt` control header
+    let tcmt = u32::from_be_bytes(*b"tcmt").to_le_bytes();
+    let mut text: Vec<u8> = utf16("Visible ");
+    text.extend(
+        [
+            0x000F,
+            u16::from_le_bytes([tcmt[0], tcmt[1]]),
+            u16::from_le_bytes([tcmt[2], tcmt[3]]),
+            0,
+            0,
+            0,
+            0,
+            0x000F,
+        ]
+        .iter()
+        .flat_map(|unit: &u16| unit.to_le_bytes()),
+    );
+    text.extend(utf16("note\r"));
+    let mut list_header = 1i32.to_le_bytes().to_vec();
+    list_header.extend(0u32.to_le_bytes());
+
+    let mut section = section_definition();
+    section.extend(record(0x42, 0, &[0; 24]));
+    section.extend(record(0x43, 1, &text));
+    section.extend(record(0x47, 1, &u32::from_be_bytes(*b"tcmt").to_le_bytes()));
+    section.extend(record(0x48, 2, &list_header));
+    section.extend(record(0x42, 2, &[0; 24]));
+    section.extend(record(0x43, 3, &utf16("secret\r")));
+    section.extend(record(0x42, 0, &[0; 24]));
+    section.extend(record(0x43, 1, &utf16("After\r")));
+
+    let mut writer = HwpWriter::new();
+    writer.add_raw_section(&section).unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    let paragraphs = &document.body_texts[1].sections[0].paragraphs;
+    assert_eq!(paragraphs[0].hidden_comments[0].text, "secret");
+    assert!(paragraphs[1].hidden_comments.is_empty());
+
+    let extracted = document.extract_text();
+    assert!(extracted.contains("Visible note"));
+    assert!(!extracted.contains("secret"));
+    let options = hwpers::model::ExtractionOptions::new().include_hidden(true);
+    let extracted = document.extract_text_with(&options);
+    assert!(extracted.contains("Visible note\nsecret\nAfter"));
+}
+
+#[test]
+fn test_runs_after_control_and_emoji() {
+    use hwpers::model::Field;
+
+    let mut writer = HwpWriter::new();
+    let styled =
+        StyledText::new("😀 then bold".to_string()).add_range(7, 11, TextStyle::new().bold());
+    writer.add_styled_paragraph(&styled).unwrap();
+
+    // The page number control ahead of the text takes 8 UTF-16 units, the emoji 2
+    let mut document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let paragraph = &mut document.body_texts[0].sections[0].paragraphs[0];
+    paragraph.fields.push(Field::page_number());
+    let bold_id = paragraph.char_shapes.as_ref().unwrap().char_positions[1].char_shape_id;
+    let bytes = HwpWriter::from_document(document).to_bytes().unwrap();
+
+    // PARA_CHAR_SHAPE counts UTF-16 units: "bold" starts at unit 16, char 7
+    let char_shapes: Vec<Vec<u8>> = stream_records(bytes.clone(), "/BodyText/Section0")
+        .into_iter()
+        .filter(|record| record.tag_id() == 0x44)
+        .map(|record| record.data)
+        .collect();
+    let expected: Vec<u8> = [0, 0, 16, u32::from(bold_id)]
+        .iter()
+        .flat_map(|value: &u32| value.to_le_bytes())
+        .collect();
+    assert!(char_shapes.contains(&expected), "got {char_shapes:?}");
+
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let runs: Vec<(String, bool)> = document
+        .styled_runs()
+        .into_iter()
+        .map(|run| (run.text, run.bold))
+        .collect();
+    assert_eq!(
+        runs,
+        [("😀 then ".to_string(), false), ("bold".to_string(), true)]
+    );
+}
+
+#[test]
+fn test_runs_offsets_after_non_bmp_chars() {
+    use common::{record, section_definition, utf16};
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_bold("Bold", vec![(0, 4)])
+        .unwrap();
+    let bold_id = writer.document().body_texts[0].sections[0].paragraphs[0]
+        .char_shapes
+        .as_ref()
+        .unwrap()
+        .char_positions[0]
+        .char_shape_id;
+
+    // Each of the two math letters takes 2 UTF-16 units: "굵게" starts at unit 11
+    let char_shape: Vec<u8> = [0, 0, 11, u32::from(bold_id)]
+        .iter()
+        .flat_map(|value: &u32| value.to_le_bytes())
+        .collect();
+    let mut section = section_definition();
+    section.extend(record(0x42, 0, &[0; 24]));
+    section.extend(record(0x43, 1, &utf16("𝐀𝐁 plain 굵게\r")));
+    section.extend(record(0x44, 1, &char_shape));
+    writer.add_raw_section(&section).unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let runs: Vec<(usize, String, bool)> = document
+        .runs()
+        .map(|(offset, run)| (offset, run.text, run.bold))
+        .collect();
+    let start = runs
+        .iter()
+        .position(|(_, text, _)| text == "𝐀𝐁 plain ")
+        .unwrap();
+    let base = runs[start].0;
+    assert_eq!(runs[start + 1], (base + 9, "굵게".to_string(), true));
+}
+
+#[test]
+fn test_locate_after_inline_control() {
+    use common::{record, section_definition, utf16};
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_paragraph_with_bold("Bold", vec![(0, 4)])
+        .unwrap();
+    let bold_id = writer.document().body_texts[0].sections[0].paragraphs[0]
+        .char_shapes
+        .as_ref()
+        .unwrap()
+        .char_positions[0]
+        .char_shape_id;
+
+    // An inline object anchor takes 8 UTF-16 units but reads as one char
+    let gso = u32::from_be_bytes(*b"gso ").to_le_bytes();
+    let mut text: Vec<u8> = [
+        0x000B,
+        u16::from_le_bytes([gso[0], gso[1]]),
+        u16::from_le_bytes([gso[2], gso[3]]),
+        0,
+        0,
+        0,
+        0,
+        0x000B,
+    ]
+    .iter()
+    .flat_map(|unit: &u16| unit.to_le_bytes())
+    .collect();
+    text.extend(utf16("plain bold\r"));
+    let char_shape: Vec<u8> = [0, 0, 14, u32::from(bold_id)]
+        .iter()
+        .flat_map(|value: &u32| value.to_le_bytes())
+        .collect();
+    let mut section = section_definition();
+    section.extend(record(0x42, 0, &[0; 24]));
+    section.extend(record(0x43, 1, &text));
+    section.extend(record(0x44, 1, &char_shape));
+    writer.add_raw_section(&section).unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let extracted = document.extract_text();
+    let char_offset = |needle: &str| extracted[..extracted.find(needle).unwrap()].chars().count();
+
+    let bold = document.locate(char_offset("bold")).unwrap();
+    assert_eq!(bold.section, 1);
+    assert_eq!(bold.run_index, 1);
+    let plain = document.locate(char_offset("plain")).unwrap();
+    assert_eq!(plain.section, 1);
+    assert_eq!(plain.run_index, 0);
+}
@@ -1,4 +1,4 @@
-use hwpers::HwpWriter;
+use hwpers::{HwpReader, HwpWriter};
 
 #[test]
 fn test_simple_table() {
@@ -201,3 +201,174 @@ fn test_mixed_content_with_table() {
         .content
         .contains("After table"));
 }
+
+#[test]
+fn test_text_with_table_context() {
+    use hwpers::model::TableCellRef;
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Intro").unwrap();
+    writer
+        .add_simple_table(&[vec!["Name", "Age"], vec!["Alice", "25"]])
+        .unwrap();
+    writer.add_paragraph("Outro").unwrap();
+    writer.add_simple_table(&[vec!["Solo"]]).unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let segments = document.text_with_table_context();
+    let context = |text: &str| {
+        segments
+            .iter()
+            .find(|segment| segment.text == text)
+            .unwrap_or_else(|| panic!("{text:?} missing"))
+            .cell
+    };
+
+    assert_eq!(context("Intro"), None);
+    assert_eq!(
+        context("25"),
+        Some(TableCellRef {
+            table_index: 0,
+            row: 1,
+            col: 1
+        })
+    );
+    assert_eq!(
+        context("Name"),
+        Some(TableCellRef {
+            table_index: 0,
+            row: 0,
+            col: 0
+        })
+    );
+    assert_eq!(context("Outro"), None);
+    assert_eq!(
+        context("Solo"),
+        Some(TableCellRef {
+            table_index: 1,
+            row: 0,
+            col: 0
+        })
+    );
+}
+
+#[test]
+fn test_table_column_and_total_width() {
+    let mut writer = HwpWriter::new();
+
+    writer
+        .add_table(2, 3)
+        .unwrap()
+        .column_width(0, 50.0)
+        .total_width(150.0)
+        .merge_cells(1, 1, 1, 2)
+        .finish()
+        .unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let tables = document.tables();
+    let table = tables[0];
+    let fifty_mm = hwpers::model::page_layout::mm_to_hwp_units(50.0);
+    let hundred_fifty_mm = hwpers::model::page_layout::mm_to_hwp_units(150.0);
+
+    assert_eq!(table.get_cell(0, 0).unwrap().width, fifty_mm);
+    assert_eq!(table.total_width(), hundred_fifty_mm);
+    // The remaining 100mm is shared by the two open columns
+    let col1 = table.get_cell(0, 1).unwrap().width;
+    let col2 = table.get_cell(0, 2).unwrap().width;
+    assert!(col1.abs_diff(col2) <= 1);
+    assert_eq!(table.get_cell(1, 1).unwrap().width, col1 + col2);
+}
+
+#[test]
+fn test_cell_vertical_alignment() {
+    use hwpers::model::VAlign;
+
+    let mut writer = HwpWriter::new();
+
+    writer
+        .add_table(2, 2)
+        .unwrap()
+        .set_cell(0, 0, "Centered")
+        .cell_valign(0, 0, VAlign::Center)
+        .cell_valign(1, 1, VAlign::Bottom)
+        .finish()
+        .unwrap();
+
+    // The cells' LIST_HEADER properties keep the alignment in bits 5-6
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let tables = document.tables();
+    let table = tables[0];
+    assert_eq!(table.get_cell(0, 0).unwrap().vertical_align, VAlign::Center);
+    assert_eq!(table.get_cell(0, 1).unwrap().vertical_align, VAlign::Top);
+    assert_eq!(table.get_cell(1, 1).unwrap().vertical_align, VAlign::Bottom);
+}
+
+#[test]
+fn test_repeat_header_row() {
+    let mut writer = HwpWriter::new();
+
+    writer
+        .add_table(3, 2)
+        .unwrap()
+        .set_cell(0, 0, "Name")
+        .set_cell(0, 1, "Value")
+        .repeat_header_row(true)
+        .finish()
+        .unwrap();
+    writer.add_simple_table(&[vec!["A", "B"]]).unwrap();
+
+    // The TABLE record keeps the repeat flag, the cells' LIST_HEADERs the header flag
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let tables = document.tables();
+    assert!(tables[0].repeats_header());
+    assert_eq!(tables[0].header_rows(), 1);
+    assert!(tables[0].get_cell(0, 1).unwrap().is_header);
+    assert!(!tables[0].get_cell(1, 0).unwrap().is_header);
+
+    assert!(!tables[1].repeats_header());
+    assert_eq!(tables[1].header_rows(), 0);
+}
+
+#[test]
+fn test_table_dimensions() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Before").unwrap();
+    writer
+        .add_simple_table(&[vec!["a", "b", "c"], vec!["d", "e", "f"]])
+        .unwrap();
+    writer.add_table(4, 1).unwrap().finish().unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.table_dimensions(), [(2, 3), (4, 1)]);
+}
+
+#[test]
+fn test_styled_cell_among_plain_cells() {
+    use hwpers::writer::style::TextStyle;
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_table(2, 2)
+        .unwrap()
+        .set_cell(0, 0, "Name")
+        .set_cell_styled(0, 1, "Total", &TextStyle::new().bold())
+        .set_cell(1, 0, "Apples")
+        .set_cell(1, 1, "12")
+        .finish()
+        .unwrap();
+
+    // Cell paragraphs keep their character shapes under the cell's LIST_HEADER
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    let styled = document.table_cell_runs(0, 0, 1);
+    assert_eq!(styled.len(), 1);
+    assert_eq!(styled[0].text, "Total");
+    assert!(styled[0].bold);
+
+    for (row, col) in [(0, 0), (1, 0), (1, 1)] {
+        let runs = document.table_cell_runs(0, row, col);
+        assert_eq!(runs.len(), 1);
+        assert!(!runs[0].bold);
+    }
+    assert!(document.table_cell_runs(0, 2, 0).is_empty());
+}
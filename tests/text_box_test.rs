@@ -1,7 +1,10 @@
+mod common;
+
+use common::{record, section_definition, utf16};
 use hwpers::{
     model::{TextBoxAlignment, TextBoxBorderStyle, TextBoxFillType},
     writer::{CustomTextBoxStyle, FloatingTextBoxStyle},
-    HwpWriter,
+    HwpReader, HwpWriter,
 };
 
 #[test]
@@ -14,7 +17,9 @@ fn test_basic_text_box() {
         .unwrap();
 
     // Add basic text box
-    writer.add_text_box("This is a basic text box").unwrap();
+    writer
+        .add_text_box("This is a basic text box", 0.0, 0.0, 50.0, 20.0)
+        .unwrap();
 
     let document = writer.document();
 
@@ -242,7 +247,9 @@ fn test_multiple_text_boxes() {
         .unwrap();
 
     // Add various text boxes
-    writer.add_text_box("First text box").unwrap();
+    writer
+        .add_text_box("First text box", 10.0, 10.0, 50.0, 20.0)
+        .unwrap();
     writer
         .add_text_box_at_position("Second text box", 50, 60, 40, 25)
         .unwrap();
@@ -367,3 +374,114 @@ fn test_mixed_document_with_text_boxes() {
     assert_eq!(page_def.header_footer.headers().len(), 1);
     assert_eq!(page_def.header_footer.footers().len(), 1);
 }
+
+#[test]
+fn test_text_box_text_extracted_after_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Before the box").unwrap();
+    writer
+        .add_text_box("Boxed note\nSecond line", 10.0, 20.0, 50.0, 30.0)
+        .unwrap();
+    writer.add_paragraph("After the box").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+    let text = document.extract_text();
+
+    let positions: Vec<usize> = [
+        "Before the box",
+        "Boxed note",
+        "Second line",
+        "After the box",
+    ]
+    .iter()
+    .map(|needle| {
+        text.find(needle)
+            .unwrap_or_else(|| panic!("{needle:?} missing"))
+    })
+    .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_text_box_read_back_as_shape() {
+    use hwpers::model::ShapeKind;
+
+    let mut writer = HwpWriter::new();
+    writer
+        .add_text_box("Boxed note\nSecond line", 10.0, 20.0, 50.0, 30.0)
+        .unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let shapes = document.shapes();
+    assert_eq!(shapes.len(), 1);
+    assert_eq!(shapes[0].kind, ShapeKind::Rectangle);
+    // 1mm is about 283.465 HWP units
+    assert_eq!((shapes[0].x, shapes[0].y), (2835, 5669));
+    assert_eq!((shapes[0].width, shapes[0].height), (14173, 8504));
+    assert_eq!(shapes[0].text, "Boxed note\nSecond line");
+    assert_eq!(document.extraction_report().unhandled_controls, 0);
+}
+
+/// Section with a paragraph anchoring an ellipse labelled "Start", then "Next"
+fn section_with_labelled_ellipse() -> hwpers::parser::body_text::BodyText {
+    let gso = u32::from_be_bytes(*b"gso ").to_le_bytes();
+    let anchor: Vec<u8> = [
+        0x000B,
+        u16::from_le_bytes([gso[0], gso[1]]),
+        u16::from_le_bytes([gso[2], gso[3]]),
+        0,
+        0,
+        0,
+        0,
+        0x000B,
+        0x000D,
+    ]
+    .iter()
+    .flat_map(|u: &u16| u.to_le_bytes())
+    .collect();
+
+    let mut ctrl = gso.to_vec();
+    for value in [0u32, 700, 300, 2000, 1000] {
+        ctrl.extend(value.to_le_bytes()); // properties, y, x, width, height
+    }
+    let component = u32::from_be_bytes(*b"$ell").to_le_bytes().repeat(2);
+
+    let mut stream = section_definition();
+    stream.extend(record(0x42, 0, &[0; 24]));
+    stream.extend(record(0x43, 1, &anchor));
+    stream.extend(record(0x47, 1, &ctrl));
+    stream.extend(record(0x4C, 2, &component));
+    stream.extend(record(0x48, 3, &[0; 6]));
+    stream.extend(record(0x42, 3, &[0; 24]));
+    stream.extend(record(0x43, 4, &utf16("Start\r")));
+    stream.extend(record(0x42, 0, &[0; 24]));
+    stream.extend(record(0x43, 1, &utf16("Next\r")));
+    hwpers::parser::body_text::BodyTextParser::parse(stream, false).unwrap()
+}
+
+#[test]
+fn test_labelled_shape_text_extracted() {
+    use hwpers::model::{ExtractionOptions, ShapeKind};
+
+    let body_text = section_with_labelled_ellipse();
+    let paragraphs = &body_text.sections[0].paragraphs;
+    assert_eq!(paragraphs.len(), 2);
+
+    let shape = &paragraphs[0].shapes[0];
+    assert_eq!(shape.kind, ShapeKind::Ellipse);
+    assert_eq!(
+        (shape.x, shape.y, shape.width, shape.height),
+        (300, 700, 2000, 1000)
+    );
+    assert_eq!(shape.text, "Start");
+
+    assert_eq!(body_text.extract_text(), "\nStart\nNext\n");
+    let marked = ExtractionOptions::new().shape_marker(Some("[shape] "));
+    assert_eq!(
+        body_text.extract_text_with(&marked),
+        "\n[shape] Start\nNext\n"
+    );
+}
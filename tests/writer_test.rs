@@ -1,3 +1,6 @@
+mod common;
+
+use common::{record, section_definition};
 use hwpers::{HwpReader, HwpWriter};
 
 #[test]
@@ -71,3 +74,174 @@ fn test_korean_text() {
     assert!(!bytes.is_empty());
     println!("Mixed language document size: {} bytes", bytes.len());
 }
+
+#[test]
+fn test_horizontal_line_roundtrip() {
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Section one").unwrap();
+    writer.add_horizontal_line().unwrap();
+    writer.add_paragraph("Section two").unwrap();
+
+    let bytes = writer.to_bytes().unwrap();
+    let document = HwpReader::from_bytes(&bytes).unwrap();
+
+    let separators: Vec<bool> = document
+        .paragraphs()
+        .map(|para| document.is_horizontal_line(para))
+        .collect();
+    assert_eq!(separators, [false, true, false]);
+    // The rule paragraph carries the char-shape run its header counts
+    let rule = document.paragraphs().nth(1).unwrap();
+    assert_eq!(rule.char_shape_count, 1);
+    assert!(rule.char_shapes.is_some());
+    assert_eq!(
+        hwpers::render::to_markdown(&document),
+        "Section one\n\n---\n\nSection two\n"
+    );
+}
+
+#[test]
+fn test_blank_line_roundtrip() {
+    fn blank_count(document: &hwpers::HwpDocument) -> usize {
+        document
+            .paragraphs()
+            .filter(|para| para.text().trim().is_empty())
+            .count()
+    }
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Above").unwrap();
+    writer.add_paragraph("Below").unwrap();
+    let without = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Above").unwrap();
+    writer.add_blank_line().unwrap();
+    writer.add_paragraph("Below").unwrap();
+    let with = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(blank_count(&with), blank_count(&without) + 1);
+    assert_eq!(with.extract_text(), "Above\n\nBelow\n");
+}
+
+#[test]
+fn test_markdown_crlf_line_endings() {
+    use hwpers::render::{to_markdown_with, LineEnding};
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("First").unwrap();
+    writer.add_horizontal_line().unwrap();
+    writer.add_paragraph("Second").unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(
+        to_markdown_with(&document, LineEnding::CrLf),
+        "First\r\n\r\n---\r\n\r\nSecond\r\n"
+    );
+    assert_eq!(
+        to_markdown_with(&document, LineEnding::Lf),
+        hwpers::render::to_markdown(&document)
+    );
+}
+
+#[test]
+fn test_html_line_endings() {
+    use hwpers::render::{to_html, to_html_with, LineEnding};
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("Fish & chips").unwrap();
+    writer.add_horizontal_line().unwrap();
+    writer.add_paragraph("Second").unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    let html = to_html(&document);
+    assert!(html.contains("<body>\n<p>Fish &amp; chips</p>\n<hr>\n<p>Second</p>\n</body>\n"));
+    assert!(!html.contains('\r'));
+
+    let crlf = to_html_with(&document, LineEnding::CrLf);
+    assert_eq!(crlf, html.replace('\n', "\r\n"));
+}
+
+#[test]
+fn test_embedded_font_round_trip() {
+    let mut font = vec![0x00, 0x01, 0x00, 0x00];
+    font.extend((0..=255u8).cycle().take(2048));
+
+    let mut writer = HwpWriter::new();
+    writer.embed_font("Test Sans", &font).unwrap();
+    writer.add_paragraph("Embedded font").unwrap();
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.embedded_font("Test Sans"), Some(font.as_slice()));
+    let face = document
+        .doc_info
+        .face_names
+        .iter()
+        .find(|face| face.font_name == "Test Sans")
+        .unwrap();
+    assert_eq!(face.embedded_bin_id, Some(1));
+    assert_eq!(face.properties & 0x01, 0x01);
+    assert!(document.get_images().is_empty());
+    assert_eq!(document.embedded_font("Missing"), None);
+
+    assert!(writer.embed_font("Broken", b"not a font").is_err());
+
+    // Embedding the face again replaces the data of the same entry
+    font[100] ^= 0xFF;
+    writer.embed_font("Test Sans", &font).unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.doc_info.bin_data.len(), 1);
+    assert_eq!(document.embedded_font("Test Sans"), Some(font.as_slice()));
+}
+
+#[test]
+fn test_add_line_breaks_within_one_paragraph() {
+    let mut writer = HwpWriter::new();
+    writer.add_line("123 Main Street").unwrap();
+    writer.add_line("Springfield").unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(document.paragraph_count(), 1);
+    assert_eq!(document.extract_text(), "123 Main Street\nSpringfield\n");
+
+    // A regular paragraph ends the open one
+    writer.add_paragraph("Next").unwrap();
+    writer.add_line("Own line").unwrap();
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.paragraph_count(), 3);
+}
+
+#[test]
+fn test_raw_section_round_trip() {
+    // PARA_HEADER: 4 chars, no controls, shape 0, style 0, one char shape and line
+    let mut header = 4u32.to_le_bytes().to_vec();
+    header.extend(0u32.to_le_bytes());
+    header.extend([0, 0, 0, 0]);
+    header.extend(1u16.to_le_bytes());
+    header.extend(0u16.to_le_bytes());
+    header.extend(1u16.to_le_bytes());
+    header.extend(0u32.to_le_bytes());
+    let text: Vec<u8> = "외부 \r"
+        .encode_utf16()
+        .take(4)
+        .flat_map(|unit| unit.to_le_bytes())
+        .collect();
+    let char_shape = [0u8; 8];
+
+    let mut section = section_definition();
+    section.extend(record(0x42, 0, &header));
+    section.extend(record(0x43, 1, &text));
+    section.extend(record(0x44, 1, &char_shape));
+
+    let mut writer = HwpWriter::new();
+    writer.add_paragraph("본문").unwrap();
+    writer.add_raw_section(&section).unwrap();
+    assert_eq!(writer.document().body_texts.len(), 2);
+
+    let document = HwpReader::from_bytes(&writer.to_bytes().unwrap()).unwrap();
+    assert_eq!(document.body_texts.len(), 2);
+    assert_eq!(document.body_texts[1].sections[0].paragraphs.len(), 1);
+    let texts: Vec<String> = document.paragraphs().map(|para| para.text()).collect();
+    assert_eq!(texts.last().map(String::as_str), Some("외부 "));
+    assert!(document.extract_text().contains("본문"));
+}